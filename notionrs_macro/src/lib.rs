@@ -0,0 +1,176 @@
+//! Derive macros for [notionrs](https://docs.rs/notionrs).
+//!
+//! Use them through the `derive` feature of `notionrs` instead of depending on this crate directly.
+
+use proc_macro::TokenStream;
+use quote::{format_ident, quote};
+use syn::{parse_macro_input, Data, DeriveInput, Fields, LitStr};
+
+/// The property types that can be specified in `#[notion(..)]`,
+/// and the marker types in `notionrs::page::row::kind`.
+const KINDS: &[(&str, &str)] = &[
+    ("title", "Title"),
+    ("rich_text", "RichText"),
+    ("number", "Number"),
+    ("select", "Select"),
+    ("multi_select", "MultiSelect"),
+    ("status", "Status"),
+    ("date", "Date"),
+    ("checkbox", "Checkbox"),
+    ("url", "Url"),
+    ("email", "Email"),
+    ("phone_number", "PhoneNumber"),
+    ("relation", "Relation"),
+];
+
+/// Implements `notionrs::page::row::NotionRow` for a struct with named fields.
+///
+/// See the documentation of `notionrs::page::row` for the supported attributes and types.
+#[proc_macro_derive(NotionRow, attributes(notion))]
+pub fn derive_notion_row(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    match expand(input) {
+        Ok(tokens) => tokens.into(),
+        Err(error) => error.to_compile_error().into(),
+    }
+}
+
+/// The options of a field given with `#[notion(..)]`.
+struct FieldOptions {
+    /// The name of the property.
+    name: String,
+
+    /// The marker type of the property type, if specified.
+    kind: Option<syn::Ident>,
+
+    skip: bool,
+}
+
+fn parse_field_options(field: &syn::Field) -> syn::Result<FieldOptions> {
+    let ident = field.ident.as_ref().expect("named field");
+
+    let mut options = FieldOptions {
+        name: ident.to_string().trim_start_matches("r#").to_string(),
+        kind: None,
+        skip: false,
+    };
+
+    for attr in field
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("notion"))
+    {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("rename") {
+                options.name = meta.value()?.parse::<LitStr>()?.value();
+                return Ok(());
+            }
+
+            if meta.path.is_ident("skip") {
+                options.skip = true;
+                return Ok(());
+            }
+
+            for (name, kind) in KINDS {
+                if meta.path.is_ident(name) {
+                    if options.kind.is_some() {
+                        return Err(meta.error("the property type is specified more than once"));
+                    }
+                    options.kind = Some(format_ident!("{}", kind));
+                    return Ok(());
+                }
+            }
+
+            Err(meta.error(format!(
+                "unknown attribute; expected `rename = \"..\"`, `skip` or a property type ({})",
+                KINDS
+                    .iter()
+                    .map(|(name, _)| *name)
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            )))
+        })?;
+    }
+
+    Ok(options)
+}
+
+fn expand(input: DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    &input.ident,
+                    "`NotionRow` can only be derived for structs with named fields",
+                ))
+            }
+        },
+        _ => {
+            return Err(syn::Error::new_spanned(
+                &input.ident,
+                "`NotionRow` can only be derived for structs",
+            ))
+        }
+    };
+
+    let row = quote!(::notionrs::page::row);
+
+    let mut reads = Vec::new();
+    let mut writes = Vec::new();
+
+    for field in fields {
+        let options = parse_field_options(field)?;
+        let ident = &field.ident;
+        let ty = &field.ty;
+        let name = &options.name;
+
+        if options.skip {
+            reads.push(quote! {
+                #ident: ::core::default::Default::default()
+            });
+            continue;
+        }
+
+        let kind = match &options.kind {
+            Some(kind) => quote!(#row::kind::#kind),
+            None => quote!(<#ty as #row::DefaultKind>::Kind),
+        };
+
+        reads.push(quote! {
+            #ident: #row::read_property::<#ty, #kind>(properties, #name)?
+        });
+
+        writes.push(quote! {
+            #row::write_property::<#ty, #kind>(&mut properties, #name, &self.#ident);
+        });
+    }
+
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics #row::NotionRow for #ident #ty_generics #where_clause {
+            fn from_properties(
+                properties: &::std::collections::HashMap<
+                    ::std::string::String,
+                    ::notionrs::page::PageProperty,
+                >,
+            ) -> ::core::result::Result<Self, ::notionrs::error::Error> {
+                ::core::result::Result::Ok(Self {
+                    #(#reads,)*
+                })
+            }
+
+            fn to_properties(
+                &self,
+            ) -> ::std::collections::HashMap<::std::string::String, ::notionrs::page::PageProperty>
+            {
+                let mut properties = ::std::collections::HashMap::new();
+                #(#writes)*
+                properties
+            }
+        }
+    })
+}
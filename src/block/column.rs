@@ -2,6 +2,12 @@ use serde::{Deserialize, Serialize};
 
 #[derive(Deserialize, Serialize, Debug, Default, Clone)]
 pub struct ColumnBlock {
+    /// The width of the column as a ratio of the column list (between 0 and 1).
+    /// The ratios of all the columns in a column list should add up to 1.
+    /// If it is not set, the columns share the width equally.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub width_ratio: Option<f64>,
+
     /// It can only be specified when making a block creation request.
     /// If you need to retrieve the child blocks, you will have to send a request to this block again.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -11,10 +17,16 @@ pub struct ColumnBlock {
 impl ColumnBlock {
     pub fn new() -> Self {
         ColumnBlock {
+            width_ratio: None,
             children: Some(vec![]),
         }
     }
 
+    pub fn width_ratio(mut self, width_ratio: f64) -> Self {
+        self.width_ratio = Some(width_ratio);
+        self
+    }
+
     pub fn children(mut self, children: Vec<super::Block>) -> Self {
         self.children = Some(children);
         self
@@ -23,6 +35,9 @@ impl ColumnBlock {
 
 impl std::fmt::Display for ColumnBlock {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        write!(f, "")
+        match self.width_ratio {
+            Some(width_ratio) => write!(f, "{}", width_ratio),
+            None => write!(f, ""),
+        }
     }
 }
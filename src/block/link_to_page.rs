@@ -0,0 +1,105 @@
+use serde::{Deserialize, Serialize};
+
+/// <https://developers.notion.com/reference/block#link-to-page>
+///
+/// Link to page block objects contain the following
+/// information within the link_to_page property:
+///
+/// Either `page_id` or `database_id` is set, depending on `type`.
+#[derive(Deserialize, Serialize, Debug, Default, Clone)]
+pub struct LinkToPageBlock {
+    /// "page_id" or "database_id"
+    pub r#type: String,
+
+    /// The ID of the linked page.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page_id: Option<String>,
+
+    /// The ID of the linked database.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub database_id: Option<String>,
+}
+
+impl LinkToPageBlock {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Links to a page.
+    pub fn page_id<T>(mut self, page_id: T) -> Self
+    where
+        T: AsRef<str>,
+    {
+        self.r#type = "page_id".to_string();
+        self.page_id = Some(page_id.as_ref().to_string());
+        self.database_id = None;
+        self
+    }
+
+    /// Links to a database.
+    pub fn database_id<T>(mut self, database_id: T) -> Self
+    where
+        T: AsRef<str>,
+    {
+        self.r#type = "database_id".to_string();
+        self.database_id = Some(database_id.as_ref().to_string());
+        self.page_id = None;
+        self
+    }
+
+    /// Returns the ID of the linked page or database.
+    pub fn get_id(&self) -> Option<&str> {
+        self.page_id.as_deref().or(self.database_id.as_deref())
+    }
+}
+
+impl std::fmt::Display for LinkToPageBlock {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.get_id().unwrap_or_default())
+    }
+}
+
+// # --------------------------------------------------------------------------------
+//
+// unit test
+//
+// # --------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod unit_tests {
+
+    use super::LinkToPageBlock;
+
+    #[test]
+    fn deserialize_block_link_to_page() {
+        let json_data = r#"
+        {
+            "type": "database_id",
+            "database_id": "4cc5c1f6-e9b3-47b8-a6cd-6d9ac28bc9d7"
+        }
+        "#;
+
+        let link_to_page = serde_json::from_str::<LinkToPageBlock>(json_data).unwrap();
+
+        assert_eq!(link_to_page.r#type, "database_id");
+        assert_eq!(
+            link_to_page.get_id(),
+            Some("4cc5c1f6-e9b3-47b8-a6cd-6d9ac28bc9d7")
+        );
+    }
+
+    #[test]
+    fn serialize_block_link_to_page() {
+        let link_to_page = LinkToPageBlock::new()
+            .database_id("xxx")
+            .page_id("b2b4a5ad-7dab-4e4e-9a23-7ab5a1bb1ff4");
+
+        assert_eq!(
+            serde_json::to_value(link_to_page).unwrap(),
+            serde_json::json!({
+                "type": "page_id",
+                "page_id": "b2b4a5ad-7dab-4e4e-9a23-7ab5a1bb1ff4"
+            })
+        );
+    }
+}
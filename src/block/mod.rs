@@ -12,15 +12,19 @@ pub use self::embed::EmbedBlock;
 pub use self::equation::EquationBlock;
 pub use self::heading::HeadingBlock;
 pub use self::link_preview::LinkPreviewBlock;
+pub use self::link_to_page::LinkToPageBlock;
 pub use self::numbered_list_item::NumberedListItemBlock;
 pub use self::paragraph::ParagraphBlock;
 pub use self::quote::QuoteBlock;
 pub use self::synced_block::SyncedBlock;
 pub use self::table::TableBlock;
+pub use self::table_of_contents::TableOfContentsBlock;
 pub use self::table_row::TableRowBlock;
 pub use self::template::TemplateBlock;
 pub use self::to_do::ToDoBlock;
 pub use self::toggle::ToggleBlock;
+pub use self::tree::{BlockNode, BlockTree, TreeNode};
+pub use self::unsupported::UnsupportedBlock;
 
 pub mod bookmark;
 pub mod bulleted_list_item;
@@ -34,15 +38,19 @@ pub mod embed;
 pub mod equation;
 pub mod heading;
 pub mod link_preview;
+pub mod link_to_page;
 pub mod numbered_list_item;
 pub mod paragraph;
 pub mod quote;
 pub mod synced_block;
 pub mod table;
+pub mod table_of_contents;
 pub mod table_row;
 pub mod template;
 pub mod to_do;
 pub mod toggle;
+pub mod tree;
+pub mod unsupported;
 
 /// ```json
 /// {
@@ -77,7 +85,7 @@ pub mod toggle;
 pub struct BlockResponse {
     pub object: String,
 
-    pub id: String,
+    pub id: crate::others::id::BlockId,
 
     pub parent: crate::others::parent::Parent,
 
@@ -169,6 +177,9 @@ pub enum Block {
     LinkPreview {
         link_preview: link_preview::LinkPreviewBlock,
     },
+    LinkToPage {
+        link_to_page: link_to_page::LinkToPageBlock,
+    },
     NumberedListItem {
         numbered_list_item: numbered_list_item::NumberedListItemBlock,
     },
@@ -187,6 +198,9 @@ pub enum Block {
     Table {
         table: table::TableBlock,
     },
+    TableOfContents {
+        table_of_contents: table_of_contents::TableOfContentsBlock,
+    },
     TableRow {
         table_row: table_row::TableRowBlock,
     },
@@ -199,6 +213,10 @@ pub enum Block {
     Toggle {
         toggle: toggle::ToggleBlock,
     },
+    /// A block that is not supported by the Notion API.
+    Unsupported {
+        unsupported: unsupported::UnsupportedBlock,
+    },
     Video {
         video: crate::others::file::File,
     },
@@ -227,16 +245,19 @@ impl std::fmt::Display for Block {
             Block::Heading3 { heading_3 } => write!(f, "{}", heading_3),
             Block::Image { image } => write!(f, "{}", image),
             Block::LinkPreview { link_preview } => write!(f, "{}", link_preview),
+            Block::LinkToPage { link_to_page } => write!(f, "{}", link_to_page),
             Block::NumberedListItem { numbered_list_item } => write!(f, "{}", numbered_list_item),
             Block::Paragraph { paragraph } => write!(f, "{}", paragraph),
             Block::Pdf { pdf } => write!(f, "{}", pdf),
             Block::Quote { quote } => write!(f, "{}", quote),
             Block::SyncedBlock { synced_block } => write!(f, "{}", synced_block),
             Block::Table { table } => write!(f, "{}", table),
+            Block::TableOfContents { table_of_contents } => write!(f, "{}", table_of_contents),
             Block::TableRow { table_row } => write!(f, "{}", table_row),
             Block::Template { template } => write!(f, "{}", template),
             Block::ToDo { to_do } => write!(f, "{}", to_do),
             Block::Toggle { toggle } => write!(f, "{}", toggle),
+            Block::Unsupported { unsupported } => write!(f, "{}", unsupported),
             Block::Video { video } => write!(f, "{}", video),
             Block::Unknown(value) => write!(f, "{:?}", value),
         }
@@ -405,7 +426,8 @@ mod unit_tests {
                         Some("2024-07-18 202106.png".to_string())
                     )
                 }
-                crate::others::file::File::External(_) => panic!("Unexpected!"),
+                crate::others::file::File::External(_)
+                | crate::others::file::File::FileUpload(_) => panic!("Unexpected!"),
             },
             _ => panic!("Unexpected!"),
         }
@@ -458,9 +480,187 @@ mod unit_tests {
                         "https://prod-files-secure.s3.us-west-2.amazonaws.com/"
                     )
                 }
-                crate::others::file::File::External(_) => panic!("Unexpected!"),
+                crate::others::file::File::External(_)
+                | crate::others::file::File::FileUpload(_) => panic!("Unexpected!"),
+            },
+            _ => panic!("Unexpected!"),
+        }
+    }
+
+    #[test]
+    fn deserialize_block_table_of_contents() {
+        let json_data = r#"
+        {
+            "object": "block",
+            "id": "1f2f3e6c-9c3a-4f43-9a3b-1a4b3c5d6e7f",
+            "parent": {
+                "type": "page_id",
+                "page_id": "12eb8c08-9d6d-4b3f-9a8f-80de613de2ed"
+            },
+            "created_time": "2024-08-17T06:01:00.000Z",
+            "last_edited_time": "2024-08-17T06:01:00.000Z",
+            "created_by": {
+                "object": "user",
+                "id": "bb7ef91a-d553-447a-90de-00f56c06caa5"
+            },
+            "last_edited_by": {
+                "object": "user",
+                "id": "bb7ef91a-d553-447a-90de-00f56c06caa5"
+            },
+            "has_children": false,
+            "archived": false,
+            "in_trash": false,
+            "type": "table_of_contents",
+            "table_of_contents": {
+                "color": "default"
+            }
+        }
+        "#;
+
+        let block = serde_json::from_str::<BlockResponse>(json_data).unwrap();
+
+        match block.block {
+            Block::TableOfContents { table_of_contents } => {
+                assert_eq!(
+                    table_of_contents.color,
+                    crate::others::color::Color::Default
+                )
+            }
+            _ => panic!("Unexpected!"),
+        }
+    }
+
+    #[test]
+    fn deserialize_block_link_to_page() {
+        let json_data = r#"
+        {
+            "object": "block",
+            "id": "2a3b4c5d-6e7f-4a8b-9c0d-1e2f3a4b5c6d",
+            "parent": {
+                "type": "page_id",
+                "page_id": "12eb8c08-9d6d-4b3f-9a8f-80de613de2ed"
+            },
+            "created_time": "2024-08-17T06:01:00.000Z",
+            "last_edited_time": "2024-08-17T06:01:00.000Z",
+            "created_by": {
+                "object": "user",
+                "id": "bb7ef91a-d553-447a-90de-00f56c06caa5"
+            },
+            "last_edited_by": {
+                "object": "user",
+                "id": "bb7ef91a-d553-447a-90de-00f56c06caa5"
+            },
+            "has_children": false,
+            "archived": false,
+            "in_trash": false,
+            "type": "link_to_page",
+            "link_to_page": {
+                "type": "page_id",
+                "page_id": "b2b4a5ad-7dab-4e4e-9a23-7ab5a1bb1ff4"
+            }
+        }
+        "#;
+
+        let block = serde_json::from_str::<BlockResponse>(json_data).unwrap();
+
+        match block.block {
+            Block::LinkToPage { link_to_page } => {
+                assert_eq!(
+                    link_to_page.page_id.as_deref(),
+                    Some("b2b4a5ad-7dab-4e4e-9a23-7ab5a1bb1ff4")
+                );
+                assert_eq!(link_to_page.database_id, None);
+            }
+            _ => panic!("Unexpected!"),
+        }
+    }
+
+    #[test]
+    fn deserialize_block_unsupported() {
+        let json_data = r#"
+        {
+            "object": "block",
+            "id": "3b4c5d6e-7f8a-4b9c-8d0e-2f3a4b5c6d7e",
+            "parent": {
+                "type": "page_id",
+                "page_id": "12eb8c08-9d6d-4b3f-9a8f-80de613de2ed"
+            },
+            "created_time": "2024-08-17T06:01:00.000Z",
+            "last_edited_time": "2024-08-17T06:01:00.000Z",
+            "created_by": {
+                "object": "user",
+                "id": "bb7ef91a-d553-447a-90de-00f56c06caa5"
             },
+            "last_edited_by": {
+                "object": "user",
+                "id": "bb7ef91a-d553-447a-90de-00f56c06caa5"
+            },
+            "has_children": false,
+            "archived": false,
+            "in_trash": false,
+            "type": "unsupported",
+            "unsupported": {}
+        }
+        "#;
+
+        let block = serde_json::from_str::<BlockResponse>(json_data).unwrap();
+
+        assert!(matches!(block.block, Block::Unsupported { .. }));
+    }
+
+    #[test]
+    fn deserialize_block_column() {
+        let json_data = r#"
+        {
+            "object": "block",
+            "id": "4c5d6e7f-8a9b-4c0d-9e1f-3a4b5c6d7e8f",
+            "parent": {
+                "type": "page_id",
+                "page_id": "12eb8c08-9d6d-4b3f-9a8f-80de613de2ed"
+            },
+            "created_time": "2024-08-17T06:01:00.000Z",
+            "last_edited_time": "2024-08-17T06:01:00.000Z",
+            "created_by": {
+                "object": "user",
+                "id": "bb7ef91a-d553-447a-90de-00f56c06caa5"
+            },
+            "last_edited_by": {
+                "object": "user",
+                "id": "bb7ef91a-d553-447a-90de-00f56c06caa5"
+            },
+            "has_children": false,
+            "archived": false,
+            "in_trash": false,
+            "type": "column",
+            "column": {
+                "width_ratio": 0.25
+            }
+        }
+        "#;
+
+        let block = serde_json::from_str::<BlockResponse>(json_data).unwrap();
+
+        match block.block {
+            Block::Column { column } => assert_eq!(column.width_ratio, Some(0.25)),
             _ => panic!("Unexpected!"),
         }
     }
+
+    #[test]
+    fn serialize_block_column() {
+        let block = Block::Column {
+            column: column::ColumnBlock::new().width_ratio(0.5),
+        };
+
+        assert_eq!(
+            serde_json::to_value(block).unwrap(),
+            serde_json::json!({
+                "type": "column",
+                "column": {
+                    "width_ratio": 0.5,
+                    "children": []
+                }
+            })
+        );
+    }
 }
@@ -0,0 +1,53 @@
+use serde::{Deserialize, Serialize};
+
+/// <https://developers.notion.com/reference/block#table-of-contents>
+///
+/// Table of contents block objects contain the following
+/// information within the table_of_contents property:
+#[derive(Deserialize, Serialize, Debug, Default, Clone, Copy)]
+pub struct TableOfContentsBlock {
+    /// The color of the block.
+    pub color: crate::others::color::Color,
+}
+
+impl TableOfContentsBlock {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    crate::color_setters!(self, self.color);
+}
+
+impl std::fmt::Display for TableOfContentsBlock {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.color.as_str())
+    }
+}
+
+// # --------------------------------------------------------------------------------
+//
+// unit test
+//
+// # --------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod unit_tests {
+
+    use super::TableOfContentsBlock;
+
+    #[test]
+    fn deserialize_block_table_of_contents() {
+        let json_data = r#"
+        {
+            "color": "gray_background"
+        }
+        "#;
+
+        let table_of_contents = serde_json::from_str::<TableOfContentsBlock>(json_data).unwrap();
+
+        assert_eq!(
+            table_of_contents.color,
+            crate::others::color::Color::GrayBackground
+        );
+    }
+}
@@ -15,6 +15,11 @@ pub struct ToDoBlock {
 
     /// The color of the block.
     pub color: crate::others::color::Color,
+
+    /// It can only be specified when making a block creation request.
+    /// If you need to retrieve the child blocks, you will have to send a request to this block again.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub children: Option<Vec<super::Block>>,
 }
 
 impl ToDoBlock {
@@ -32,6 +37,11 @@ impl ToDoBlock {
         self
     }
 
+    pub fn children(mut self, children: Vec<super::Block>) -> Self {
+        self.children = Some(children);
+        self
+    }
+
     color_setters!(self, self.color);
 }
 
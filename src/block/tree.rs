@@ -0,0 +1,157 @@
+use serde::{Deserialize, Serialize};
+
+use super::{Block, BlockResponse};
+
+/// The blocks of a page (or any block), with their descendants attached.
+///
+/// Retrieved with [`Client::get_block_tree`](crate::Client::get_block_tree).
+#[derive(Deserialize, Serialize, Debug)]
+pub struct BlockTree {
+    /// The ID of the page or block whose children were retrieved.
+    pub root_id: String,
+
+    /// The top-level child blocks.
+    pub children: Vec<BlockNode>,
+}
+
+/// A block and its child blocks.
+#[derive(Deserialize, Serialize, Debug)]
+pub struct BlockNode {
+    pub block: BlockResponse,
+
+    /// The child blocks. This is empty if the block has no children,
+    /// or if they were not retrieved (because of `max_depth` or `stop_at_child_pages`).
+    pub children: Vec<BlockNode>,
+}
+
+impl BlockTree {
+    /// Iterates over all the nodes in depth-first order
+    /// (a block is followed by its descendants, then by its next sibling).
+    pub fn iter(&self) -> BlockTreeIter<'_> {
+        BlockTreeIter {
+            stack: self.children.iter().rev().collect(),
+        }
+    }
+
+    /// The number of blocks in the tree.
+    pub fn len(&self) -> usize {
+        self.iter().count()
+    }
+
+    /// Whether the tree has no blocks.
+    pub fn is_empty(&self) -> bool {
+        self.children.is_empty()
+    }
+}
+
+impl BlockNode {
+    /// Whether the block has children that were not retrieved.
+    pub fn is_truncated(&self) -> bool {
+        self.block.has_children && self.children.is_empty()
+    }
+}
+
+/// A block with its child blocks, as accepted by the renderers
+/// (e.g. [`markdown::render`](crate::markdown::render)).
+///
+/// - [`Block`]: the children are read from the `children` field of the block
+///   (e.g. blocks built for `append_block_children`).
+/// - [`BlockResponse`]: the API doesn't return children, so it has none.
+/// - [`BlockNode`]: the children retrieved by `get_block_tree`.
+pub trait TreeNode: Sized {
+    /// The block itself.
+    fn block(&self) -> &Block;
+
+    /// The child blocks.
+    fn children(&self) -> &[Self];
+
+    /// The ID of the block, if it was retrieved from the API.
+    fn id(&self) -> Option<&str> {
+        None
+    }
+}
+
+impl TreeNode for Block {
+    fn block(&self) -> &Block {
+        self
+    }
+
+    fn children(&self) -> &[Self] {
+        let children = match self {
+            Block::BulletedListItem { bulleted_list_item } => &bulleted_list_item.children,
+            Block::Column { column } => &column.children,
+            Block::ColumnList { column_list } => &column_list.children,
+            Block::Heading1 { heading_1 } => &heading_1.children,
+            Block::Heading2 { heading_2 } => &heading_2.children,
+            Block::Heading3 { heading_3 } => &heading_3.children,
+            Block::NumberedListItem { numbered_list_item } => &numbered_list_item.children,
+            Block::Quote { quote } => &quote.children,
+            Block::SyncedBlock { synced_block } => &synced_block.children,
+            Block::Table { table } => &table.children,
+            Block::ToDo { to_do } => &to_do.children,
+            Block::Toggle { toggle } => &toggle.children,
+            _ => return &[],
+        };
+
+        children.as_deref().unwrap_or_default()
+    }
+}
+
+impl TreeNode for BlockResponse {
+    fn block(&self) -> &Block {
+        &self.block
+    }
+
+    fn children(&self) -> &[Self] {
+        &[]
+    }
+
+    fn id(&self) -> Option<&str> {
+        Some(self.id.as_str())
+    }
+}
+
+impl TreeNode for BlockNode {
+    fn block(&self) -> &Block {
+        &self.block.block
+    }
+
+    fn children(&self) -> &[Self] {
+        &self.children
+    }
+
+    fn id(&self) -> Option<&str> {
+        Some(self.block.id.as_str())
+    }
+}
+
+/// The URL of a page (or database) on `notion.so`, used by the renderers to link child pages
+/// and mentions.
+pub(crate) fn notion_url(id: &str) -> String {
+    format!("https://www.notion.so/{}", id.replace('-', ""))
+}
+
+/// A depth-first iterator over the nodes of a [`BlockTree`].
+#[derive(Debug)]
+pub struct BlockTreeIter<'a> {
+    stack: Vec<&'a BlockNode>,
+}
+
+impl<'a> Iterator for BlockTreeIter<'a> {
+    type Item = &'a BlockNode;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.stack.pop()?;
+        self.stack.extend(node.children.iter().rev());
+        Some(node)
+    }
+}
+
+impl<'a> IntoIterator for &'a BlockTree {
+    type Item = &'a BlockNode;
+    type IntoIter = BlockTreeIter<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
@@ -0,0 +1,44 @@
+use serde::{Deserialize, Serialize};
+
+/// <https://developers.notion.com/reference/block#unsupported>
+///
+/// Blocks that are not supported by the Notion API are returned with the type `unsupported`.
+/// They cannot be created, so this type is read-only.
+#[derive(Deserialize, Serialize, Debug, Default, Clone)]
+pub struct UnsupportedBlock {
+    /// The type of the block in the Notion UI, if the API reports it.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub block_type: Option<String>,
+}
+
+impl UnsupportedBlock {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl std::fmt::Display for UnsupportedBlock {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.block_type.as_deref().unwrap_or("unsupported"))
+    }
+}
+
+// # --------------------------------------------------------------------------------
+//
+// unit test
+//
+// # --------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod unit_tests {
+
+    use super::UnsupportedBlock;
+
+    #[test]
+    fn deserialize_block_unsupported() {
+        let unsupported = serde_json::from_str::<UnsupportedBlock>("{}").unwrap();
+
+        assert_eq!(unsupported.block_type, None);
+        assert_eq!(unsupported.to_string(), "unsupported");
+    }
+}
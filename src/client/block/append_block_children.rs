@@ -1,17 +1,17 @@
 use serde::{Deserialize, Serialize};
 
-use crate::error::{api_error::ApiError, Error};
+use crate::error::Error;
 
 #[derive(Debug)]
 pub struct AppendBlockChildrenClient {
-    /// The reqwest http client
-    pub(crate) reqwest_client: reqwest::Client,
+    /// The Notion API client
+    pub(crate) client: crate::client::Client,
 
     /// Identifier for a block. Also accepts a page ID.
-    pub(crate) block_id: Option<String>,
+    pub(crate) block_id: Option<crate::others::id::BlockId>,
 
     /// The ID of the existing block that the new block should be appended after.
-    pub(crate) after: Option<String>,
+    pub(crate) after: Option<crate::others::id::BlockId>,
 
     pub(crate) children: Vec<crate::block::Block>,
 }
@@ -21,7 +21,7 @@ pub struct AppendBlockChildrenRequestBody {
     pub(crate) children: Vec<crate::block::Block>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub(crate) after: Option<String>,
+    pub(crate) after: Option<crate::others::id::BlockId>,
     // #[serde(skip_serializing_if = "Option::is_none")]
     // pub(crate) page_size: Option<u32>,
 }
@@ -31,11 +31,9 @@ impl AppendBlockChildrenClient {
     pub async fn send(
         self,
     ) -> Result<crate::list_response::ListResponse<crate::block::BlockResponse>, Error> {
-        let block_id = self
-            .block_id
-            .ok_or(Error::RequestParameter(
-                "`block_id` has not been set.".to_string(),
-            ))?;
+        let block_id = self.block_id.ok_or(Error::RequestParameter(
+            "`block_id` has not been set.".to_string(),
+        ))?;
 
         let request_body_struct = AppendBlockChildrenRequestBody {
             children: self.children,
@@ -44,27 +42,17 @@ impl AppendBlockChildrenClient {
 
         let request_body = serde_json::to_string(&request_body_struct)?;
 
-        let url = format!("https://api.notion.com/v1/blocks/{}/children", block_id);
+        let url = format!("/blocks/{}/children", block_id);
 
         let request = self
-            .reqwest_client
-            .patch(url)
+            .client
+            .request(reqwest::Method::PATCH, url)
             .header("Content-Type", "application/json")
             .body(request_body);
 
-        let response = request.send().await?;
+        let body = self.client.send(request).await?;
 
-        if !response.status().is_success() {
-            let error_body = response.text().await?;
-
-            let error_json = serde_json::from_str::<ApiError>(&error_body)?;
-
-            return Err(Error::Api(Box::new(error_json)));
-        }
-
-        let body = response.text().await?;
-
-        let block = serde_json::from_str::<
+        let block = crate::client::deserialize_body::<
             crate::list_response::ListResponse<crate::block::BlockResponse>,
         >(&body)?;
 
@@ -72,14 +60,14 @@ impl AppendBlockChildrenClient {
     }
 
     /// Identifier for a block. Also accepts a page ID.
-    pub fn block_id<T: AsRef<str>>(mut self, block_id: T) -> Self {
-        self.block_id = Some(block_id.as_ref().to_string());
+    pub fn block_id<T: Into<crate::others::id::BlockId>>(mut self, block_id: T) -> Self {
+        self.block_id = Some(block_id.into());
         self
     }
 
     // TODO: docs for after
-    pub fn after<T: AsRef<str>>(mut self, after: T) -> Self {
-        self.after = Some(after.as_ref().to_string());
+    pub fn after<T: Into<crate::others::id::BlockId>>(mut self, after: T) -> Self {
+        self.after = Some(after.into());
         self
     }
 
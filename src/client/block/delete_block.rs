@@ -1,11 +1,11 @@
-use crate::error::{api_error::ApiError, Error};
+use crate::error::Error;
 
 #[derive(Debug)]
 pub struct DeleteBlockClient {
-    /// The reqwest http client
-    pub(crate) reqwest_client: reqwest::Client,
+    /// The Notion API client
+    pub(crate) client: crate::client::Client,
 
-    pub(crate) block_id: Option<String>,
+    pub(crate) block_id: Option<crate::others::id::BlockId>,
 }
 
 impl DeleteBlockClient {
@@ -15,30 +15,20 @@ impl DeleteBlockClient {
             "`block_id` has not been set.".to_string(),
         ))?;
 
-        let url = format!("https://api.notion.com/v1/blocks/{}", block_id);
+        let url = format!("/blocks/{}", block_id);
 
-        let request = self.reqwest_client.delete(url);
+        let request = self.client.request(reqwest::Method::DELETE, url);
 
-        let response = request.send().await?;
+        let body = self.client.send(request).await?;
 
-        if !response.status().is_success() {
-            let error_body = response.text().await?;
-
-            let error_json = serde_json::from_str::<ApiError>(&error_body)?;
-
-            return Err(Error::Api(Box::new(error_json)));
-        }
-
-        let body = response.text().await?;
-
-        let block = serde_json::from_str::<crate::block::BlockResponse>(&body)?;
+        let block = crate::client::deserialize_body::<crate::block::BlockResponse>(&body)?;
 
         Ok(block)
     }
 
     // TODO: docs for block_id
-    pub fn block_id<T: AsRef<str>>(mut self, block_id: T) -> Self {
-        self.block_id = Some(block_id.as_ref().to_string());
+    pub fn block_id<T: Into<crate::others::id::BlockId>>(mut self, block_id: T) -> Self {
+        self.block_id = Some(block_id.into());
         self
     }
 }
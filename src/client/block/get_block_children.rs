@@ -1,11 +1,14 @@
-use crate::error::{api_error::ApiError, Error};
+use crate::{
+    client::pagination::{self, PaginatedStream},
+    error::Error,
+};
 
 #[derive(Debug)]
 pub struct GetBlockChildrenClient {
-    /// The reqwest http client
-    pub(crate) reqwest_client: reqwest::Client,
+    /// The Notion API client
+    pub(crate) client: crate::client::Client,
 
-    pub(crate) block_id: Option<String>,
+    pub(crate) block_id: Option<crate::others::id::BlockId>,
 
     pub(crate) page_size: u64,
 
@@ -21,11 +24,9 @@ impl GetBlockChildrenClient {
 
         let mut page_size_remain = self.page_size;
 
-        let block_id = &self
-            .block_id
-            .ok_or(Error::RequestParameter(
-                "`block_id` has not been set.".to_string(),
-            ))?;
+        let block_id = &self.block_id.ok_or(Error::RequestParameter(
+            "`block_id` has not been set.".to_string(),
+        ))?;
 
         let mut start_cursor = self.start_cursor;
 
@@ -38,32 +39,8 @@ impl GetBlockChildrenClient {
 
             page_size_remain -= page_size;
 
-            let url = format!("https://api.notion.com/v1/blocks/{}/children", block_id);
-
-            let mut query_params: Vec<(String, String)> =
-                vec![("page_size".to_string(), page_size.to_string())];
-
-            if let Some(ref cursor) = start_cursor {
-                query_params.push(("start_cursor".to_string(), cursor.to_string()))
-            }
-
-            let request = self.reqwest_client.get(url).query(&query_params);
-
-            let response = request.send().await?;
-
-            if !response.status().is_success() {
-                let error_body = response.text().await?;
-
-                let error_json = serde_json::from_str::<ApiError>(&error_body)?;
-
-                return Err(Error::Api(Box::new(error_json)));
-            }
-
-            let body = response.text().await?;
-
-            let block_list_response = serde_json::from_str::<
-                crate::list_response::ListResponse<crate::block::BlockResponse>,
-            >(&body)?;
+            let block_list_response =
+                fetch_page(&self.client, block_id.as_str(), start_cursor, page_size).await?;
 
             result_blocks.extend(block_list_response.results);
 
@@ -83,12 +60,59 @@ impl GetBlockChildrenClient {
         })
     }
 
+    /// Returns a stream that yields all the child blocks one by one.
+    ///
+    /// Blocks are fetched lazily, 100 per request, and dropping the stream stops fetching.
+    /// `page_size` is ignored; use `futures::StreamExt::take` to limit the number of blocks.
+    ///
+    /// ```no_run
+    /// use futures::TryStreamExt;
+    ///
+    /// # async fn run() -> Result<(), notionrs::error::Error> {
+    /// let client = notionrs::Client::new();
+    ///
+    /// let mut stream = client
+    ///     .get_block_children()
+    ///     .block_id("BLOCK_ID")
+    ///     .into_stream();
+    ///
+    /// while let Some(block) = stream.try_next().await? {
+    ///     println!("{}", block.id);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn into_stream(self) -> PaginatedStream<crate::block::BlockResponse> {
+        let Some(block_id) = self.block_id else {
+            return pagination::fail(Error::RequestParameter(
+                "`block_id` has not been set.".to_string(),
+            ));
+        };
+
+        let client = self.client;
+
+        pagination::paginate(self.start_cursor, move |cursor| {
+            let client = client.clone();
+            let block_id = block_id.clone();
+
+            async move {
+                fetch_page(
+                    &client,
+                    block_id.as_str(),
+                    cursor,
+                    u64::from(pagination::MAX_PAGE_SIZE),
+                )
+                .await
+            }
+        })
+    }
+
     // TODO: docs for block_id
     pub fn block_id<T>(mut self, page_id: T) -> Self
     where
-        T: AsRef<str>,
+        T: Into<crate::others::id::BlockId>,
     {
-        self.block_id = Some(page_id.as_ref().to_string());
+        self.block_id = Some(page_id.into());
         self
     }
 
@@ -107,3 +131,32 @@ impl GetBlockChildrenClient {
         self
     }
 }
+
+/// Fetches one page of the child blocks.
+async fn fetch_page(
+    client: &crate::client::Client,
+    block_id: &str,
+    start_cursor: Option<String>,
+    page_size: u64,
+) -> Result<crate::list_response::ListResponse<crate::block::BlockResponse>, Error> {
+    let url = format!("/blocks/{}/children", block_id);
+
+    let mut query_params: Vec<(String, String)> =
+        vec![("page_size".to_string(), page_size.to_string())];
+
+    if let Some(cursor) = start_cursor {
+        query_params.push(("start_cursor".to_string(), cursor))
+    }
+
+    let request = client
+        .request(reqwest::Method::GET, url)
+        .query(&query_params);
+
+    let body = client.send(request).await?;
+
+    let block_list_response = crate::client::deserialize_body::<
+        crate::list_response::ListResponse<crate::block::BlockResponse>,
+    >(&body)?;
+
+    Ok(block_list_response)
+}
@@ -0,0 +1,265 @@
+use std::sync::Arc;
+
+use futures::TryStreamExt;
+
+use crate::{
+    block::{Block, BlockNode, BlockResponse, BlockTree},
+    error::Error,
+};
+
+/// A request builder for retrieving all the descendant blocks of a page or block.
+///
+/// `concurrency` limits the number of blocks whose children are fetched
+/// at the same time across the whole tree.
+#[derive(Debug)]
+pub struct GetBlockTreeClient {
+    /// The Notion API client
+    pub(crate) client: crate::client::Client,
+
+    pub(crate) block_id: Option<crate::others::id::BlockId>,
+
+    pub(crate) max_depth: Option<u32>,
+
+    pub(crate) concurrency: usize,
+
+    pub(crate) stop_at_child_pages: bool,
+}
+
+/// The settings shared by all the fetches of one tree.
+#[derive(Debug)]
+struct TreeContext {
+    client: crate::client::Client,
+    semaphore: tokio::sync::Semaphore,
+    max_depth: Option<u32>,
+    stop_at_child_pages: bool,
+}
+
+impl GetBlockTreeClient {
+    /// Send requests to the API endpoint of Notion until the whole tree is retrieved.
+    pub async fn send(self) -> Result<BlockTree, Error> {
+        let block_id = self.block_id.ok_or(Error::RequestParameter(
+            "`block_id` has not been set.".to_string(),
+        ))?;
+
+        let context = Arc::new(TreeContext {
+            client: self.client,
+            semaphore: tokio::sync::Semaphore::new(self.concurrency),
+            max_depth: self.max_depth,
+            stop_at_child_pages: self.stop_at_child_pages,
+        });
+
+        let children = fetch_children(context, block_id.to_string(), 1).await?;
+
+        Ok(BlockTree {
+            root_id: block_id.to_string(),
+            children,
+        })
+    }
+
+    /// The ID of the page or block whose descendants are retrieved.
+    pub fn block_id<T>(mut self, block_id: T) -> Self
+    where
+        T: Into<crate::others::id::BlockId>,
+    {
+        self.block_id = Some(block_id.into());
+        self
+    }
+
+    /// The number of levels to retrieve. `1` retrieves only the top-level blocks.
+    /// Unlimited by default.
+    pub fn max_depth(mut self, max_depth: u32) -> Self {
+        self.max_depth = Some(max_depth.max(1));
+        self
+    }
+
+    /// The maximum number of blocks whose children are fetched at the same time. Defaults to 3.
+    pub fn concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = concurrency.max(1);
+        self
+    }
+
+    /// If `true`, the contents of `child_page` blocks (sub-pages) are not retrieved.
+    /// Defaults to `false`.
+    pub fn stop_at_child_pages(mut self, stop_at_child_pages: bool) -> Self {
+        self.stop_at_child_pages = stop_at_child_pages;
+        self
+    }
+}
+
+impl TreeContext {
+    /// Returns the ID used to fetch the children of the block at `depth`,
+    /// or `None` if they should not be fetched.
+    fn children_source(&self, block: &BlockResponse, depth: u32) -> Option<String> {
+        if self.max_depth.is_some_and(|max_depth| depth >= max_depth) {
+            return None;
+        }
+
+        match &block.block {
+            Block::ChildPage { .. } if self.stop_at_child_pages => None,
+            // The rows of a database are pages, which are retrieved with `query_database`.
+            Block::ChildDatabase { .. } => None,
+            // A duplicate synced block shows the children of the original block.
+            Block::SyncedBlock { synced_block } if !block.has_children => synced_block
+                .synced_from
+                .as_ref()
+                .map(|synced_from| synced_from.block_id.clone()),
+            _ => block.has_children.then(|| block.id.to_string()),
+        }
+    }
+}
+
+/// Fetches the children of `block_id` (at `depth`) and their descendants.
+#[async_recursion::async_recursion]
+async fn fetch_children(
+    context: Arc<TreeContext>,
+    block_id: String,
+    depth: u32,
+) -> Result<Vec<BlockNode>, Error> {
+    let blocks = {
+        let _permit = context
+            .semaphore
+            .acquire()
+            .await
+            .map_err(|error| Error::Unknown(error.to_string()))?;
+
+        context
+            .client
+            .get_block_children()
+            .block_id(&block_id)
+            .into_stream()
+            .try_collect::<Vec<BlockResponse>>()
+            .await?
+    };
+
+    let nodes = blocks.into_iter().map(|block| {
+        let context = context.clone();
+
+        async move {
+            let children = match context.children_source(&block, depth) {
+                Some(source_id) => fetch_children(context, source_id, depth + 1).await?,
+                None => vec![],
+            };
+
+            Ok::<BlockNode, Error>(BlockNode { block, children })
+        }
+    });
+
+    futures::future::try_join_all(nodes).await
+}
+
+// # --------------------------------------------------------------------------------
+//
+// unit test
+//
+// # --------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod unit_tests {
+    use super::*;
+
+    fn block_json(id: &str, block_type: &str, has_children: bool) -> String {
+        let content = match block_type {
+            "child_page" => r#"{"title":"Sub page"}"#,
+            _ => r#"{"rich_text":[],"color":"default"}"#,
+        };
+
+        format!(
+            r#"{{
+                "object": "block",
+                "id": "{id}",
+                "parent": {{"type": "page_id", "page_id": "root"}},
+                "created_time": "2024-07-09T18:33:00.000Z",
+                "last_edited_time": "2024-07-09T18:33:00.000Z",
+                "created_by": {{"object": "user", "id": "user"}},
+                "last_edited_by": {{"object": "user", "id": "user"}},
+                "has_children": {has_children},
+                "archived": false,
+                "in_trash": false,
+                "type": "{block_type}",
+                "{block_type}": {content}
+            }}"#
+        )
+    }
+
+    /// root
+    /// ├── a
+    /// │   └── c
+    /// └── b (child_page)
+    ///     └── d
+    #[derive(Debug)]
+    struct TreeTransport;
+
+    #[async_trait::async_trait]
+    impl crate::client::Transport for TreeTransport {
+        async fn execute(&self, request: reqwest::Request) -> Result<reqwest::Response, Error> {
+            let results = match request.url().path() {
+                "/v1/blocks/root/children" => vec![
+                    block_json("a", "paragraph", true),
+                    block_json("b", "child_page", true),
+                ],
+                "/v1/blocks/a/children" => vec![block_json("c", "paragraph", false)],
+                "/v1/blocks/b/children" => vec![block_json("d", "paragraph", false)],
+                path => panic!("unexpected request: {}", path),
+            };
+
+            let body = format!(
+                r#"{{"object":"list","results":[{}],"next_cursor":null,"has_more":false,"type":"block"}}"#,
+                results.join(",")
+            );
+
+            Ok(reqwest::Response::from(http::Response::new(body)))
+        }
+    }
+
+    fn client() -> crate::client::Client {
+        crate::client::Client::builder()
+            .secret("secret_test")
+            .transport(TreeTransport)
+            .build()
+    }
+
+    fn ids(tree: &BlockTree) -> Vec<&str> {
+        tree.iter().map(|node| node.block.id.as_str()).collect()
+    }
+
+    #[tokio::test]
+    async fn get_whole_tree() {
+        let tree = client()
+            .get_block_tree()
+            .block_id("root")
+            .concurrency(1)
+            .send()
+            .await
+            .unwrap();
+
+        assert_eq!(ids(&tree), vec!["a", "c", "b", "d"]);
+        assert_eq!(tree.children[0].children[0].block.id, "c");
+    }
+
+    #[tokio::test]
+    async fn stop_at_child_pages() {
+        let tree = client()
+            .get_block_tree()
+            .block_id("root")
+            .stop_at_child_pages(true)
+            .send()
+            .await
+            .unwrap();
+
+        assert_eq!(ids(&tree), vec!["a", "c", "b"]);
+        assert!(tree.children[1].is_truncated());
+    }
+
+    #[tokio::test]
+    async fn limit_depth() {
+        let tree = client()
+            .get_block_tree()
+            .block_id("root")
+            .max_depth(1)
+            .send()
+            .await
+            .unwrap();
+
+        assert_eq!(ids(&tree), vec!["a", "b"]);
+    }
+}
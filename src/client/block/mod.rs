@@ -2,4 +2,5 @@ pub mod append_block_children;
 pub mod delete_block;
 pub mod get_block;
 pub mod get_block_children;
+pub mod get_block_tree;
 pub mod update_block;
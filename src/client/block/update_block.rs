@@ -1,14 +1,14 @@
 use serde::{Deserialize, Serialize};
 
-use crate::error::{api_error::ApiError, Error};
+use crate::error::Error;
 
 #[derive(Debug)]
 pub struct UpdateBlockClient {
-    /// The reqwest http client
-    pub(crate) reqwest_client: reqwest::Client,
+    /// The Notion API client
+    pub(crate) client: crate::client::Client,
 
     /// Identifier for a block. Also accepts a page ID.
-    pub(crate) block_id: Option<String>,
+    pub(crate) block_id: Option<crate::others::id::BlockId>,
 
     /// The ID of the existing block that the new block should be appended after.
     pub(crate) archived: Option<bool>,
@@ -43,34 +43,24 @@ impl UpdateBlockClient {
 
         let request_body = serde_json::to_string(&request_body_struct)?;
 
-        let url = format!("https://api.notion.com/v1/blocks/{}", block_id);
+        let url = format!("/blocks/{}", block_id);
 
         let request = self
-            .reqwest_client
-            .patch(url)
+            .client
+            .request(reqwest::Method::PATCH, url)
             .header("Content-Type", "application/json")
             .body(request_body);
 
-        let response = request.send().await?;
+        let body = self.client.send(request).await?;
 
-        if !response.status().is_success() {
-            let error_body = response.text().await?;
-
-            let error_json = serde_json::from_str::<ApiError>(&error_body)?;
-
-            return Err(Error::Api(Box::new(error_json)));
-        }
-
-        let body = response.text().await?;
-
-        let block = serde_json::from_str::<crate::block::BlockResponse>(&body)?;
+        let block = crate::client::deserialize_body::<crate::block::BlockResponse>(&body)?;
 
         Ok(block)
     }
 
     /// Identifier for a block. Also accepts a page ID.
-    pub fn block_id<T: AsRef<str>>(mut self, block_id: T) -> Self {
-        self.block_id = Some(block_id.as_ref().to_string());
+    pub fn block_id<T: Into<crate::others::id::BlockId>>(mut self, block_id: T) -> Self {
+        self.block_id = Some(block_id.into());
         self
     }
 
@@ -0,0 +1,172 @@
+use std::sync::Arc;
+
+use super::{
+    rate_limit::RateLimiter, retry::RetryPolicy, transport::Transport, Client, DEFAULT_BASE_URL,
+};
+
+/// A builder for configuring a [`Client`].
+///
+/// ```no_run
+/// use notionrs::Client;
+///
+/// let client = Client::builder()
+///     .secret("secret_XXXXXXXXXXXXXX")
+///     .base_url("http://localhost:8080/v1")
+///     .build();
+/// ```
+#[derive(Debug, Default)]
+pub struct ClientBuilder {
+    /// The token used for calling the Notion API.
+    pub(crate) secret: Option<String>,
+
+    /// The base URL to which the endpoint paths (e.g. `/pages/{page_id}`) are appended.
+    pub(crate) base_url: Option<String>,
+
+    /// The reqwest http client
+    pub(crate) reqwest_client: Option<reqwest::Client>,
+
+    /// The HTTP layer used to send requests.
+    pub(crate) transport: Option<Arc<dyn Transport>>,
+
+    /// How failed requests are retried.
+    pub(crate) retry_policy: Option<RetryPolicy>,
+
+    /// Throttles requests.
+    pub(crate) rate_limiter: Option<RateLimiter>,
+}
+
+impl ClientBuilder {
+    /// Sets the token used for calling the Notion API.
+    /// If you don't set it, the environment variable named `NOTION_TOKEN` is used.
+    pub fn secret<T>(mut self, notion_api_key: T) -> Self
+    where
+        T: AsRef<str>,
+    {
+        self.secret = Some(notion_api_key.as_ref().to_string());
+        self
+    }
+
+    /// Sets the base URL of the API. Defaults to `https://api.notion.com/v1`.
+    ///
+    /// Endpoint paths such as `/pages/{page_id}` are appended to this URL,
+    /// so it should include the version segment if the server expects it.
+    /// A trailing slash is ignored.
+    pub fn base_url<T>(mut self, base_url: T) -> Self
+    where
+        T: AsRef<str>,
+    {
+        self.base_url = Some(base_url.as_ref().trim_end_matches('/').to_string());
+        self
+    }
+
+    /// Uses a preconfigured `reqwest::Client` (e.g. with a proxy or custom timeouts).
+    ///
+    /// Unless a custom transport is also set, requests are sent with this client.
+    /// The `Authorization` and `Notion-Version` headers are attached to every request,
+    /// so they don't need to be configured as default headers.
+    pub fn reqwest_client(mut self, reqwest_client: reqwest::Client) -> Self {
+        self.reqwest_client = Some(reqwest_client);
+        self
+    }
+
+    /// Sends requests through a custom [`Transport`] instead of `reqwest`.
+    pub fn transport<T>(mut self, transport: T) -> Self
+    where
+        T: Transport + 'static,
+    {
+        self.transport = Some(Arc::new(transport));
+        self
+    }
+
+    /// Retries requests that fail with `429` or `5xx` according to the policy.
+    /// By default, requests are not retried.
+    pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = Some(retry_policy);
+        self
+    }
+
+    /// Throttles requests with a token bucket allowing `requests_per_second` on average
+    /// and bursts of up to `burst` requests.
+    ///
+    /// Use [`ClientBuilder::rate_limiter`] instead to share one budget between multiple clients.
+    pub fn rate_limit(self, requests_per_second: f64, burst: u32) -> Self {
+        self.rate_limiter(RateLimiter::new(requests_per_second, burst))
+    }
+
+    /// Throttles requests with the given limiter.
+    /// Clones of a `RateLimiter` share the same budget.
+    pub fn rate_limiter(mut self, rate_limiter: RateLimiter) -> Self {
+        self.rate_limiter = Some(rate_limiter);
+        self
+    }
+
+    /// Builds the [`Client`].
+    pub fn build(self) -> Client {
+        let secret = self
+            .secret
+            .unwrap_or_else(|| std::env::var("NOTION_TOKEN").unwrap_or_default());
+
+        let reqwest_client = self.reqwest_client.unwrap_or_default();
+
+        let transport = self
+            .transport
+            .unwrap_or_else(|| Arc::new(reqwest_client.clone()));
+
+        Client {
+            reqwest_client,
+            base_url: self
+                .base_url
+                .unwrap_or_else(|| DEFAULT_BASE_URL.to_string()),
+            secret,
+            transport,
+            retry_policy: self.retry_policy.unwrap_or_else(RetryPolicy::disabled),
+            rate_limiter: self.rate_limiter,
+        }
+    }
+}
+
+// # --------------------------------------------------------------------------------
+//
+// unit test
+//
+// # --------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod unit_tests {
+    use super::*;
+
+    #[test]
+    fn build_request_with_base_url() {
+        let client = ClientBuilder::default()
+            .secret("secret_test")
+            .base_url("http://localhost:8080/v1/")
+            .build();
+
+        let request = client
+            .request(reqwest::Method::GET, "/pages/xxx")
+            .build()
+            .unwrap();
+
+        assert_eq!(request.url().as_str(), "http://localhost:8080/v1/pages/xxx");
+        assert_eq!(
+            request.headers().get("Authorization").unwrap(),
+            "Bearer secret_test"
+        );
+        assert_eq!(
+            request.headers().get("Notion-Version").unwrap(),
+            super::super::NOTION_VERSION
+        );
+    }
+
+    #[test]
+    fn build_request_with_default_base_url() {
+        let client = ClientBuilder::default().secret("secret_test").build();
+
+        let request = client
+            .request(reqwest::Method::GET, "/users/me")
+            .build()
+            .unwrap();
+
+        assert_eq!(request.url().as_str(), "https://api.notion.com/v1/users/me");
+    }
+}
@@ -0,0 +1,370 @@
+use std::{
+    collections::BTreeMap,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{client::Transport, error::Error};
+
+/// A [`Transport`] that records requests and responses to a file (a "cassette"),
+/// or replays them without sending anything.
+///
+/// - In record mode, requests are sent through another transport and every
+///   request/response pair is written to the cassette as JSON.
+///   The `Authorization` header is redacted.
+/// - In replay mode, each request is answered with the first recorded response
+///   whose request has the same method, path (including the query string) and
+///   JSON body. Key order and whitespace of the body don't matter.
+///   Each recorded response is replayed once, in the order they were recorded.
+///
+/// ```no_run
+/// use notionrs::{client::Cassette, Client};
+///
+/// # async fn run() -> Result<(), notionrs::error::Error> {
+/// // Record once against the real API ...
+/// let client = Client::builder()
+///     .transport(Cassette::record("tests/cassettes/query_database.json"))
+///     .build();
+///
+/// // ... and replay in CI, without a token.
+/// let client = Client::builder()
+///     .transport(Cassette::replay("tests/cassettes/query_database.json")?)
+///     .build();
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct Cassette {
+    path: PathBuf,
+
+    /// The transport used to send requests while recording.
+    /// `None` when replaying.
+    inner: Option<Arc<dyn Transport>>,
+
+    interactions: tokio::sync::Mutex<Vec<Interaction>>,
+}
+
+/// The contents of a cassette file.
+#[derive(Debug, Deserialize)]
+struct CassetteFile {
+    interactions: Vec<Interaction>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+struct Interaction {
+    request: RecordedRequest,
+
+    response: RecordedResponse,
+
+    /// Whether the response has already been replayed.
+    #[serde(skip)]
+    played: bool,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+struct RecordedRequest {
+    method: String,
+
+    /// The path of the URL, followed by the query string if there is one.
+    path: String,
+
+    #[serde(default)]
+    headers: BTreeMap<String, String>,
+
+    /// The body, as JSON if it is valid JSON and as a string otherwise.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    body: Option<serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+struct RecordedResponse {
+    status: u16,
+
+    #[serde(default)]
+    headers: BTreeMap<String, String>,
+
+    /// The body, as JSON if it is valid JSON and as a string otherwise.
+    body: serde_json::Value,
+}
+
+impl Cassette {
+    /// Sends requests with a default `reqwest::Client` and records them to `path`.
+    /// An existing cassette at `path` is overwritten.
+    pub fn record<P: AsRef<Path>>(path: P) -> Self {
+        Self::record_with(path, reqwest::Client::new())
+    }
+
+    /// Sends requests through `transport` and records them to `path`.
+    /// An existing cassette at `path` is overwritten.
+    pub fn record_with<P, T>(path: P, transport: T) -> Self
+    where
+        P: AsRef<Path>,
+        T: Transport + 'static,
+    {
+        Self {
+            path: path.as_ref().to_path_buf(),
+            inner: Some(Arc::new(transport)),
+            interactions: tokio::sync::Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Loads the cassette at `path` to replay its responses.
+    pub fn replay<P: AsRef<Path>>(path: P) -> Result<Self, Error> {
+        let contents = std::fs::read_to_string(path.as_ref())?;
+
+        let file = serde_json::from_str::<CassetteFile>(&contents)?;
+
+        Ok(Self {
+            path: path.as_ref().to_path_buf(),
+            inner: None,
+            interactions: tokio::sync::Mutex::new(file.interactions),
+        })
+    }
+
+    /// Whether requests are sent and recorded, rather than replayed.
+    pub fn is_recording(&self) -> bool {
+        self.inner.is_some()
+    }
+
+    async fn record_response(
+        &self,
+        inner: &dyn Transport,
+        request: reqwest::Request,
+    ) -> Result<reqwest::Response, Error> {
+        let recorded_request = RecordedRequest::from(&request);
+
+        let response = inner.execute(request).await?;
+
+        let status = response.status();
+        let headers = response.headers().clone();
+        let body = response.bytes().await?;
+
+        let recorded_response = RecordedResponse {
+            status: status.as_u16(),
+            headers: header_map(&headers),
+            body: parse_body(&body),
+        };
+
+        let mut interactions = self.interactions.lock().await;
+
+        interactions.push(Interaction {
+            request: recorded_request,
+            response: recorded_response,
+            played: false,
+        });
+
+        let file = serde_json::json!({ "interactions": &*interactions });
+
+        if let Some(parent) = self.path.parent() {
+            tokio::fs::create_dir_all(parent).await?;
+        }
+
+        tokio::fs::write(&self.path, serde_json::to_string_pretty(&file)?).await?;
+
+        let mut builder = http::Response::builder().status(status);
+
+        for (name, value) in headers.iter() {
+            builder = builder.header(name, value);
+        }
+
+        let response = builder
+            .body(body)
+            .map_err(|e| Error::Unknown(e.to_string()))?;
+
+        Ok(reqwest::Response::from(response))
+    }
+
+    async fn replay_response(&self, request: reqwest::Request) -> Result<reqwest::Response, Error> {
+        let request = RecordedRequest::from(&request);
+
+        let mut interactions = self.interactions.lock().await;
+
+        let interaction = interactions
+            .iter_mut()
+            .find(|interaction| !interaction.played && interaction.request.matches(&request))
+            .ok_or_else(|| {
+                Error::Cassette(format!(
+                    "no recorded response for {} {} in {}",
+                    request.method,
+                    request.path,
+                    self.path.display()
+                ))
+            })?;
+
+        interaction.played = true;
+
+        let recorded = &interaction.response;
+
+        let mut builder = http::Response::builder().status(recorded.status);
+
+        for (name, value) in &recorded.headers {
+            builder = builder.header(name, value);
+        }
+
+        let body = match &recorded.body {
+            serde_json::Value::String(text) => text.clone(),
+            body => body.to_string(),
+        };
+
+        let response = builder
+            .body(body)
+            .map_err(|e| Error::Unknown(e.to_string()))?;
+
+        Ok(reqwest::Response::from(response))
+    }
+}
+
+#[async_trait::async_trait]
+impl Transport for Cassette {
+    async fn execute(&self, request: reqwest::Request) -> Result<reqwest::Response, Error> {
+        match &self.inner {
+            Some(inner) => self.record_response(inner.as_ref(), request).await,
+            None => self.replay_response(request).await,
+        }
+    }
+}
+
+impl From<&reqwest::Request> for RecordedRequest {
+    fn from(request: &reqwest::Request) -> Self {
+        let url = request.url();
+
+        let path = match url.query() {
+            Some(query) => format!("{}?{}", url.path(), query),
+            None => url.path().to_string(),
+        };
+
+        let mut headers = header_map(request.headers());
+
+        if let Some(authorization) = headers.get_mut("authorization") {
+            *authorization = "[REDACTED]".to_string();
+        }
+
+        Self {
+            method: request.method().to_string(),
+            path,
+            headers,
+            body: request
+                .body()
+                .and_then(|body| body.as_bytes())
+                .filter(|body| !body.is_empty())
+                .map(parse_body),
+        }
+    }
+}
+
+impl RecordedRequest {
+    /// Compares the method, the path and the body. Headers are ignored.
+    fn matches(&self, other: &RecordedRequest) -> bool {
+        self.method == other.method && self.path == other.path && self.body == other.body
+    }
+}
+
+fn header_map(headers: &reqwest::header::HeaderMap) -> BTreeMap<String, String> {
+    headers
+        .iter()
+        .map(|(name, value)| {
+            (
+                name.to_string(),
+                String::from_utf8_lossy(value.as_bytes()).to_string(),
+            )
+        })
+        .collect()
+}
+
+/// Parses a body as JSON, so that bodies are compared regardless of formatting and key order.
+fn parse_body(body: &[u8]) -> serde_json::Value {
+    serde_json::from_slice(body)
+        .unwrap_or_else(|_| serde_json::Value::String(String::from_utf8_lossy(body).to_string()))
+}
+
+// # --------------------------------------------------------------------------------
+//
+// unit test
+//
+// # --------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod unit_tests {
+    use super::*;
+
+    #[derive(Debug)]
+    struct UserTransport;
+
+    #[async_trait::async_trait]
+    impl Transport for UserTransport {
+        async fn execute(&self, request: reqwest::Request) -> Result<reqwest::Response, Error> {
+            assert_eq!(request.url().path(), "/v1/users/me");
+
+            let body = r#"{"object":"user","id":"ee5f0f84-409a-440f-983a-a5315961c6e4","name":"notionrs","type":"bot","bot":{}}"#;
+
+            Ok(reqwest::Response::from(http::Response::new(body)))
+        }
+    }
+
+    fn cassette_path() -> PathBuf {
+        std::env::temp_dir().join(format!("notionrs-cassette-{}.json", fastrand::u64(..)))
+    }
+
+    #[tokio::test]
+    async fn record_and_replay() {
+        let path = cassette_path();
+
+        let client = crate::client::Client::builder()
+            .secret("secret_test")
+            .transport(Cassette::record_with(&path, UserTransport))
+            .build();
+
+        let recorded = client.get_self().send().await.unwrap();
+
+        let contents = std::fs::read_to_string(&path).unwrap();
+
+        assert!(contents.contains("[REDACTED]"));
+        assert!(!contents.contains("secret_test"));
+
+        let client = crate::client::Client::builder()
+            .transport(Cassette::replay(&path).unwrap())
+            .build();
+
+        let replayed = client.get_self().send().await.unwrap();
+
+        assert_eq!(replayed.id, recorded.id);
+
+        // Each response is replayed once.
+        let error = client.get_self().send().await.unwrap_err();
+
+        assert!(matches!(error, Error::Cassette(_)));
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn match_normalized_body() {
+        let recorded = RecordedRequest {
+            method: "POST".to_string(),
+            path: "/v1/databases/d9824bdc84454327be8b5b47500af6ce/query".to_string(),
+            headers: BTreeMap::new(),
+            body: Some(parse_body(
+                br#"{"page_size":100,"filter":{"property":"Price"}}"#,
+            )),
+        };
+
+        let request = RecordedRequest {
+            headers: BTreeMap::from([("authorization".to_string(), "Bearer x".to_string())]),
+            body: Some(parse_body(
+                br#"{ "filter": { "property": "Price" }, "page_size": 100 }"#,
+            )),
+            ..recorded.clone()
+        };
+
+        assert!(recorded.matches(&request));
+
+        let request = RecordedRequest {
+            body: Some(parse_body(br#"{"page_size":50}"#)),
+            ..recorded.clone()
+        };
+
+        assert!(!recorded.matches(&request));
+    }
+}
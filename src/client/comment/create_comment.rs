@@ -0,0 +1,141 @@
+use serde::Serialize;
+
+use crate::{comment::Comment, error::Error, others::parent::Parent, RichText};
+
+/// A request builder for creating a comment.
+///
+/// Specify either `page_id` to start a new discussion on a page,
+/// or `discussion_id` to reply inside an existing discussion thread.
+#[derive(Debug, Default)]
+pub struct CreateCommentClient {
+    /// The Notion API client
+    pub(crate) client: crate::client::Client,
+
+    pub(crate) page_id: Option<crate::others::id::PageId>,
+
+    pub(crate) discussion_id: Option<String>,
+
+    pub(crate) rich_text: Vec<RichText>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct CreateCommentRequestBody {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) parent: Option<Parent>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) discussion_id: Option<String>,
+
+    pub(crate) rich_text: Vec<RichText>,
+}
+
+impl CreateCommentClient {
+    /// Send a request to the API endpoint of Notion.
+    pub async fn send(self) -> Result<Comment, Error> {
+        let request_body_struct = self.request_body()?;
+
+        let request_body = serde_json::to_string(&request_body_struct)?;
+
+        let request = self
+            .client
+            .request(reqwest::Method::POST, "/comments")
+            .header("Content-Type", "application/json")
+            .body(request_body);
+
+        let body = self.client.send(request).await?;
+
+        let comment = crate::client::deserialize_body::<Comment>(&body)?;
+
+        Ok(comment)
+    }
+
+    fn request_body(&self) -> Result<CreateCommentRequestBody, Error> {
+        let parent = match (&self.page_id, &self.discussion_id) {
+            (Some(page_id), None) => Some(Parent::PageParent(page_id.as_str().into())),
+            (None, Some(_)) => None,
+            _ => {
+                return Err(Error::RequestParameter(
+                    "You need to specify either the page_id or the discussion_id.".to_string(),
+                ))
+            }
+        };
+
+        Ok(CreateCommentRequestBody {
+            parent,
+            discussion_id: self.discussion_id.clone(),
+            rich_text: self.rich_text.clone(),
+        })
+    }
+
+    /// Starts a new discussion on the page.
+    pub fn page_id<T: Into<crate::others::id::PageId>>(mut self, page_id: T) -> Self {
+        self.page_id = Some(page_id.into());
+        self
+    }
+
+    /// Adds the comment to an existing discussion thread.
+    /// The ID is available as `discussion_id` in the [`Comment`] object.
+    pub fn discussion_id<T: AsRef<str>>(mut self, discussion_id: T) -> Self {
+        self.discussion_id = Some(discussion_id.as_ref().to_string());
+        self
+    }
+
+    /// The content of the comment.
+    pub fn rich_text(mut self, rich_text: Vec<RichText>) -> Self {
+        self.rich_text = rich_text;
+        self
+    }
+}
+
+// # --------------------------------------------------------------------------------
+//
+// unit test
+//
+// # --------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod unit_tests {
+
+    use super::*;
+
+    #[test]
+    fn serialize_request_body() {
+        let client = crate::client::Client::new();
+
+        let body = client
+            .create_comment()
+            .page_id("5c6a2821-6bb1-4a7e-b6e1-c50111515c3d")
+            .rich_text(vec![RichText::from("Hello")])
+            .request_body()
+            .unwrap();
+
+        let json = serde_json::to_value(body).unwrap();
+
+        assert_eq!(
+            json["parent"]["page_id"],
+            "5c6a2821-6bb1-4a7e-b6e1-c50111515c3d"
+        );
+        assert!(json.get("discussion_id").is_none());
+
+        let body = client
+            .create_comment()
+            .discussion_id("f1407351-36f5-4c49-a13c-49f8ba11776d")
+            .request_body()
+            .unwrap();
+
+        let json = serde_json::to_value(body).unwrap();
+
+        assert!(json.get("parent").is_none());
+        assert_eq!(
+            json["discussion_id"],
+            "f1407351-36f5-4c49-a13c-49f8ba11776d"
+        );
+
+        assert!(client
+            .create_comment()
+            .page_id("xxx")
+            .discussion_id("yyy")
+            .request_body()
+            .is_err());
+    }
+}
@@ -0,0 +1,119 @@
+use serde::Serialize;
+
+use crate::{
+    client::pagination::{self, PaginatedStream},
+    comment::Comment,
+    error::Error,
+    list_response::ListResponse,
+};
+
+/// A request builder for listing the unresolved comments on a page or block.
+#[derive(Debug, Default)]
+pub struct ListCommentsClient {
+    /// The Notion API client
+    pub(crate) client: crate::client::Client,
+
+    pub(crate) block_id: Option<crate::others::id::BlockId>,
+
+    pub(crate) start_cursor: Option<String>,
+
+    pub(crate) page_size: Option<u32>,
+}
+
+/// Query parameters for making requests to the endpoint.
+#[derive(Serialize)]
+struct ListCommentsQueryParams<'a> {
+    block_id: &'a str,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    start_cursor: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page_size: Option<u32>,
+}
+
+impl ListCommentsClient {
+    /// Send a request to the API endpoint of Notion.
+    /// Up to `page_size` (100 by default) comments are returned.
+    pub async fn send(self) -> Result<ListResponse<Comment>, Error> {
+        let block_id = self
+            .block_id
+            .as_ref()
+            .map(|block_id| block_id.as_str())
+            .ok_or(Error::RequestParameter("block_id is empty".to_string()))?;
+
+        fetch_page(
+            &self.client,
+            &ListCommentsQueryParams {
+                block_id,
+                start_cursor: self.start_cursor.clone(),
+                page_size: self.page_size,
+            },
+        )
+        .await
+    }
+
+    /// Returns a stream that yields all the comments one by one.
+    ///
+    /// Comments are fetched lazily, `page_size` (100 by default) per request,
+    /// and dropping the stream stops fetching.
+    pub fn into_stream(self) -> PaginatedStream<Comment> {
+        let Some(block_id) = self.block_id else {
+            return pagination::fail(Error::RequestParameter("block_id is empty".to_string()));
+        };
+
+        let client = self.client;
+        let page_size = self.page_size.unwrap_or(pagination::MAX_PAGE_SIZE);
+
+        pagination::paginate(self.start_cursor, move |cursor| {
+            let client = client.clone();
+            let block_id = block_id.clone();
+
+            async move {
+                fetch_page(
+                    &client,
+                    &ListCommentsQueryParams {
+                        block_id: block_id.as_str(),
+                        start_cursor: cursor,
+                        page_size: Some(page_size),
+                    },
+                )
+                .await
+            }
+        })
+    }
+
+    /// The ID of the page or block whose comments are listed.
+    pub fn block_id<T: Into<crate::others::id::BlockId>>(mut self, block_id: T) -> Self {
+        self.block_id = Some(block_id.into());
+        self
+    }
+
+    /// Performs cursor-based pagination when data cannot be fetched in one go.
+    pub fn start_cursor<T: AsRef<str>>(mut self, start_cursor: T) -> Self {
+        self.start_cursor = Some(start_cursor.as_ref().to_string());
+        self
+    }
+
+    /// The number of comments fetched at once. Valid range for the number is 1~100.
+    pub fn page_size(mut self, page_size: u32) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+}
+
+/// Fetches one page of comments.
+async fn fetch_page(
+    client: &crate::client::Client,
+    params: &ListCommentsQueryParams<'_>,
+) -> Result<ListResponse<Comment>, Error> {
+    let request = client
+        .request(reqwest::Method::GET, "/comments")
+        .query(params);
+
+    let body = client.send(request).await?;
+
+    let comments = crate::client::deserialize_body::<ListResponse<Comment>>(&body)?;
+
+    Ok(comments)
+}
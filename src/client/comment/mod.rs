@@ -0,0 +1,2 @@
+pub mod create_comment;
+pub mod list_comments;
@@ -1,16 +1,13 @@
 use serde::{Deserialize, Serialize};
 
-use crate::{
-    error::{api_error::ApiError, Error},
-    RichText,
-};
+use crate::{error::Error, RichText};
 
 #[derive(Debug, Default)]
 pub struct CreateDatabaseClient {
-    /// The reqwest http client
-    pub(crate) reqwest_client: reqwest::Client,
+    /// The Notion API client
+    pub(crate) client: crate::client::Client,
 
-    pub(crate) page_id: Option<String>,
+    pub(crate) page_id: Option<crate::others::id::PageId>,
 
     pub(crate) title: Vec<RichText>,
 
@@ -65,34 +62,24 @@ impl CreateDatabaseClient {
 
         let request_body = serde_json::to_string(&request_body_struct)?;
 
-        let url = "https://api.notion.com/v1/databases".to_string();
+        let url = "/databases".to_string();
 
         let request = self
-            .reqwest_client
-            .post(url)
+            .client
+            .request(reqwest::Method::POST, url)
             .header("Content-Type", "application/json")
             .body(request_body);
 
-        let response = request.send().await?;
-
-        if !response.status().is_success() {
-            let error_body = response.text().await?;
-
-            let error_json = serde_json::from_str::<ApiError>(&error_body)?;
-
-            return Err(Error::Api(Box::new(error_json)));
-        }
-
-        let body = response.text().await?;
+        let body = self.client.send(request).await?;
 
         let database: crate::database::DatabaseResponse =
-            serde_json::from_str::<crate::database::DatabaseResponse>(&body)?;
+            crate::client::deserialize_body::<crate::database::DatabaseResponse>(&body)?;
 
         Ok(database)
     }
 
-    pub fn page_id<T: AsRef<str>>(mut self, page_id: T) -> Self {
-        self.page_id = Some(page_id.as_ref().to_string());
+    pub fn page_id<T: Into<crate::others::id::PageId>>(mut self, page_id: T) -> Self {
+        self.page_id = Some(page_id.into());
         self
     }
 
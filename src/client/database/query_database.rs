@@ -1,7 +1,9 @@
+use futures::{StreamExt, TryStreamExt};
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    error::{api_error::ApiError, Error},
+    client::pagination::{self, PaginatedStream},
+    error::Error,
     filter::Filter,
     list_response::ListResponse,
     page::page_response::PageResponse,
@@ -10,17 +12,19 @@ use crate::{
 
 #[derive(Debug, Default)]
 pub struct QueryDatabaseClient {
-    /// The reqwest http client
-    pub(crate) reqwest_client: reqwest::Client,
+    /// The Notion API client
+    pub(crate) client: crate::client::Client,
 
-    pub(crate) database_id: Option<String>,
+    pub(crate) database_id: Option<crate::others::id::DatabaseId>,
 
     pub(crate) body: QueryDatabaseRequestBody,
 
     pub(crate) fetch_all: bool,
+
+    pub(crate) validate_filter: bool,
 }
 
-#[derive(Debug, Serialize, Deserialize, Default)]
+#[derive(Debug, Serialize, Deserialize, Default, Clone)]
 pub struct QueryDatabaseRequestBody {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub(crate) filter: Option<Filter>,
@@ -36,83 +40,100 @@ pub struct QueryDatabaseRequestBody {
 }
 
 impl QueryDatabaseClient {
-    pub async fn send(mut self) -> Result<ListResponse<PageResponse>, Error> {
-        match self.database_id {
-            Some(id) => {
-                if self.fetch_all {
-                    let mut results: Vec<PageResponse> = vec![];
-
-                    self.body.page_size = Some(100);
-
-                    loop {
-                        let url = format!("https://api.notion.com/v1/databases/{}/query", id);
-
-                        let request_body = self.body.to_json().to_string();
-
-                        let request = self
-                            .reqwest_client
-                            .post(url)
-                            .header("Content-Type", "application/json")
-                            .body(request_body);
-
-                        let response = request.send().await?;
-
-                        if !response.status().is_success() {
-                            let error_body = response.text().await?;
-
-                            let error_json = serde_json::from_str::<ApiError>(&error_body)?;
-
-                            return Err(Error::Api(Box::new(error_json)));
-                        }
-
-                        let body = response.text().await?;
-
-                        let mut pages = serde_json::from_str::<ListResponse<PageResponse>>(&body)?;
-
-                        results.extend(pages.results);
-
-                        if pages.has_more.unwrap_or(false) {
-                            self.body.start_cursor = pages.next_cursor;
-                        } else {
-                            pages.results = results;
-                            return Ok(pages);
-                        }
-                    }
-                } else {
-                    let url = format!("https://api.notion.com/v1/databases/{}/query", id);
-
-                    let request_body = self.body.to_json().to_string();
-
-                    let request = self
-                        .reqwest_client
-                        .post(url)
-                        .header("Content-Type", "application/json")
-                        .body(request_body);
-
-                    let response = request.send().await?;
-
-                    if !response.status().is_success() {
-                        let error_body = response.text().await?;
-
-                        let error_json = serde_json::from_str::<ApiError>(&error_body)?;
+    pub async fn send(self) -> Result<ListResponse<PageResponse>, Error> {
+        let database_id = self
+            .database_id
+            .as_ref()
+            .map(|database_id| database_id.as_str())
+            .ok_or(Error::RequestParameter("database_id is empty".to_string()))?;
+
+        if self.fetch_all {
+            let results = self
+                .into_stream()
+                .try_collect::<Vec<PageResponse>>()
+                .await?;
+
+            return Ok(ListResponse {
+                object: "list".to_string(),
+                results,
+                next_cursor: None,
+                has_more: Some(false),
+                r#type: Some("page_or_database".to_string()),
+            });
+        }
 
-                        return Err(Error::Api(Box::new(error_json)));
-                    }
+        if self.validate_filter {
+            validate_filter(&self.client, database_id, self.body.filter.as_ref()).await?;
+        }
 
-                    let body = response.text().await?;
+        fetch_page(&self.client, database_id, &self.body).await
+    }
 
-                    let pages = serde_json::from_str::<ListResponse<PageResponse>>(&body)?;
+    /// Returns a stream that yields the pages in the database one by one.
+    ///
+    /// Pages are fetched lazily, `page_size` (100 by default) records per request,
+    /// so even large databases can be processed with bounded memory.
+    /// Dropping the stream stops fetching. `fetch_all` has no effect on the stream.
+    ///
+    /// ```no_run
+    /// use futures::{StreamExt, TryStreamExt};
+    ///
+    /// # async fn run() -> Result<(), notionrs::error::Error> {
+    /// let client = notionrs::Client::new();
+    ///
+    /// let mut stream = client
+    ///     .query_database()
+    ///     .database_id("DATABASE_ID")
+    ///     .into_stream();
+    ///
+    /// while let Some(page) = stream.try_next().await? {
+    ///     println!("{}", page.id);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn into_stream(self) -> PaginatedStream<PageResponse> {
+        let Some(database_id) = self.database_id else {
+            return pagination::fail(Error::RequestParameter("database_id is empty".to_string()));
+        };
+
+        let client = self.client;
+        let validate = self.validate_filter;
+
+        let mut body = self.body;
+        body.page_size.get_or_insert(pagination::MAX_PAGE_SIZE);
+        let start_cursor = body.start_cursor.take();
+
+        let filter = body.filter.clone();
+
+        let pages = pagination::paginate(start_cursor, {
+            let client = client.clone();
+            let database_id = database_id.clone();
+            move |cursor| {
+                let client = client.clone();
+                let database_id = database_id.clone();
+                let body = QueryDatabaseRequestBody {
+                    start_cursor: cursor,
+                    ..body.clone()
+                };
+
+                async move { fetch_page(&client, database_id.as_str(), &body).await }
+            }
+        });
 
-                    Ok(pages)
-                }
+        futures::stream::once(async move {
+            if validate {
+                validate_filter(&client, database_id.as_str(), filter.as_ref()).await?;
             }
-            None => Err(Error::RequestParameter("database_id is empty".to_string())),
-        }
+            Ok::<_, Error>(pages)
+        })
+        .try_flatten()
+        .boxed()
     }
 
     /// Specify the ID of the database to query.
-    pub fn database_id<T: AsRef<str>>(mut self, database_id: T) -> Self {
-        self.database_id = Some(database_id.as_ref().to_string());
+    pub fn database_id<T: Into<crate::others::id::DatabaseId>>(mut self, database_id: T) -> Self {
+        self.database_id = Some(database_id.into());
         self
     }
 
@@ -134,11 +155,24 @@ impl QueryDatabaseClient {
 
     /// Normally, you can only retrieve up to 100 records in one query,
     /// but by setting fetch_all to true, you can retrieve all the data.
+    ///
+    /// All records are kept in memory. Use [`QueryDatabaseClient::into_stream`]
+    /// to process them one by one instead.
     pub fn fetch_all(mut self) -> Self {
         self.fetch_all = true;
         self
     }
 
+    /// Retrieves the database and checks the filter against its properties with
+    /// [`Filter::validate`] before querying. An invalid filter fails with `Error::Filter`
+    /// instead of a `validation_error` from the API.
+    ///
+    /// This costs an extra request.
+    pub fn validate_filter(mut self) -> Self {
+        self.validate_filter = true;
+        self
+    }
+
     pub fn filter(mut self, filter: Filter) -> Self {
         self.body.filter = Some(filter);
         self
@@ -148,4 +182,69 @@ impl QueryDatabaseClient {
         self.body.sorts = sorts;
         self
     }
+
+    /// Sorts the results by `sort`, replacing the sorts set so far.
+    ///
+    /// ```no_run
+    /// use notionrs::database::Sort;
+    ///
+    /// let request = notionrs::Client::new()
+    ///     .query_database()
+    ///     .database_id("d9824bdc84454327be8b5b47500af6ce")
+    ///     .sort_by(Sort::desc("Price"))
+    ///     .then_by(Sort::created_time_asc());
+    /// ```
+    pub fn sort_by(mut self, sort: crate::database::Sort) -> Self {
+        self.body.sorts = vec![sort];
+        self
+    }
+
+    /// Adds a sort that orders the results that are equal by the previous sorts.
+    pub fn then_by(mut self, sort: crate::database::Sort) -> Self {
+        self.body.sorts.push(sort);
+        self
+    }
+}
+
+/// Retrieves the database and validates the filter against it.
+async fn validate_filter(
+    client: &crate::client::Client,
+    database_id: &str,
+    filter: Option<&Filter>,
+) -> Result<(), Error> {
+    let Some(filter) = filter else {
+        return Ok(());
+    };
+
+    let database = client
+        .retrieve_database()
+        .database_id(database_id)
+        .send()
+        .await?;
+
+    filter.validate(&database)?;
+
+    Ok(())
+}
+
+/// Fetches one page of the query result.
+async fn fetch_page(
+    client: &crate::client::Client,
+    database_id: &str,
+    body: &QueryDatabaseRequestBody,
+) -> Result<ListResponse<PageResponse>, Error> {
+    let url = format!("/databases/{}/query", database_id);
+
+    let request_body = body.to_json().to_string();
+
+    let request = client
+        .request(reqwest::Method::POST, url)
+        .header("Content-Type", "application/json")
+        .body(request_body);
+
+    let body = client.send_read_only(request).await?;
+
+    let pages = crate::client::deserialize_body::<ListResponse<PageResponse>>(&body)?;
+
+    Ok(pages)
 }
@@ -1,11 +1,11 @@
-use crate::error::{api_error::ApiError, Error};
+use crate::error::Error;
 
 #[derive(Debug, Default)]
 pub struct RetrieveDatabaseClient {
-    /// The reqwest http client
-    pub(crate) reqwest_client: reqwest::Client,
+    /// The Notion API client
+    pub(crate) client: crate::client::Client,
 
-    pub(crate) database_id: Option<String>,
+    pub(crate) database_id: Option<crate::others::id::DatabaseId>,
 }
 
 impl RetrieveDatabaseClient {
@@ -14,29 +14,19 @@ impl RetrieveDatabaseClient {
             "`database_id` has not been set.".to_string(),
         ))?;
 
-        let url = format!("https://api.notion.com/v1/databases/{}", database_id);
+        let url = format!("/databases/{}", database_id);
 
-        let request = self.reqwest_client.get(url);
+        let request = self.client.request(reqwest::Method::GET, url);
 
-        let response = request.send().await?;
+        let body = self.client.send(request).await?;
 
-        if !response.status().is_success() {
-            let error_body = response.text().await?;
-
-            let error_json = serde_json::from_str::<ApiError>(&error_body)?;
-
-            return Err(Error::Api(Box::new(error_json)));
-        }
-
-        let body = response.text().await?;
-
-        let database = serde_json::from_str::<crate::database::DatabaseResponse>(&body)?;
+        let database = crate::client::deserialize_body::<crate::database::DatabaseResponse>(&body)?;
 
         Ok(database)
     }
 
-    pub fn database_id<T: AsRef<str>>(mut self, database_id: T) -> Self {
-        self.database_id = Some(database_id.as_ref().to_string());
+    pub fn database_id<T: Into<crate::others::id::DatabaseId>>(mut self, database_id: T) -> Self {
+        self.database_id = Some(database_id.into());
         self
     }
 }
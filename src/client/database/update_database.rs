@@ -1,16 +1,13 @@
 use serde::{Deserialize, Serialize};
 
-use crate::{
-    error::{api_error::ApiError, Error},
-    RichText,
-};
+use crate::{error::Error, RichText};
 
 #[derive(Debug, Default)]
 pub struct UpdateDatabaseClient {
-    /// The reqwest http client
-    pub(crate) reqwest_client: reqwest::Client,
+    /// The Notion API client
+    pub(crate) client: crate::client::Client,
 
-    pub(crate) database_id: Option<String>,
+    pub(crate) database_id: Option<crate::others::id::DatabaseId>,
 
     pub(crate) title: Vec<RichText>,
 
@@ -72,34 +69,24 @@ impl UpdateDatabaseClient {
 
         let request_body = serde_json::to_string(&request_body_struct)?;
 
-        let url = format!("https://api.notion.com/v1/databases/{}", database_id);
+        let url = format!("/databases/{}", database_id);
 
         let request = self
-            .reqwest_client
-            .patch(url)
+            .client
+            .request(reqwest::Method::PATCH, url)
             .header("Content-Type", "application/json")
             .body(request_body);
 
-        let response = request.send().await?;
-
-        if !response.status().is_success() {
-            let error_body = response.text().await?;
-
-            let error_json = serde_json::from_str::<ApiError>(&error_body)?;
-
-            return Err(Error::Api(Box::new(error_json)));
-        }
-
-        let body = response.text().await?;
+        let body = self.client.send(request).await?;
 
         let database: crate::database::DatabaseResponse =
-            serde_json::from_str::<crate::database::DatabaseResponse>(&body)?;
+            crate::client::deserialize_body::<crate::database::DatabaseResponse>(&body)?;
 
         Ok(database)
     }
 
-    pub fn databse_id<T: AsRef<str>>(mut self, databse_id: T) -> Self {
-        self.database_id = Some(databse_id.as_ref().to_string());
+    pub fn databse_id<T: Into<crate::others::id::DatabaseId>>(mut self, databse_id: T) -> Self {
+        self.database_id = Some(databse_id.into());
         self
     }
 
@@ -0,0 +1,315 @@
+use std::path::PathBuf;
+
+use serde::Serialize;
+use tokio::io::{AsyncRead, AsyncReadExt};
+
+use crate::{error::Error, file_upload::FileUpload};
+
+/// The maximum size of a single-part upload, and of each part of a multi-part upload.
+pub(crate) const MAX_PART_SIZE: u64 = 20 * 1024 * 1024;
+
+/// The minimum size of each part (except the last one) of a multi-part upload.
+pub(crate) const MIN_PART_SIZE: u64 = 5 * 1024 * 1024;
+
+/// A request builder for uploading a file with the File Upload API.
+///
+/// Files up to `part_size` bytes are sent in a single request. Larger files are split
+/// into parts of `part_size` bytes, and only one part is held in memory at a time.
+#[derive(Debug)]
+pub struct UploadFileClient {
+    /// The Notion API client
+    pub(crate) client: crate::client::Client,
+
+    pub(crate) source: Option<UploadSource>,
+
+    pub(crate) filename: Option<String>,
+
+    pub(crate) content_type: Option<String>,
+
+    pub(crate) part_size: u64,
+}
+
+/// Where the file contents are read from.
+pub(crate) enum UploadSource {
+    Path(PathBuf),
+    Reader {
+        reader: Box<dyn AsyncRead + Send + Unpin>,
+        content_length: u64,
+    },
+}
+
+impl std::fmt::Debug for UploadSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            UploadSource::Path(path) => f.debug_tuple("Path").field(path).finish(),
+            UploadSource::Reader { content_length, .. } => f
+                .debug_struct("Reader")
+                .field("content_length", content_length)
+                .finish_non_exhaustive(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct CreateFileUploadRequestBody {
+    /// "single_part" or "multi_part"
+    pub(crate) mode: String,
+
+    pub(crate) filename: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) content_type: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) number_of_parts: Option<u64>,
+}
+
+impl UploadFileClient {
+    /// Uploads the file and returns the completed [`FileUpload`].
+    ///
+    /// Attach it with [`File::file_upload`](crate::File::file_upload) using its `id`
+    /// before it expires.
+    pub async fn send(self) -> Result<FileUpload, Error> {
+        let (mut reader, content_length, filename): (Box<dyn AsyncRead + Send + Unpin>, _, _) =
+            match self.source {
+                Some(UploadSource::Path(path)) => {
+                    let file = tokio::fs::File::open(&path).await?;
+                    let content_length = file.metadata().await?.len();
+                    let filename = self.filename.or_else(|| {
+                        path.file_name()
+                            .map(|name| name.to_string_lossy().to_string())
+                    });
+                    (Box::new(file), content_length, filename)
+                }
+                Some(UploadSource::Reader {
+                    reader,
+                    content_length,
+                }) => (reader, content_length, self.filename),
+                None => {
+                    return Err(Error::RequestParameter(
+                        "Either `path` or `reader` must be set.".to_string(),
+                    ))
+                }
+            };
+
+        let filename = filename.ok_or(Error::RequestParameter(
+            "`filename` must be set when uploading from a reader.".to_string(),
+        ))?;
+
+        let part_size = self.part_size.max(1);
+
+        let number_of_parts = content_length.div_ceil(part_size);
+
+        let multi_part = number_of_parts > 1;
+
+        let request_body_struct = CreateFileUploadRequestBody {
+            mode: if multi_part {
+                "multi_part".to_string()
+            } else {
+                "single_part".to_string()
+            },
+            filename: filename.clone(),
+            content_type: self.content_type.clone(),
+            number_of_parts: multi_part.then_some(number_of_parts),
+        };
+
+        let request_body = serde_json::to_string(&request_body_struct)?;
+
+        let request = self
+            .client
+            .request(reqwest::Method::POST, "/file_uploads")
+            .header("Content-Type", "application/json")
+            .body(request_body);
+
+        let body = self.client.send(request).await?;
+
+        let mut file_upload = crate::client::deserialize_body::<FileUpload>(&body)?;
+
+        for part_number in 1..=number_of_parts.max(1) {
+            let mut part = Vec::new();
+
+            (&mut reader).take(part_size).read_to_end(&mut part).await?;
+
+            let mut file_part = reqwest::multipart::Part::bytes(part).file_name(filename.clone());
+
+            if let Some(content_type) = &self.content_type {
+                file_part = file_part.mime_str(content_type)?;
+            }
+
+            let mut form = reqwest::multipart::Form::new().part("file", file_part);
+
+            if multi_part {
+                form = form.text("part_number", part_number.to_string());
+            }
+
+            let url = format!("/file_uploads/{}/send", file_upload.id);
+
+            let request = self
+                .client
+                .request(reqwest::Method::POST, url)
+                .multipart(form);
+
+            let body = self.client.send(request).await?;
+
+            file_upload = crate::client::deserialize_body::<FileUpload>(&body)?;
+        }
+
+        if multi_part {
+            let url = format!("/file_uploads/{}/complete", file_upload.id);
+
+            let request = self.client.request(reqwest::Method::POST, url);
+
+            let body = self.client.send(request).await?;
+
+            file_upload = crate::client::deserialize_body::<FileUpload>(&body)?;
+        }
+
+        Ok(file_upload)
+    }
+
+    /// Uploads the file at the path.
+    /// Unless `filename` is set, the file name of the path is used.
+    pub fn path<T: Into<PathBuf>>(mut self, path: T) -> Self {
+        self.source = Some(UploadSource::Path(path.into()));
+        self
+    }
+
+    /// Uploads `content_length` bytes read from the reader.
+    /// `filename` must also be set.
+    pub fn reader<R>(mut self, reader: R, content_length: u64) -> Self
+    where
+        R: AsyncRead + Send + Unpin + 'static,
+    {
+        self.source = Some(UploadSource::Reader {
+            reader: Box::new(reader),
+            content_length,
+        });
+        self
+    }
+
+    /// The name of the file, including its extension.
+    pub fn filename<T: AsRef<str>>(mut self, filename: T) -> Self {
+        self.filename = Some(filename.as_ref().to_string());
+        self
+    }
+
+    /// The MIME type of the file (e.g. `image/png`).
+    /// If not specified, Notion infers it from the file name.
+    pub fn content_type<T: AsRef<str>>(mut self, content_type: T) -> Self {
+        self.content_type = Some(content_type.as_ref().to_string());
+        self
+    }
+
+    /// The size of each part of a multi-part upload. Defaults to 20MB.
+    /// Files up to this size are sent in a single request.
+    ///
+    /// The value is clamped to the range accepted by Notion (5MB ~ 20MB).
+    pub fn part_size(mut self, part_size: u64) -> Self {
+        self.part_size = part_size.clamp(MIN_PART_SIZE, MAX_PART_SIZE);
+        self
+    }
+}
+
+// # --------------------------------------------------------------------------------
+//
+// unit test
+//
+// # --------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod unit_tests {
+    use std::sync::{Arc, Mutex};
+
+    use super::*;
+
+    /// Records the request paths and responds with a file upload object.
+    #[derive(Debug, Default)]
+    struct FileUploadTransport {
+        requests: Arc<Mutex<Vec<String>>>,
+    }
+
+    #[async_trait::async_trait]
+    impl crate::client::Transport for FileUploadTransport {
+        async fn execute(&self, request: reqwest::Request) -> Result<reqwest::Response, Error> {
+            let mut entry = request.url().path().to_string();
+
+            if let Some(body) = request.body().and_then(|body| body.as_bytes()) {
+                entry.push(' ');
+                entry.push_str(std::str::from_utf8(body).unwrap());
+            }
+
+            self.requests.lock().unwrap().push(entry);
+
+            let body = r#"
+            {
+                "object": "file_upload",
+                "id": "43833259-72ae-404e-8441-b6577f3159b4",
+                "created_time": "2025-03-15T20:53:00.000Z",
+                "last_edited_time": "2025-03-15T20:53:00.000Z",
+                "expiry_time": null,
+                "status": "uploaded",
+                "filename": "notes.txt",
+                "content_type": "text/plain",
+                "content_length": 25
+            }
+            "#;
+
+            Ok(reqwest::Response::from(http::Response::new(body)))
+        }
+    }
+
+    fn client(requests: Arc<Mutex<Vec<String>>>) -> crate::client::Client {
+        crate::client::Client::builder()
+            .secret("secret_test")
+            .transport(FileUploadTransport { requests })
+            .build()
+    }
+
+    #[tokio::test]
+    async fn upload_single_part() {
+        let requests = Arc::new(Mutex::new(Vec::new()));
+
+        let file_upload = client(requests.clone())
+            .upload_file()
+            .reader(&b"hello"[..], 5)
+            .filename("notes.txt")
+            .send()
+            .await
+            .unwrap();
+
+        assert_eq!(file_upload.id, "43833259-72ae-404e-8441-b6577f3159b4");
+
+        assert_eq!(
+            *requests.lock().unwrap(),
+            vec![
+                r#"/v1/file_uploads {"mode":"single_part","filename":"notes.txt"}"#,
+                "/v1/file_uploads/43833259-72ae-404e-8441-b6577f3159b4/send",
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn upload_multi_part() {
+        let requests = Arc::new(Mutex::new(Vec::new()));
+
+        let mut upload_file = client(requests.clone())
+            .upload_file()
+            .reader(&[0u8; 25][..], 25)
+            .filename("notes.txt");
+
+        upload_file.part_size = 10;
+
+        upload_file.send().await.unwrap();
+
+        assert_eq!(
+            *requests.lock().unwrap(),
+            vec![
+                r#"/v1/file_uploads {"mode":"multi_part","filename":"notes.txt","number_of_parts":3}"#,
+                "/v1/file_uploads/43833259-72ae-404e-8441-b6577f3159b4/send",
+                "/v1/file_uploads/43833259-72ae-404e-8441-b6577f3159b4/send",
+                "/v1/file_uploads/43833259-72ae-404e-8441-b6577f3159b4/send",
+                "/v1/file_uploads/43833259-72ae-404e-8441-b6577f3159b4/complete",
+            ]
+        );
+    }
+}
@@ -1,41 +1,73 @@
 pub mod block;
+pub mod comment;
 pub mod database;
+pub mod file_upload;
 pub mod page;
 pub mod search;
 pub mod user;
 
-use std::env;
+use std::sync::Arc;
 
-#[derive(Default, Debug)]
+pub mod builder;
+pub mod cassette;
+pub mod pagination;
+pub mod rate_limit;
+pub mod retry;
+pub mod transport;
+
+pub use builder::ClientBuilder;
+pub use cassette::Cassette;
+pub use pagination::PaginatedStream;
+pub use rate_limit::RateLimiter;
+pub use retry::RetryPolicy;
+pub use transport::Transport;
+
+/// The base URL used when none is configured with [`ClientBuilder::base_url`].
+pub const DEFAULT_BASE_URL: &str = "https://api.notion.com/v1";
+
+/// The value sent in the `Notion-Version` header.
+pub(crate) const NOTION_VERSION: &str = "2022-06-28";
+
+#[derive(Debug, Clone)]
 pub struct Client {
-    reqwest_client: reqwest::Client,
+    /// The reqwest http client used to build requests.
+    pub(crate) reqwest_client: reqwest::Client,
+
+    /// The base URL to which the endpoint paths are appended.
+    pub(crate) base_url: String,
+
+    /// The token used for calling the Notion API.
+    pub(crate) secret: String,
+
+    /// The HTTP layer used to send requests.
+    pub(crate) transport: Arc<dyn Transport>,
+
+    /// How failed requests are retried.
+    pub(crate) retry_policy: RetryPolicy,
+
+    /// Throttles requests. Shared between all clones of this client.
+    pub(crate) rate_limiter: Option<RateLimiter>,
+}
+
+impl Default for Client {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl Client {
-    // TODO: docs: new method
+    /// Creates a client with the default configuration.
+    /// The token is read from the environment variable named `NOTION_TOKEN`.
+    ///
+    /// Use [`Client::builder`] to configure the base URL or the HTTP transport.
     pub fn new() -> Self {
-        let mut headers = reqwest::header::HeaderMap::new();
-
-        let secret = env::var("NOTION_TOKEN").unwrap_or_else(|_| String::new());
-
-        headers.insert(
-            "Notion-Version",
-            reqwest::header::HeaderValue::from_static("2022-06-28"),
-        );
-        headers.insert(
-            "Authorization",
-            reqwest::header::HeaderValue::from_str(&format!("Bearer {}", secret))
-                .expect("Invalid header value"),
-        );
-
-        let client = reqwest::Client::builder()
-            .default_headers(headers)
-            .build()
-            .unwrap();
-
-        Client {
-            reqwest_client: client,
-        }
+        ClientBuilder::default().build()
+    }
+
+    /// Returns a [`ClientBuilder`] for configuring the token, the base URL
+    /// and the HTTP transport.
+    pub fn builder() -> ClientBuilder {
+        ClientBuilder::default()
     }
 
     /// This method sets the token used for calling the Notion API.
@@ -54,26 +86,102 @@ impl Client {
     where
         T: AsRef<str>,
     {
-        let mut headers = reqwest::header::HeaderMap::new();
-        let secret = notion_api_key.as_ref().to_string();
-
-        headers.insert(
-            "Notion-Version",
-            reqwest::header::HeaderValue::from_static("2022-06-28"),
-        );
-        headers.insert(
-            "Authorization",
-            reqwest::header::HeaderValue::from_str(&format!("Bearer {}", secret))
-                .expect("Invalid header value"),
-        );
-
-        self.reqwest_client = reqwest::Client::builder()
-            .default_headers(headers)
-            .build()
-            .unwrap();
+        self.secret = notion_api_key.as_ref().to_string();
         self
     }
 
+    /// Creates a request to `{base_url}{path}` with the `Authorization`
+    /// and `Notion-Version` headers attached.
+    pub(crate) fn request<T>(&self, method: reqwest::Method, path: T) -> reqwest::RequestBuilder
+    where
+        T: AsRef<str>,
+    {
+        self.reqwest_client
+            .request(method, format!("{}{}", self.base_url, path.as_ref()))
+            .header("Notion-Version", NOTION_VERSION)
+            .bearer_auth(&self.secret)
+    }
+
+    /// Sends the request through the configured transport and returns the response body.
+    /// Non-2xx responses are converted into `Error::Api`.
+    ///
+    /// Use this for requests that create, update or delete resources.
+    /// They are retried only on `429` unless `RetryPolicy::retry_mutating` is enabled.
+    pub(crate) async fn send(
+        &self,
+        request: reqwest::RequestBuilder,
+    ) -> Result<String, crate::error::Error> {
+        self.execute(request, false).await
+    }
+
+    /// Same as [`Client::send`], for requests that don't modify anything
+    /// (e.g. querying a database, searching). They are also retried on `5xx`.
+    pub(crate) async fn send_read_only(
+        &self,
+        request: reqwest::RequestBuilder,
+    ) -> Result<String, crate::error::Error> {
+        self.execute(request, true).await
+    }
+
+    async fn execute(
+        &self,
+        request: reqwest::RequestBuilder,
+        read_only: bool,
+    ) -> Result<String, crate::error::Error> {
+        let mut request = request.build()?;
+
+        let read_only = read_only || request.method().is_safe();
+
+        let mut attempt: u32 = 1;
+
+        let response = loop {
+            // Requests with a streaming body cannot be cloned, so they are never retried.
+            let next_request = if attempt < self.retry_policy.max_attempts {
+                request.try_clone()
+            } else {
+                None
+            };
+
+            if let Some(rate_limiter) = &self.rate_limiter {
+                rate_limiter.acquire().await;
+            }
+
+            let response = self.transport.execute(request).await?;
+
+            match next_request {
+                Some(next_request)
+                    if self.retry_policy.should_retry(response.status(), read_only) =>
+                {
+                    let retry_after = retry::parse_retry_after(response.headers());
+
+                    tokio::time::sleep(self.retry_policy.delay(attempt, retry_after)).await;
+
+                    request = next_request;
+                    attempt += 1;
+                }
+                _ => break response,
+            }
+        };
+
+        if !response.status().is_success() {
+            let status = response.status().as_u16();
+            let headers = response.headers().clone();
+
+            let error_body = response.text().await?;
+
+            return match serde_json::from_str::<crate::error::api_error::ApiError>(&error_body) {
+                Ok(api_error) => Err(crate::error::Error::Api(Box::new(
+                    api_error.with_retry_after(retry::parse_retry_after(&headers)),
+                ))),
+                Err(_) => Err(crate::error::Error::Http(Box::new(
+                    crate::error::HttpError::new(status, &headers, &error_body),
+                ))),
+            };
+        }
+
+        Ok(response.text().await?)
+    }
+
     // # --------------------------------------------------------------------------------
     //
     // User
@@ -83,7 +191,7 @@ impl Client {
     // TODO: docs: list_users method
     pub fn list_users(&self) -> crate::client::user::list_users::ListUsersClient {
         crate::client::user::list_users::ListUsersClient {
-            reqwest_client: self.reqwest_client.clone(),
+            client: self.clone(),
             start_cursor: None,
             page_size: None,
             recursive: false,
@@ -93,7 +201,7 @@ impl Client {
     // TODO: docs: get_user method
     pub fn get_user(&self) -> crate::client::user::get_user::GetUserClient {
         crate::client::user::get_user::GetUserClient {
-            reqwest_client: self.reqwest_client.clone(),
+            client: self.clone(),
             user_id: None,
         }
     }
@@ -101,7 +209,7 @@ impl Client {
     // TODO: docs: get_self method
     pub fn get_self(&self) -> crate::client::user::get_self::GetSelfClient {
         crate::client::user::get_self::GetSelfClient {
-            reqwest_client: self.reqwest_client.clone(),
+            client: self.clone(),
         }
     }
 
@@ -114,17 +222,18 @@ impl Client {
     // TODO: docs: get_page method
     pub fn get_page(&self) -> crate::client::page::get_page::GetPageClient {
         crate::client::page::get_page::GetPageClient {
-            reqwest_client: self.reqwest_client.clone(),
+            client: self.clone(),
             page_id: None,
         }
     }
 
-    // TODO: docs: get_page_property_item method
+    /// Retrieves a single property of a page.
+    /// Paginated properties (e.g. relations with more than 25 pages) are fetched in full.
     pub fn get_page_property_item(
         &self,
     ) -> crate::client::page::get_page_property_item::GetPagePropertyItemClient {
         crate::client::page::get_page_property_item::GetPagePropertyItemClient {
-            reqwest_client: self.reqwest_client.clone(),
+            client: self.clone(),
             page_id: None,
             property_id: None,
         }
@@ -132,11 +241,45 @@ impl Client {
 
     pub fn create_page(&self) -> crate::client::page::create_page::CreatePageClient {
         crate::client::page::create_page::CreatePageClient {
-            reqwest_client: self.reqwest_client.clone(),
+            client: self.clone(),
+            ..Default::default()
+        }
+    }
+
+    /// Updates the properties, icon or cover of a page, or archives/trashes it.
+    ///
+    /// Only the fields that are set are sent.
+    pub fn update_page(&self) -> crate::client::page::update_page::UpdatePageClient {
+        crate::client::page::update_page::UpdatePageClient {
+            client: self.clone(),
             ..Default::default()
         }
     }
 
+    /// Archives a page. Equivalent to `update_page().archived(true)`.
+    ///
+    /// ```no_run
+    /// # async fn run() -> Result<(), notionrs::error::Error> {
+    /// let client = notionrs::Client::new();
+    ///
+    /// client.archive_page().page_id("PAGE_ID").send().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn archive_page(&self) -> crate::client::page::update_page::UpdatePageClient {
+        self.update_page().archived(true)
+    }
+
+    /// Moves a page to the trash. Equivalent to `update_page().in_trash(true)`.
+    pub fn trash_page(&self) -> crate::client::page::update_page::UpdatePageClient {
+        self.update_page().in_trash(true)
+    }
+
+    /// Restores an archived or trashed page. Equivalent to `update_page().in_trash(false)`.
+    pub fn restore_page(&self) -> crate::client::page::update_page::UpdatePageClient {
+        self.update_page().in_trash(false)
+    }
+
     // # --------------------------------------------------------------------------------
     //
     // Database
@@ -146,7 +289,7 @@ impl Client {
     // TODO: docs
     pub fn query_database(&self) -> crate::client::database::query_database::QueryDatabaseClient {
         crate::client::database::query_database::QueryDatabaseClient {
-            reqwest_client: self.reqwest_client.clone(),
+            client: self.clone(),
             ..Default::default()
         }
     }
@@ -155,7 +298,7 @@ impl Client {
         &self,
     ) -> crate::client::database::create_database::CreateDatabaseClient {
         crate::client::database::create_database::CreateDatabaseClient {
-            reqwest_client: self.reqwest_client.clone(),
+            client: self.clone(),
             ..Default::default()
         }
     }
@@ -164,7 +307,7 @@ impl Client {
         &self,
     ) -> crate::client::database::update_database::UpdateDatabaseClient {
         crate::client::database::update_database::UpdateDatabaseClient {
-            reqwest_client: self.reqwest_client.clone(),
+            client: self.clone(),
             ..Default::default()
         }
     }
@@ -173,7 +316,7 @@ impl Client {
         &self,
     ) -> crate::client::database::retrieve_database::RetrieveDatabaseClient {
         crate::client::database::retrieve_database::RetrieveDatabaseClient {
-            reqwest_client: self.reqwest_client.clone(),
+            client: self.clone(),
             ..Default::default()
         }
     }
@@ -187,7 +330,7 @@ impl Client {
     // TODO: docs
     pub fn get_block(&self) -> crate::client::block::get_block::GetBlockClient {
         crate::client::block::get_block::GetBlockClient {
-            reqwest_client: self.reqwest_client.clone(),
+            client: self.clone(),
             block_id: None,
         }
     }
@@ -197,17 +340,47 @@ impl Client {
         &self,
     ) -> crate::client::block::get_block_children::GetBlockChildrenClient {
         crate::client::block::get_block_children::GetBlockChildrenClient {
-            reqwest_client: self.reqwest_client.clone(),
+            client: self.clone(),
             block_id: None,
             page_size: 100,
             start_cursor: None,
         }
     }
 
+    /// Retrieves all the descendant blocks of a page or block as a [`BlockTree`](crate::block::BlockTree).
+    ///
+    /// ```no_run
+    /// # async fn run() -> Result<(), notionrs::error::Error> {
+    /// let client = notionrs::Client::new();
+    ///
+    /// let tree = client
+    ///     .get_block_tree()
+    ///     .block_id("PAGE_ID")
+    ///     .max_depth(5)
+    ///     .stop_at_child_pages(true)
+    ///     .send()
+    ///     .await?;
+    ///
+    /// for node in &tree {
+    ///     println!("{}", node.block.block);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn get_block_tree(&self) -> crate::client::block::get_block_tree::GetBlockTreeClient {
+        crate::client::block::get_block_tree::GetBlockTreeClient {
+            client: self.clone(),
+            block_id: None,
+            max_depth: None,
+            concurrency: 3,
+            stop_at_child_pages: false,
+        }
+    }
+
     // TODO: docs
     pub fn delete_block(&self) -> crate::client::block::delete_block::DeleteBlockClient {
         crate::client::block::delete_block::DeleteBlockClient {
-            reqwest_client: self.reqwest_client.clone(),
+            client: self.clone(),
             block_id: None,
         }
     }
@@ -217,7 +390,7 @@ impl Client {
         &self,
     ) -> crate::client::block::append_block_children::AppendBlockChildrenClient {
         crate::client::block::append_block_children::AppendBlockChildrenClient {
-            reqwest_client: self.reqwest_client.clone(),
+            client: self.clone(),
             block_id: None,
             after: None,
             children: vec![],
@@ -227,7 +400,7 @@ impl Client {
     // TODO: docs
     pub fn update_block(&self) -> crate::client::block::update_block::UpdateBlockClient {
         crate::client::block::update_block::UpdateBlockClient {
-            reqwest_client: self.reqwest_client.clone(),
+            client: self.clone(),
             block_id: None,
             archived: None,
             block: None,
@@ -242,22 +415,120 @@ impl Client {
 
     pub fn search(&self) -> crate::client::search::SearchClient {
         crate::client::search::SearchClient {
-            reqwest_client: self.reqwest_client.clone(),
+            client: self.clone(),
             ..Default::default()
         }
     }
 
     pub fn search_database(&self) -> crate::client::search::SearchDatabaseClient {
         crate::client::search::SearchDatabaseClient {
-            reqwest_client: self.reqwest_client.clone(),
+            client: self.clone(),
             ..Default::default()
         }
     }
 
     pub fn search_page(&self) -> crate::client::search::SearchPageClient {
         crate::client::search::SearchPageClient {
-            reqwest_client: self.reqwest_client.clone(),
+            client: self.clone(),
+            ..Default::default()
+        }
+    }
+
+    // # --------------------------------------------------------------------------------
+    //
+    // Comment
+    //
+    // # --------------------------------------------------------------------------------
+
+    /// Creates a comment on a page, or a reply in an existing discussion thread.
+    ///
+    /// ```no_run
+    /// # async fn run() -> Result<(), notionrs::error::Error> {
+    /// use notionrs::RichText;
+    ///
+    /// let client = notionrs::Client::new();
+    ///
+    /// let comment = client
+    ///     .create_comment()
+    ///     .page_id("PAGE_ID")
+    ///     .rich_text(vec![RichText::from("Looks good to me!")])
+    ///     .send()
+    ///     .await?;
+    ///
+    /// client
+    ///     .create_comment()
+    ///     .discussion_id(comment.discussion_id)
+    ///     .rich_text(vec![RichText::from("Thanks!")])
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn create_comment(&self) -> crate::client::comment::create_comment::CreateCommentClient {
+        crate::client::comment::create_comment::CreateCommentClient {
+            client: self.clone(),
             ..Default::default()
         }
     }
+
+    /// Lists the unresolved comments on a page or block.
+    pub fn list_comments(&self) -> crate::client::comment::list_comments::ListCommentsClient {
+        crate::client::comment::list_comments::ListCommentsClient {
+            client: self.clone(),
+            ..Default::default()
+        }
+    }
+
+    // # --------------------------------------------------------------------------------
+    //
+    // File Upload
+    //
+    // # --------------------------------------------------------------------------------
+
+    /// Uploads a local file with the File Upload API.
+    ///
+    /// The returned upload can be attached to file-type blocks, `Files` properties,
+    /// icons and page covers with [`File::file_upload`](crate::File::file_upload).
+    ///
+    /// ```no_run
+    /// # async fn run() -> Result<(), notionrs::error::Error> {
+    /// let client = notionrs::Client::new();
+    ///
+    /// let upload = client
+    ///     .upload_file()
+    ///     .path("./diagram.png")
+    ///     .content_type("image/png")
+    ///     .send()
+    ///     .await?;
+    ///
+    /// let icon = notionrs::Icon::File(notionrs::File::file_upload(upload.id));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn upload_file(&self) -> crate::client::file_upload::upload_file::UploadFileClient {
+        crate::client::file_upload::upload_file::UploadFileClient {
+            client: self.clone(),
+            source: None,
+            filename: None,
+            content_type: None,
+            part_size: crate::client::file_upload::upload_file::MAX_PART_SIZE,
+        }
+    }
+}
+
+/// Deserializes a successful response body.
+/// On failure, the error reports the JSON path that failed and a snippet of the body.
+pub(crate) fn deserialize_body<T>(body: &str) -> Result<T, crate::error::Error>
+where
+    T: serde::de::DeserializeOwned,
+{
+    let mut deserializer = serde_json::Deserializer::from_str(body);
+
+    let value = serde_path_to_error::deserialize(&mut deserializer).map_err(|error| {
+        crate::error::Error::Deserialize(Box::new(crate::error::DeserializeError::new(error, body)))
+    })?;
+
+    deserializer.end()?;
+
+    Ok(value)
 }
@@ -1,20 +1,17 @@
 use serde::{Deserialize, Serialize};
 
-use crate::{
-    error::{api_error::ApiError, Error},
-    page::page_response::PageResponse,
-};
+use crate::{error::Error, page::page_response::PageResponse};
 
 #[derive(Debug, Default)]
 pub struct CreatePageClient {
-    /// The reqwest http client
-    pub(crate) reqwest_client: reqwest::Client,
+    /// The Notion API client
+    pub(crate) client: crate::client::Client,
 
     /// Cannot specify the same page ID as the parent page's database_id  
-    pub(crate) page_id: Option<String>,
+    pub(crate) page_id: Option<crate::others::id::PageId>,
 
     /// Cannot specify the same database ID as the parent database's page_id  
-    pub(crate) database_id: Option<String>,
+    pub(crate) database_id: Option<crate::others::id::DatabaseId>,
 
     pub(crate) properties: std::collections::HashMap<String, crate::page::properties::PageProperty>,
 
@@ -42,12 +39,10 @@ pub struct CreatePageRequestBody {
 }
 
 impl CreatePageClient {
-    /// Send a request specifying generics.
+    /// Send a request.
     ///
-    /// Create a struct with generics like `send::<MyResponse>()`.
-    /// When the response type is not specific,
-    /// use `send::<HashMap<String, PageProperty>>()`.
-    /// (Type inference for the property field cannot be used.)
+    /// To work with the properties of the created page as a struct,
+    /// use [`NotionRow::from_page`](crate::page::row::NotionRow::from_page).
     pub async fn send(self) -> Result<PageResponse, Error> {
         let mut parent: Option<crate::others::parent::Parent> = None;
 
@@ -78,42 +73,29 @@ impl CreatePageClient {
         };
 
         let request_body = serde_json::to_string(&request_body_struct)?;
-        println!("The request body is: {}", request_body);
 
-        let url = "https://api.notion.com/v1/pages".to_string();
-
-        println!("{}", request_body);
+        let url = "/pages".to_string();
 
         let request = self
-            .reqwest_client
-            .post(url)
+            .client
+            .request(reqwest::Method::POST, url)
             .header("Content-Type", "application/json")
             .body(request_body);
 
-        let response = request.send().await?;
-
-        if !response.status().is_success() {
-            let error_body = response.text().await?;
-
-            let error_json = serde_json::from_str::<ApiError>(&error_body)?;
-
-            return Err(Error::Api(Box::new(error_json)));
-        }
-
-        let body = response.text().await?;
+        let body = self.client.send(request).await?;
 
-        let page: PageResponse = serde_json::from_str::<PageResponse>(&body)?;
+        let page: PageResponse = crate::client::deserialize_body::<PageResponse>(&body)?;
 
         Ok(page)
     }
 
-    pub fn page_id<T: AsRef<str>>(mut self, page_id: T) -> Self {
-        self.page_id = Some(page_id.as_ref().to_string());
+    pub fn page_id<T: Into<crate::others::id::PageId>>(mut self, page_id: T) -> Self {
+        self.page_id = Some(page_id.into());
         self
     }
 
-    pub fn database_id<T: AsRef<str>>(mut self, database_id: T) -> Self {
-        self.database_id = Some(database_id.as_ref().to_string());
+    pub fn database_id<T: Into<crate::others::id::DatabaseId>>(mut self, database_id: T) -> Self {
+        self.database_id = Some(database_id.into());
         self
     }
 
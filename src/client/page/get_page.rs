@@ -1,37 +1,24 @@
-use crate::{
-    error::{api_error::ApiError, Error},
-    page::page_response::PageResponse,
-};
+use crate::{error::Error, page::page_response::PageResponse};
 
 #[derive(Debug)]
 pub struct GetPageClient {
-    /// The reqwest http client
-    pub(crate) reqwest_client: reqwest::Client,
+    /// The Notion API client
+    pub(crate) client: crate::client::Client,
 
-    pub(crate) page_id: Option<String>,
+    pub(crate) page_id: Option<crate::others::id::PageId>,
 }
 
 impl GetPageClient {
     pub async fn send(self) -> Result<PageResponse, Error> {
         match self.page_id {
             Some(id) => {
-                let url = format!("https://api.notion.com/v1/pages/{}", id);
+                let url = format!("/pages/{}", id);
 
-                let request = self.reqwest_client.get(url);
+                let request = self.client.request(reqwest::Method::GET, url);
 
-                let response = request.send().await?;
+                let body = self.client.send(request).await?;
 
-                if !response.status().is_success() {
-                    let error_body = response.text().await?;
-
-                    let error_json = serde_json::from_str::<ApiError>(&error_body)?;
-
-                    return Err(Error::Api(Box::new(error_json)));
-                }
-
-                let body = response.text().await?;
-
-                let page: PageResponse = serde_json::from_str::<PageResponse>(&body)?;
+                let page: PageResponse = crate::client::deserialize_body::<PageResponse>(&body)?;
 
                 Ok(page)
             }
@@ -41,8 +28,8 @@ impl GetPageClient {
 
     /// Specify the ID of the page.
     /// The ID is also included in the Notion page URL.
-    pub fn page_id<T: AsRef<str>>(mut self, page_id: T) -> Self {
-        self.page_id = Some(page_id.as_ref().to_string());
+    pub fn page_id<T: Into<crate::others::id::PageId>>(mut self, page_id: T) -> Self {
+        self.page_id = Some(page_id.into());
         self
     }
 }
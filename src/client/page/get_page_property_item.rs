@@ -1,18 +1,42 @@
-use crate::error::{api_error::ApiError, Error};
+use serde::Serialize;
+
+use crate::{
+    client::pagination,
+    error::Error,
+    page::{
+        properties::PageProperty,
+        property_item::{PropertyItemList, PropertyItemResponse},
+    },
+};
 
 #[derive(Debug)]
 pub struct GetPagePropertyItemClient {
-    /// The reqwest http client
-    pub(crate) reqwest_client: reqwest::Client,
+    /// The Notion API client
+    pub(crate) client: crate::client::Client,
 
-    pub(crate) page_id: Option<String>,
+    pub(crate) page_id: Option<crate::others::id::PageId>,
 
     pub(crate) property_id: Option<String>,
 }
 
+/// Query parameters for making requests to the endpoint.
+#[derive(Serialize)]
+struct GetPagePropertyItemQueryParams {
+    /// If supplied, this endpoint will return a page of results starting after the cursor provided.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    start_cursor: Option<String>,
+
+    /// The number of items from the full list desired in the response. Maximum: 100
+    page_size: u32,
+}
+
 impl GetPagePropertyItemClient {
-    // TODO: docs for send
-    pub async fn send(self) -> Result<crate::page::properties::PageProperty, Error> {
+    /// Send a request to the API endpoint of Notion.
+    ///
+    /// Paginated properties (`title`, `rich_text`, `relation`, `people` and `rollup`)
+    /// are fetched page by page and merged into a complete property,
+    /// so a relation with more than 25 pages is returned in full.
+    pub async fn send(self) -> Result<PageProperty, Error> {
         let page_id = self.page_id.ok_or(Error::RequestParameter(
             "`page_id` has not been set.".to_string(),
         ))?;
@@ -21,39 +45,114 @@ impl GetPagePropertyItemClient {
             "`property_id` has not been set.".to_string(),
         ))?;
 
-        let url = format!(
-            "https://api.notion.com/v1/pages/{}/properties/{}",
-            page_id, property_id
-        );
+        let url = format!("/pages/{}/properties/{}", page_id, property_id);
 
-        let request = self.reqwest_client.get(url);
+        let mut params = GetPagePropertyItemQueryParams {
+            start_cursor: None,
+            page_size: pagination::MAX_PAGE_SIZE,
+        };
 
-        let response = request.send().await?;
+        let mut list = match fetch_page(&self.client, &url, &params).await? {
+            PropertyItemResponse::PropertyItem(property) => return Ok(property),
+            PropertyItemResponse::List(list) => list,
+        };
 
-        if !response.status().is_success() {
-            let error_body = response.text().await?;
+        let mut items = std::mem::take(&mut list.results);
 
-            let error_json = serde_json::from_str::<ApiError>(&error_body)?;
+        while let (true, Some(next_cursor)) = (list.has_more, list.next_cursor.take()) {
+            params.start_cursor = Some(next_cursor);
 
-            return Err(Error::Api(Box::new(error_json)));
-        }
+            list = match fetch_page(&self.client, &url, &params).await? {
+                PropertyItemResponse::List(list) => list,
+                PropertyItemResponse::PropertyItem(_) => {
+                    return Err(Error::Unknown(
+                        "a property item is returned while paginating".to_string(),
+                    ))
+                }
+            };
 
-        let body = response.text().await?;
+            items.append(&mut list.results);
+        }
 
-        let user = serde_json::from_str::<crate::page::properties::PageProperty>(&body)?;
+        let PropertyItemList { property_item, .. } = list;
 
-        Ok(user)
+        property_item.into_property(items)
     }
 
-    // TODO: docs for page_id
-    pub fn page_id<T: AsRef<str>>(mut self, page_id: T) -> Self {
-        self.page_id = Some(page_id.as_ref().to_string());
+    /// Specify the ID of the page.
+    pub fn page_id<T: Into<crate::others::id::PageId>>(mut self, page_id: T) -> Self {
+        self.page_id = Some(page_id.into());
         self
     }
 
-    // TODO: docs for property_id
+    /// Specify the ID of the property (`id` of [`PageProperty`]), not its name.
     pub fn property_id<T: AsRef<str>>(mut self, property_id: T) -> Self {
         self.property_id = Some(property_id.as_ref().to_string());
         self
     }
 }
+
+/// Fetches one page of property items.
+async fn fetch_page(
+    client: &crate::client::Client,
+    url: &str,
+    params: &GetPagePropertyItemQueryParams,
+) -> Result<PropertyItemResponse, Error> {
+    let request = client.request(reqwest::Method::GET, url).query(params);
+
+    let body = client.send(request).await?;
+
+    let response = crate::client::deserialize_body::<PropertyItemResponse>(&body)?;
+
+    Ok(response)
+}
+
+// # --------------------------------------------------------------------------------
+//
+// unit test
+//
+// # --------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod unit_tests {
+    use super::*;
+
+    #[derive(Debug)]
+    struct RelationTransport;
+
+    #[async_trait::async_trait]
+    impl crate::client::Transport for RelationTransport {
+        async fn execute(&self, request: reqwest::Request) -> Result<reqwest::Response, Error> {
+            assert_eq!(request.url().path(), "/v1/pages/page_id/properties/kjPO");
+
+            let body = match request.url().query() {
+                Some(query) if query.contains("start_cursor=next") => {
+                    r#"{"object":"list","results":[{"object":"property_item","id":"kjPO","type":"relation","relation":{"id":"page-2"}}],"next_cursor":null,"has_more":false,"type":"property_item","property_item":{"id":"kjPO","next_url":null,"type":"relation","relation":{}}}"#
+                }
+                _ => {
+                    r#"{"object":"list","results":[{"object":"property_item","id":"kjPO","type":"relation","relation":{"id":"page-1"}}],"next_cursor":"next","has_more":true,"type":"property_item","property_item":{"id":"kjPO","next_url":"https://api.notion.com/v1/pages/page_id/properties/kjPO?start_cursor=next","type":"relation","relation":{}}}"#
+                }
+            };
+
+            Ok(reqwest::Response::from(http::Response::new(body)))
+        }
+    }
+
+    #[tokio::test]
+    async fn get_all_relation_items() {
+        let client = crate::client::Client::builder()
+            .secret("secret_test")
+            .transport(RelationTransport)
+            .build();
+
+        let property = client
+            .get_page_property_item()
+            .page_id("page_id")
+            .property_id("kjPO")
+            .send()
+            .await
+            .unwrap();
+
+        assert_eq!(property.as_relation_ids(), Some(vec!["page-1", "page-2"]));
+    }
+}
@@ -1,26 +1,28 @@
 use serde::{Deserialize, Serialize};
 
-use crate::{
-    error::{api_error::ApiError, Error},
-    page::page_response::PageResponse,
-};
+use crate::{error::Error, page::page_response::PageResponse};
 
 #[derive(Debug, Default)]
 pub struct UpdatePageClient {
-    /// The reqwest http client
-    pub(crate) reqwest_client: reqwest::Client,
+    /// The Notion API client
+    pub(crate) client: crate::client::Client,
 
-    pub(crate) page_id: Option<String>,
+    pub(crate) page_id: Option<crate::others::id::PageId>,
 
     pub(crate) properties: std::collections::HashMap<String, crate::page::properties::PageProperty>,
 
     pub(crate) icon: Option<crate::others::icon::Icon>,
 
     pub(crate) cover: Option<crate::File>,
+
+    pub(crate) archived: Option<bool>,
+
+    pub(crate) in_trash: Option<bool>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct UpdatePageRequestBody {
+    #[serde(skip_serializing_if = "std::collections::HashMap::is_empty")]
     pub(crate) properties: std::collections::HashMap<String, crate::page::properties::PageProperty>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -28,6 +30,12 @@ pub struct UpdatePageRequestBody {
 
     #[serde(skip_serializing_if = "Option::is_none")]
     pub(crate) cover: Option<crate::File>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) archived: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) in_trash: Option<bool>,
 }
 
 impl UpdatePageClient {
@@ -40,39 +48,29 @@ impl UpdatePageClient {
             properties: self.properties,
             icon: self.icon,
             cover: self.cover,
+            archived: self.archived,
+            in_trash: self.in_trash,
         };
 
         let request_body = serde_json::to_string(&request_body_struct)?;
 
-        let url = format!("https://api.notion.com/v1/pages/{}", page_id);
-
-        println!("{}", request_body);
+        let url = format!("/pages/{}", page_id);
 
         let request = self
-            .reqwest_client
-            .patch(url)
+            .client
+            .request(reqwest::Method::PATCH, url)
             .header("Content-Type", "application/json")
             .body(request_body);
 
-        let response = request.send().await?;
+        let body = self.client.send(request).await?;
 
-        if !response.status().is_success() {
-            let error_body = response.text().await?;
-
-            let error_json = serde_json::from_str::<ApiError>(&error_body)?;
-
-            return Err(Error::Api(Box::new(error_json)));
-        }
-
-        let body = response.text().await?;
-
-        let page: PageResponse = serde_json::from_str::<PageResponse>(&body)?;
+        let page: PageResponse = crate::client::deserialize_body::<PageResponse>(&body)?;
 
         Ok(page)
     }
 
-    pub fn page_id<T: AsRef<str>>(mut self, page_id: T) -> Self {
-        self.page_id = Some(page_id.as_ref().to_string());
+    pub fn page_id<T: Into<crate::others::id::PageId>>(mut self, page_id: T) -> Self {
+        self.page_id = Some(page_id.into());
         self
     }
 
@@ -93,4 +91,58 @@ impl UpdatePageClient {
         self.cover = Some(cover);
         self
     }
+
+    /// Set to `true` to archive the page, or `false` to unarchive it.
+    pub fn archived(mut self, archived: bool) -> Self {
+        self.archived = Some(archived);
+        self
+    }
+
+    /// Set to `true` to move the page to the trash, or `false` to restore it.
+    pub fn in_trash(mut self, in_trash: bool) -> Self {
+        self.in_trash = Some(in_trash);
+        self
+    }
+}
+
+// # --------------------------------------------------------------------------------
+//
+// unit test
+//
+// # --------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod unit_tests {
+
+    use super::*;
+
+    #[test]
+    fn serialize_request_body() {
+        let request_body = UpdatePageRequestBody {
+            properties: std::collections::HashMap::new(),
+            icon: None,
+            cover: None,
+            archived: None,
+            in_trash: Some(true),
+        };
+
+        assert_eq!(
+            serde_json::to_string(&request_body).unwrap(),
+            r#"{"in_trash":true}"#
+        );
+    }
+
+    #[test]
+    fn page_lifecycle_helpers() {
+        let client = crate::client::Client::new();
+
+        let archive = client.archive_page();
+        assert_eq!((archive.archived, archive.in_trash), (Some(true), None));
+
+        let trash = client.trash_page();
+        assert_eq!((trash.archived, trash.in_trash), (None, Some(true)));
+
+        let restore = client.restore_page();
+        assert_eq!((restore.archived, restore.in_trash), (None, Some(false)));
+    }
 }
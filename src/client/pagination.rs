@@ -0,0 +1,180 @@
+use std::future::Future;
+
+use futures::{stream::BoxStream, StreamExt, TryStreamExt};
+
+use crate::{error::Error, list_response::ListResponse};
+
+/// A stream of items fetched lazily from a paginated endpoint.
+///
+/// The next page is requested only after all items of the current page have been consumed,
+/// so dropping the stream stops fetching. Use `futures::StreamExt` or `futures::TryStreamExt`
+/// to consume it.
+pub type PaginatedStream<T> = BoxStream<'static, Result<T, Error>>;
+
+/// The maximum `page_size` accepted by the Notion API.
+pub(crate) const MAX_PAGE_SIZE: u32 = 100;
+
+/// Turns a function that fetches one page for a cursor into a stream of items.
+///
+/// The first page is fetched with `start_cursor`. Fetching stops when a page reports
+/// `has_more: false` or doesn't contain a `next_cursor`.
+pub(crate) fn paginate<T, F, Fut>(start_cursor: Option<String>, fetch: F) -> PaginatedStream<T>
+where
+    T: Send + 'static,
+    F: FnMut(Option<String>) -> Fut + Send + 'static,
+    Fut: Future<Output = Result<ListResponse<T>, Error>> + Send + 'static,
+{
+    // `None` means that there are no more pages to fetch.
+    let initial_state = (fetch, Some(start_cursor));
+
+    futures::stream::try_unfold(initial_state, |(mut fetch, cursor)| async move {
+        let Some(cursor) = cursor else {
+            return Ok::<_, Error>(None);
+        };
+
+        let page = fetch(cursor).await?;
+
+        let next_cursor = match (page.has_more, page.next_cursor) {
+            (Some(false), _) | (_, None) => None,
+            (_, Some(next_cursor)) => Some(Some(next_cursor)),
+        };
+
+        let items = futures::stream::iter(page.results.into_iter().map(Ok::<T, Error>));
+
+        Ok(Some((items, (fetch, next_cursor))))
+    })
+    .try_flatten()
+    .boxed()
+}
+
+/// Returns a stream that yields only the given error.
+pub(crate) fn fail<T>(error: Error) -> PaginatedStream<T>
+where
+    T: Send + 'static,
+{
+    futures::stream::iter(Some(Err(error))).boxed()
+}
+
+// # --------------------------------------------------------------------------------
+//
+// unit test
+//
+// # --------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod unit_tests {
+    use std::sync::{
+        atomic::{AtomicU32, Ordering},
+        Arc,
+    };
+
+    use futures::TryStreamExt;
+
+    use super::*;
+
+    fn page(results: Vec<u32>, next_cursor: Option<&str>) -> ListResponse<u32> {
+        ListResponse {
+            object: "list".to_string(),
+            results,
+            next_cursor: next_cursor.map(String::from),
+            has_more: Some(next_cursor.is_some()),
+            r#type: None,
+        }
+    }
+
+    fn fake_pages(calls: Arc<AtomicU32>) -> PaginatedStream<u32> {
+        paginate(None, move |cursor| {
+            calls.fetch_add(1, Ordering::SeqCst);
+
+            async move {
+                match cursor.as_deref() {
+                    None => Ok(page(vec![1, 2], Some("b"))),
+                    Some("b") => Ok(page(vec![3, 4], Some("c"))),
+                    Some("c") => Ok(page(vec![5], None)),
+                    Some(_) => Err(Error::Custom("unexpected cursor".to_string())),
+                }
+            }
+        })
+    }
+
+    #[tokio::test]
+    async fn paginate_all_pages() {
+        let calls = Arc::new(AtomicU32::new(0));
+
+        let items = fake_pages(calls.clone())
+            .try_collect::<Vec<u32>>()
+            .await
+            .unwrap();
+
+        assert_eq!(items, vec![1, 2, 3, 4, 5]);
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn stop_fetching_when_dropped_early() {
+        let calls = Arc::new(AtomicU32::new(0));
+
+        let items = fake_pages(calls.clone())
+            .take(3)
+            .try_collect::<Vec<u32>>()
+            .await
+            .unwrap();
+
+        assert_eq!(items, vec![1, 2, 3]);
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn propagate_errors() {
+        let mut stream = paginate::<u32, _, _>(Some("x".to_string()), |_| async {
+            Err(Error::Custom("failed".to_string()))
+        });
+
+        assert!(stream.try_next().await.is_err());
+        assert!(stream.try_next().await.unwrap().is_none());
+    }
+
+    #[derive(Debug)]
+    struct UsersTransport;
+
+    #[async_trait::async_trait]
+    impl crate::client::Transport for UsersTransport {
+        async fn execute(&self, request: reqwest::Request) -> Result<reqwest::Response, Error> {
+            let body = match request.url().query() {
+                Some(query) if query.contains("start_cursor=next") => {
+                    r#"{"object":"list","results":[{"object":"user","id":"user_2"}],"next_cursor":null,"has_more":false,"type":"user"}"#
+                }
+                _ => {
+                    r#"{"object":"list","results":[{"object":"user","id":"user_1"}],"next_cursor":"next","has_more":true,"type":"user"}"#
+                }
+            };
+
+            Ok(reqwest::Response::from(http::Response::new(body)))
+        }
+    }
+
+    #[tokio::test]
+    async fn stream_list_users() {
+        let client = crate::client::Client::builder()
+            .secret("secret_test")
+            .transport(UsersTransport)
+            .build();
+
+        let users = client
+            .list_users()
+            .into_stream()
+            .try_collect::<Vec<_>>()
+            .await
+            .unwrap();
+
+        let ids = users
+            .iter()
+            .map(|user| match user {
+                crate::user::User::Bot(user) => user.id.as_str(),
+                crate::user::User::Person(user) => user.id.as_str(),
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(ids, vec!["user_1", "user_2"]);
+    }
+}
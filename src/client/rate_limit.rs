@@ -0,0 +1,173 @@
+use std::{
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use tokio::time::Instant;
+
+/// A client-side token bucket limiter.
+///
+/// The Notion API allows an average of three requests per second per integration.
+/// When a limiter is set on a [`Client`](crate::Client), every request (including retries)
+/// waits for a token before it is sent.
+///
+/// The limiter is shared between all clones of the `Client` and all request builders
+/// created from it. To share one budget between separate `Client` instances that use
+/// the same integration token, clone the limiter and pass it to each of them:
+///
+/// ```no_run
+/// use notionrs::{client::RateLimiter, Client};
+///
+/// let limiter = RateLimiter::new(3.0, 3);
+///
+/// let client_a = Client::builder().rate_limiter(limiter.clone()).build();
+/// let client_b = Client::builder().rate_limiter(limiter).build();
+/// ```
+#[derive(Debug, Clone)]
+pub struct RateLimiter {
+    /// The number of tokens added per second.
+    requests_per_second: f64,
+
+    /// The maximum number of tokens that can be stored.
+    burst: f64,
+
+    bucket: Arc<Mutex<Bucket>>,
+}
+
+#[derive(Debug)]
+struct Bucket {
+    /// The number of available tokens.
+    /// It becomes negative while requests are waiting for tokens to be refilled.
+    tokens: f64,
+
+    last_refill: Instant,
+}
+
+impl RateLimiter {
+    /// Creates a limiter that allows `requests_per_second` on average,
+    /// with bursts of up to `burst` requests.
+    ///
+    /// `requests_per_second` must be positive, and `burst` is at least 1.
+    pub fn new(requests_per_second: f64, burst: u32) -> Self {
+        assert!(
+            requests_per_second > 0.0,
+            "`requests_per_second` must be positive."
+        );
+
+        let burst = f64::from(burst.max(1));
+
+        Self {
+            requests_per_second,
+            burst,
+            bucket: Arc::new(Mutex::new(Bucket {
+                tokens: burst,
+                last_refill: Instant::now(),
+            })),
+        }
+    }
+
+    /// Creates a limiter matching Notion's documented budget (3 requests/second).
+    pub fn notion_default() -> Self {
+        Self::new(3.0, 3)
+    }
+
+    /// The number of tokens added per second.
+    pub fn requests_per_second(&self) -> f64 {
+        self.requests_per_second
+    }
+
+    /// The maximum number of requests that can be sent at once.
+    pub fn burst(&self) -> u32 {
+        self.burst as u32
+    }
+
+    /// Waits until a request can be sent.
+    pub async fn acquire(&self) {
+        let wait = self.reserve(Instant::now());
+
+        if !wait.is_zero() {
+            tokio::time::sleep(wait).await;
+        }
+    }
+
+    /// Takes a token and returns how long the caller has to wait before using it.
+    fn reserve(&self, now: Instant) -> Duration {
+        let mut bucket = self
+            .bucket
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+
+        let elapsed = now.saturating_duration_since(bucket.last_refill);
+
+        bucket.tokens =
+            (bucket.tokens + elapsed.as_secs_f64() * self.requests_per_second).min(self.burst);
+        bucket.last_refill = now;
+
+        bucket.tokens -= 1.0;
+
+        if bucket.tokens >= 0.0 {
+            Duration::ZERO
+        } else {
+            Duration::from_secs_f64(-bucket.tokens / self.requests_per_second)
+        }
+    }
+}
+
+impl Default for RateLimiter {
+    fn default() -> Self {
+        Self::notion_default()
+    }
+}
+
+// # --------------------------------------------------------------------------------
+//
+// unit test
+//
+// # --------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod unit_tests {
+    use super::*;
+
+    #[test]
+    fn reserve_within_burst() {
+        let limiter = RateLimiter::new(2.0, 2);
+        let now = Instant::now();
+
+        assert_eq!(limiter.reserve(now), Duration::ZERO);
+        assert_eq!(limiter.reserve(now), Duration::ZERO);
+        assert_eq!(limiter.reserve(now), Duration::from_millis(500));
+        assert_eq!(limiter.reserve(now), Duration::from_millis(1000));
+    }
+
+    #[test]
+    fn refill_over_time() {
+        let limiter = RateLimiter::new(2.0, 2);
+        let now = Instant::now();
+
+        limiter.reserve(now);
+        limiter.reserve(now);
+
+        // One token is refilled after 0.5 seconds.
+        assert_eq!(
+            limiter.reserve(now + Duration::from_millis(500)),
+            Duration::ZERO
+        );
+
+        // The bucket never holds more than `burst` tokens.
+        let later = now + Duration::from_secs(60);
+        assert_eq!(limiter.reserve(later), Duration::ZERO);
+        assert_eq!(limiter.reserve(later), Duration::ZERO);
+        assert_eq!(limiter.reserve(later), Duration::from_millis(500));
+    }
+
+    #[test]
+    fn share_budget_between_clones() {
+        let limiter = RateLimiter::new(1.0, 1);
+        let cloned = limiter.clone();
+        let now = Instant::now();
+
+        assert_eq!(limiter.reserve(now), Duration::ZERO);
+        assert_eq!(cloned.reserve(now), Duration::from_secs(1));
+    }
+}
@@ -0,0 +1,338 @@
+use std::time::Duration;
+
+/// Controls how failed requests are retried.
+///
+/// A request is retried when the Notion API responds with `429 Too Many Requests`
+/// (`rate_limited`) or a `5xx` status (e.g. `service_unavailable`).
+///
+/// - `429` responses are retried for every endpoint, because Notion rejects
+///   the request before processing it.
+/// - `5xx` responses are retried only for requests that don't modify anything
+///   (retrieving, querying and searching), unless [`RetryPolicy::retry_mutating`] is enabled.
+///
+/// Retries are disabled by default. Set a policy with [`ClientBuilder::retry_policy`](crate::client::ClientBuilder::retry_policy):
+///
+/// ```no_run
+/// use notionrs::{client::RetryPolicy, Client};
+///
+/// let client = Client::builder()
+///     .retry_policy(
+///         RetryPolicy::new()
+///             .max_attempts(5)
+///             .base_delay(std::time::Duration::from_millis(500)),
+///     )
+///     .build();
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RetryPolicy {
+    /// The maximum number of attempts, including the first request.
+    pub(crate) max_attempts: u32,
+
+    /// The delay before the first retry. It doubles with each subsequent retry.
+    pub(crate) base_delay: Duration,
+
+    /// The upper bound of the computed backoff delay.
+    pub(crate) max_delay: Duration,
+
+    /// The fraction (`0.0` ~ `1.0`) of the backoff delay that is randomized.
+    pub(crate) jitter: f64,
+
+    /// Whether to wait for the duration given in the `Retry-After` header.
+    pub(crate) respect_retry_after: bool,
+
+    /// Whether to retry requests that create, update or delete resources on `5xx` responses.
+    pub(crate) retry_mutating: bool,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            base_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(30),
+            jitter: 0.5,
+            respect_retry_after: true,
+            retry_mutating: false,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Creates a policy that makes up to 3 attempts, starting with a 500ms delay.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a policy that never retries. This is the default for `Client`.
+    pub fn disabled() -> Self {
+        Self {
+            max_attempts: 1,
+            ..Default::default()
+        }
+    }
+
+    /// The maximum number of attempts, including the first request.
+    /// `1` disables retries.
+    pub fn max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = max_attempts.max(1);
+        self
+    }
+
+    /// The delay before the first retry. It doubles with each subsequent retry.
+    pub fn base_delay(mut self, base_delay: Duration) -> Self {
+        self.base_delay = base_delay;
+        self
+    }
+
+    /// The upper bound of the computed backoff delay.
+    /// (A longer `Retry-After` value sent by the server is still respected.)
+    pub fn max_delay(mut self, max_delay: Duration) -> Self {
+        self.max_delay = max_delay;
+        self
+    }
+
+    /// The fraction of the backoff delay that is randomized, clamped to `0.0` ~ `1.0`.
+    /// With `0.5`, a delay of 1s becomes a random value between 0.5s and 1s.
+    pub fn jitter(mut self, jitter: f64) -> Self {
+        self.jitter = jitter.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Whether to wait for the duration given in the `Retry-After` header. Defaults to `true`.
+    pub fn respect_retry_after(mut self, respect_retry_after: bool) -> Self {
+        self.respect_retry_after = respect_retry_after;
+        self
+    }
+
+    /// Also retry requests that create, update or delete resources on `5xx` responses.
+    ///
+    /// The server may have applied the change before failing,
+    /// so only enable this if your requests are safe to repeat.
+    pub fn retry_mutating(mut self, retry_mutating: bool) -> Self {
+        self.retry_mutating = retry_mutating;
+        self
+    }
+
+    /// Whether a response with `status` should be retried.
+    pub(crate) fn should_retry(&self, status: reqwest::StatusCode, read_only: bool) -> bool {
+        if status == reqwest::StatusCode::TOO_MANY_REQUESTS {
+            return true;
+        }
+
+        status.is_server_error() && (read_only || self.retry_mutating)
+    }
+
+    /// The delay before the retry following the `attempt`-th (1-based) attempt.
+    pub(crate) fn delay(&self, attempt: u32, retry_after: Option<Duration>) -> Duration {
+        let exponent = attempt.saturating_sub(1).min(31);
+
+        let backoff = self
+            .base_delay
+            .saturating_mul(2u32.saturating_pow(exponent))
+            .min(self.max_delay);
+
+        let backoff = backoff.mul_f64(1.0 - self.jitter * fastrand::f64());
+
+        match retry_after {
+            Some(retry_after) if self.respect_retry_after => retry_after.max(backoff),
+            _ => backoff,
+        }
+    }
+}
+
+/// Parses the `Retry-After` header. Notion sends the number of seconds to wait.
+pub(crate) fn parse_retry_after(headers: &reqwest::header::HeaderMap) -> Option<Duration> {
+    let value = headers.get(reqwest::header::RETRY_AFTER)?.to_str().ok()?;
+
+    let seconds = value.trim().parse::<f64>().ok()?;
+
+    // Negative, infinite or too large values are ignored.
+    Duration::try_from_secs_f64(seconds).ok()
+}
+
+// # --------------------------------------------------------------------------------
+//
+// unit test
+//
+// # --------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod unit_tests {
+    use std::sync::{
+        atomic::{AtomicU32, Ordering},
+        Arc,
+    };
+
+    use super::*;
+
+    #[test]
+    fn should_retry() {
+        let policy = RetryPolicy::new();
+
+        assert!(policy.should_retry(reqwest::StatusCode::TOO_MANY_REQUESTS, false));
+        assert!(policy.should_retry(reqwest::StatusCode::SERVICE_UNAVAILABLE, true));
+        assert!(!policy.should_retry(reqwest::StatusCode::SERVICE_UNAVAILABLE, false));
+        assert!(!policy.should_retry(reqwest::StatusCode::BAD_REQUEST, true));
+
+        let policy = policy.retry_mutating(true);
+        assert!(policy.should_retry(reqwest::StatusCode::BAD_GATEWAY, false));
+    }
+
+    #[test]
+    fn exponential_delay() {
+        let policy = RetryPolicy::new()
+            .base_delay(Duration::from_millis(100))
+            .max_delay(Duration::from_millis(350))
+            .jitter(0.0);
+
+        assert_eq!(policy.delay(1, None), Duration::from_millis(100));
+        assert_eq!(policy.delay(2, None), Duration::from_millis(200));
+        assert_eq!(policy.delay(3, None), Duration::from_millis(350));
+        assert_eq!(policy.delay(40, None), Duration::from_millis(350));
+    }
+
+    #[test]
+    fn delay_with_jitter() {
+        let policy = RetryPolicy::new()
+            .base_delay(Duration::from_millis(100))
+            .jitter(0.5);
+
+        for _ in 0..100 {
+            let delay = policy.delay(1, None);
+            assert!(delay >= Duration::from_millis(50));
+            assert!(delay <= Duration::from_millis(100));
+        }
+    }
+
+    #[test]
+    fn delay_with_retry_after() {
+        let policy = RetryPolicy::new()
+            .base_delay(Duration::from_millis(100))
+            .jitter(0.0);
+
+        assert_eq!(
+            policy.delay(1, Some(Duration::from_secs(2))),
+            Duration::from_secs(2)
+        );
+
+        let policy = policy.respect_retry_after(false);
+
+        assert_eq!(
+            policy.delay(1, Some(Duration::from_secs(2))),
+            Duration::from_millis(100)
+        );
+    }
+
+    #[test]
+    fn parse_retry_after_header() {
+        let mut headers = reqwest::header::HeaderMap::new();
+        assert_eq!(parse_retry_after(&headers), None);
+
+        headers.insert(reqwest::header::RETRY_AFTER, "3".parse().unwrap());
+        assert_eq!(parse_retry_after(&headers), Some(Duration::from_secs(3)));
+
+        headers.insert(
+            reqwest::header::RETRY_AFTER,
+            "Wed, 21 Oct 2015 07:28:00 GMT".parse().unwrap(),
+        );
+        assert_eq!(parse_retry_after(&headers), None);
+
+        for value in ["1e300", "-1", "inf", "NaN"] {
+            headers.insert(reqwest::header::RETRY_AFTER, value.parse().unwrap());
+            assert_eq!(parse_retry_after(&headers), None, "{}", value);
+        }
+    }
+
+    #[derive(Debug)]
+    struct FlakyTransport {
+        calls: Arc<AtomicU32>,
+        status: u16,
+    }
+
+    #[async_trait::async_trait]
+    impl crate::client::Transport for FlakyTransport {
+        async fn execute(
+            &self,
+            _request: reqwest::Request,
+        ) -> Result<reqwest::Response, crate::error::Error> {
+            let calls = self.calls.fetch_add(1, Ordering::SeqCst);
+
+            let (status, body) = if calls == 0 {
+                (
+                    self.status,
+                    r#"{"object":"error","status":429,"code":"rate_limited","message":"Rate limited","request_id":null}"#,
+                )
+            } else {
+                (
+                    200,
+                    r#"{"object":"list","results":[],"next_cursor":null,"has_more":false,"type":"user"}"#,
+                )
+            };
+
+            let response = http::Response::builder()
+                .status(status)
+                .header("Retry-After", "0")
+                .body(body)
+                .unwrap();
+
+            Ok(reqwest::Response::from(response))
+        }
+    }
+
+    fn client(calls: Arc<AtomicU32>, status: u16) -> crate::client::Client {
+        crate::client::Client::builder()
+            .secret("secret_test")
+            .transport(FlakyTransport { calls, status })
+            .retry_policy(RetryPolicy::new().base_delay(Duration::ZERO))
+            .build()
+    }
+
+    #[tokio::test]
+    async fn retry_rate_limited_request() {
+        let calls = Arc::new(AtomicU32::new(0));
+
+        client(calls.clone(), 429)
+            .list_users()
+            .send()
+            .await
+            .unwrap();
+
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn do_not_retry_mutating_request_on_server_error() {
+        let calls = Arc::new(AtomicU32::new(0));
+
+        let result = client(calls.clone(), 503)
+            .delete_block()
+            .block_id("xxx")
+            .send()
+            .await;
+
+        assert!(result.is_err());
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn surface_rate_limited_error() {
+        let calls = Arc::new(AtomicU32::new(0));
+
+        let error = crate::client::Client::builder()
+            .secret("secret_test")
+            .transport(FlakyTransport {
+                calls: calls.clone(),
+                status: 429,
+            })
+            .build()
+            .list_users()
+            .send()
+            .await
+            .unwrap_err();
+
+        assert!(error.is_retryable());
+        assert!(!error.is_not_found());
+        assert_eq!(error.retry_after(), Some(Duration::ZERO));
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+}
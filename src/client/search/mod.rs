@@ -7,20 +7,21 @@ pub use search_page::*;
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    error::{api_error::ApiError, Error},
+    client::pagination::{self, PaginatedStream},
+    error::Error,
     list_response::ListResponse,
     prelude::ToJson,
 };
 
 #[derive(Debug, Default)]
 pub struct SearchClient {
-    /// The reqwest http client
-    pub(crate) reqwest_client: reqwest::Client,
+    /// The Notion API client
+    pub(crate) client: crate::client::Client,
 
     pub(crate) body: SearchRequestBody,
 }
 
-#[derive(Debug, Serialize, Deserialize, Default)]
+#[derive(Debug, Serialize, Deserialize, Default, Clone)]
 pub struct SearchRequestBody {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub(crate) query: Option<String>,
@@ -40,36 +41,50 @@ pub struct SearchRequestBody {
 
 impl SearchClient {
     pub async fn send(self) -> Result<ListResponse<crate::list_response::SearchResultItem>, Error> {
-        let url = String::from("https://api.notion.com/v1/search");
-
-        let request_body = self.body.to_json().to_string();
-
-        let request = self
-            .reqwest_client
-            .post(url)
-            .header("Content-Type", "application/json")
-            .body(request_body);
-
-        let response = request.send().await?;
-
-        if !response.status().is_success() {
-            let error_body = response.text().await?;
-
-            let error_json = serde_json::from_str::<ApiError>(&error_body)?;
-
-            return Err(Error::Api(Box::new(error_json)));
-        }
-
-        let body = response.text().await?;
-
-        println!("{}", body);
-
         let pages =
-            serde_json::from_str::<ListResponse<crate::list_response::SearchResultItem>>(&body)?;
+            fetch_page::<crate::list_response::SearchResultItem, _>(&self.client, &self.body)
+                .await?;
 
         Ok(pages)
     }
 
+    /// Returns a stream that yields all the search results one by one.
+    ///
+    /// Results are fetched lazily, `page_size` (100 by default) per request,
+    /// and dropping the stream stops fetching.
+    ///
+    /// ```no_run
+    /// use futures::TryStreamExt;
+    ///
+    /// # async fn run() -> Result<(), notionrs::error::Error> {
+    /// let client = notionrs::Client::new();
+    ///
+    /// let mut stream = client.search().query("meeting").into_stream();
+    ///
+    /// while let Some(item) = stream.try_next().await? {
+    ///     println!("{:?}", item);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn into_stream(self) -> PaginatedStream<crate::list_response::SearchResultItem> {
+        let client = self.client;
+
+        let mut body = self.body;
+        body.page_size.get_or_insert(pagination::MAX_PAGE_SIZE);
+        let start_cursor = body.start_cursor.take();
+
+        pagination::paginate(start_cursor, move |cursor| {
+            let client = client.clone();
+            let body = SearchRequestBody {
+                start_cursor: cursor,
+                ..body.clone()
+            };
+
+            async move { fetch_page(&client, &body).await }
+        })
+    }
+
     pub fn query<T: AsRef<str>>(mut self, query: T) -> Self {
         self.body.query = Some(query.as_ref().to_string());
         self
@@ -121,3 +136,28 @@ impl SearchClient {
         self
     }
 }
+
+/// Fetches one page of the search results.
+pub(crate) async fn fetch_page<T, B>(
+    client: &crate::client::Client,
+    body: &B,
+) -> Result<ListResponse<T>, Error>
+where
+    T: serde::de::DeserializeOwned,
+    B: Serialize,
+{
+    let url = String::from("/search");
+
+    let request_body = body.to_json().to_string();
+
+    let request = client
+        .request(reqwest::Method::POST, url)
+        .header("Content-Type", "application/json")
+        .body(request_body);
+
+    let body = client.send_read_only(request).await?;
+
+    let pages = crate::client::deserialize_body::<ListResponse<T>>(&body)?;
+
+    Ok(pages)
+}
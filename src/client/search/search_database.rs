@@ -1,19 +1,19 @@
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    error::{api_error::ApiError, Error},
-    prelude::ToJson,
+    client::pagination::{self, PaginatedStream},
+    error::Error,
 };
 
 #[derive(Debug, Default)]
 pub struct SearchDatabaseClient {
-    /// The reqwest http client
-    pub(crate) reqwest_client: reqwest::Client,
+    /// The Notion API client
+    pub(crate) client: crate::client::Client,
 
     pub(crate) body: SearchDatabaseRequestBody,
 }
 
-#[derive(Debug, Serialize, Deserialize, Default)]
+#[derive(Debug, Serialize, Deserialize, Default, Clone)]
 pub struct SearchDatabaseRequestBody {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub(crate) query: Option<String>,
@@ -35,37 +35,36 @@ impl SearchDatabaseClient {
     pub async fn send(
         mut self,
     ) -> Result<crate::list_response::ListResponse<crate::database::DatabaseResponse>, Error> {
-        let url = String::from("https://api.notion.com/v1/search");
-
         self.body.filter = Some(crate::search::SearchFilter::database());
 
-        let request_body = self.body.to_json().to_string();
-
-        let request = self
-            .reqwest_client
-            .post(url)
-            .header("Content-Type", "application/json")
-            .body(request_body);
-
-        let response = request.send().await?;
-
-        if !response.status().is_success() {
-            let error_body = response.text().await?;
-
-            let error_json = serde_json::from_str::<ApiError>(&error_body)?;
-
-            return Err(Error::Api(Box::new(error_json)));
-        }
-
-        let body = response.text().await?;
-
-        let pages = serde_json::from_str::<
-            crate::list_response::ListResponse<crate::database::DatabaseResponse>,
-        >(&body)?;
+        let pages = super::fetch_page(&self.client, &self.body).await?;
 
         Ok(pages)
     }
 
+    /// Returns a stream that yields all the matching databases one by one.
+    ///
+    /// Results are fetched lazily, `page_size` (100 by default) per request,
+    /// and dropping the stream stops fetching.
+    pub fn into_stream(self) -> PaginatedStream<crate::database::DatabaseResponse> {
+        let client = self.client;
+
+        let mut body = self.body;
+        body.filter = Some(crate::search::SearchFilter::database());
+        body.page_size.get_or_insert(pagination::MAX_PAGE_SIZE);
+        let start_cursor = body.start_cursor.take();
+
+        pagination::paginate(start_cursor, move |cursor| {
+            let client = client.clone();
+            let body = SearchDatabaseRequestBody {
+                start_cursor: cursor,
+                ..body.clone()
+            };
+
+            async move { super::fetch_page(&client, &body).await }
+        })
+    }
+
     pub fn query<T: AsRef<str>>(mut self, query: T) -> Self {
         self.body.query = Some(query.as_ref().to_string());
         self
@@ -0,0 +1,49 @@
+/// The HTTP layer used by [`Client`](crate::client::Client) to send requests.
+///
+/// By default, requests are sent with a [`reqwest::Client`].
+/// Implement this trait to route requests through something else,
+/// such as a local stand-in server, a proxy, or a recording layer.
+///
+/// The request passed to `execute` is already complete: the URL is built from
+/// the configured base URL, and the `Authorization` and `Notion-Version`
+/// headers are attached.
+///
+/// ```no_run
+/// use notionrs::{client::Transport, error::Error, Client};
+///
+/// #[derive(Debug)]
+/// struct LoggingTransport(reqwest::Client);
+///
+/// #[async_trait::async_trait]
+/// impl Transport for LoggingTransport {
+///     async fn execute(&self, request: reqwest::Request) -> Result<reqwest::Response, Error> {
+///         println!("{} {}", request.method(), request.url());
+///         Ok(self.0.execute(request).await?)
+///     }
+/// }
+///
+/// let client = Client::builder()
+///     .transport(LoggingTransport(reqwest::Client::new()))
+///     .build();
+/// ```
+#[async_trait::async_trait]
+pub trait Transport: std::fmt::Debug + Send + Sync {
+    /// Send the request and return the raw HTTP response.
+    ///
+    /// Non-2xx responses should be returned as `Ok`;
+    /// they are converted into [`Error::Api`](crate::error::Error::Api) by the caller.
+    async fn execute(
+        &self,
+        request: reqwest::Request,
+    ) -> Result<reqwest::Response, crate::error::Error>;
+}
+
+#[async_trait::async_trait]
+impl Transport for reqwest::Client {
+    async fn execute(
+        &self,
+        request: reqwest::Request,
+    ) -> Result<reqwest::Response, crate::error::Error> {
+        Ok(reqwest::Client::execute(self, request).await?)
+    }
+}
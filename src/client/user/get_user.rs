@@ -1,14 +1,11 @@
-use crate::{
-    error::{api_error::ApiError, Error},
-    user::User,
-};
+use crate::{error::Error, user::User};
 
 #[derive(Debug)]
 pub struct GetUserClient {
-    /// The reqwest http client
-    pub(crate) reqwest_client: reqwest::Client,
+    /// The Notion API client
+    pub(crate) client: crate::client::Client,
 
-    pub(crate) user_id: Option<String>,
+    pub(crate) user_id: Option<crate::others::id::UserId>,
 }
 
 impl GetUserClient {
@@ -16,23 +13,13 @@ impl GetUserClient {
     pub async fn send(self) -> Result<User, Error> {
         match self.user_id {
             Some(id) => {
-                let url = format!("https://api.notion.com/v1/users/{}", id);
+                let url = format!("/users/{}", id);
 
-                let request = self.reqwest_client.get(url);
+                let request = self.client.request(reqwest::Method::GET, url);
 
-                let response = request.send().await?;
+                let body = self.client.send(request).await?;
 
-                if !response.status().is_success() {
-                    let error_body = response.text().await?;
-
-                    let error_json = serde_json::from_str::<ApiError>(&error_body)?;
-
-                    return Err(Error::Api(Box::new(error_json)));
-                }
-
-                let body = response.text().await?;
-
-                let user = serde_json::from_str::<User>(&body)?;
+                let user = crate::client::deserialize_body::<User>(&body)?;
 
                 Ok(user)
             }
@@ -41,8 +28,8 @@ impl GetUserClient {
     }
 
     /// Sets the user ID.
-    pub fn user_id<T: AsRef<str>>(mut self, user_id: T) -> Self {
-        self.user_id = Some(user_id.as_ref().to_string());
+    pub fn user_id<T: Into<crate::others::id::UserId>>(mut self, user_id: T) -> Self {
+        self.user_id = Some(user_id.into());
         self
     }
 }
@@ -1,7 +1,9 @@
+use futures::TryStreamExt;
 use serde::Serialize;
 
 use crate::{
-    error::{api_error::ApiError, Error},
+    client::pagination::{self, PaginatedStream},
+    error::Error,
     list_response::ListResponse,
     user::User,
 };
@@ -10,8 +12,8 @@ use crate::{
 
 #[derive(Debug)]
 pub struct ListUsersClient {
-    /// The reqwest http client
-    pub(crate) reqwest_client: reqwest::Client,
+    /// The Notion API client
+    pub(crate) client: crate::client::Client,
 
     /// The starting cursor position for pagination
     pub(crate) start_cursor: Option<String>,
@@ -40,41 +42,14 @@ struct LinsUserQueryParams {
 impl ListUsersClient {
     /// Send a request to the API endpoint of Notion.
     pub async fn send(&mut self) -> Result<ListResponse<User>, Error> {
-        let url = "https://api.notion.com/v1/users";
-        let mut results = Vec::new();
-
         if self.recursive {
-            loop {
-                let params = LinsUserQueryParams {
-                    start_cursor: self.start_cursor.clone(),
-                    page_size: Some(100),
-                };
-
-                let request = self.reqwest_client.get(url).query(&params);
-
-                let response = request.send().await?;
-
-                if !response.status().is_success() {
-                    let error_body = response.text().await?;
-
-                    let error_json = serde_json::from_str::<ApiError>(&error_body)?;
-
-                    return Err(Error::Api(Box::new(error_json)));
-                }
-
-                let body = response.text().await?;
-
-                let users_response = serde_json::from_str::<ListResponse<User>>(&body)?;
-
-                results.extend(users_response.results);
-
-                match users_response.next_cursor {
-                    Some(next_cursor) => {
-                        self.start_cursor = Some(next_cursor.clone());
-                    }
-                    None => break,
-                }
-            }
+            let results = paginate_users(
+                self.client.clone(),
+                self.start_cursor.clone(),
+                pagination::MAX_PAGE_SIZE as u8,
+            )
+            .try_collect::<Vec<User>>()
+            .await?;
 
             Ok(ListResponse {
                 object: "list".to_string(),
@@ -89,26 +64,37 @@ impl ListUsersClient {
                 page_size: self.page_size,
             };
 
-            let request = self.reqwest_client.get(url).query(&params);
-
-            let response = request.send().await?;
-
-            if !response.status().is_success() {
-                let error_body = response.text().await?;
-
-                let error_json = serde_json::from_str::<ApiError>(&error_body)?;
-
-                return Err(Error::Api(Box::new(error_json)));
-            }
-
-            let body = response.text().await?;
-
-            let users = serde_json::from_str::<ListResponse<User>>(&body)?;
-
-            Ok(users)
+            fetch_page(&self.client, &params).await
         }
     }
 
+    /// Returns a stream that yields all users in the workspace one by one.
+    ///
+    /// Users are fetched lazily, `page_size` (100 by default) per request,
+    /// and dropping the stream stops fetching. `recursive` has no effect on the stream.
+    ///
+    /// ```no_run
+    /// use futures::TryStreamExt;
+    ///
+    /// # async fn run() -> Result<(), notionrs::error::Error> {
+    /// let client = notionrs::Client::new();
+    ///
+    /// let users = client
+    ///     .list_users()
+    ///     .into_stream()
+    ///     .try_collect::<Vec<_>>()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn into_stream(self) -> PaginatedStream<User> {
+        paginate_users(
+            self.client,
+            self.start_cursor,
+            self.page_size.unwrap_or(pagination::MAX_PAGE_SIZE as u8),
+        )
+    }
+
     /// Performs cursor-based pagination when data cannot be fetched in one go.
     pub fn start_cursor<T: AsRef<str>>(mut self, start_cursor: T) -> Self {
         self.start_cursor = Some(start_cursor.as_ref().to_string());
@@ -128,3 +114,34 @@ impl ListUsersClient {
         self
     }
 }
+
+/// Fetches all users page by page, starting from `start_cursor`.
+fn paginate_users(
+    client: crate::client::Client,
+    start_cursor: Option<String>,
+    page_size: u8,
+) -> PaginatedStream<User> {
+    pagination::paginate(start_cursor, move |cursor| {
+        let client = client.clone();
+        let params = LinsUserQueryParams {
+            start_cursor: cursor,
+            page_size: Some(page_size),
+        };
+
+        async move { fetch_page(&client, &params).await }
+    })
+}
+
+/// Fetches one page of users.
+async fn fetch_page(
+    client: &crate::client::Client,
+    params: &LinsUserQueryParams,
+) -> Result<ListResponse<User>, Error> {
+    let request = client.request(reqwest::Method::GET, "/users").query(params);
+
+    let body = client.send(request).await?;
+
+    let users = crate::client::deserialize_body::<ListResponse<User>>(&body)?;
+
+    Ok(users)
+}
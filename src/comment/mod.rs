@@ -0,0 +1,108 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{others::parent::Parent, user::User, RichText};
+
+/// <https://developers.notion.com/reference/comment-object>
+///
+/// Comments are grouped into discussion threads. A comment created on a page starts a new
+/// discussion, and a comment created with a `discussion_id` is added to that thread.
+#[derive(Deserialize, Serialize, Debug)]
+pub struct Comment {
+    /// always "comment"
+    pub object: String,
+
+    /// Unique identifier of the comment.
+    pub id: String,
+
+    /// The page or block the comment belongs to.
+    pub parent: Parent,
+
+    /// Unique identifier of the discussion thread the comment belongs to.
+    pub discussion_id: String,
+
+    pub created_time: chrono::DateTime<chrono::FixedOffset>,
+
+    pub last_edited_time: chrono::DateTime<chrono::FixedOffset>,
+
+    /// The user who created the comment. Only the `id` is available.
+    pub created_by: User,
+
+    /// The content of the comment.
+    pub rich_text: Vec<RichText>,
+}
+
+// # --------------------------------------------------------------------------------
+//
+// unit test
+//
+// # --------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod unit_tests {
+
+    use super::*;
+
+    #[test]
+    fn deserialize_comment() {
+        let json_data = r#"
+        {
+            "object": "comment",
+            "id": "94cc56ab-9f02-409d-9f99-1037e9fe502f",
+            "parent": {
+                "type": "page_id",
+                "page_id": "5c6a2821-6bb1-4a7e-b6e1-c50111515c3d"
+            },
+            "discussion_id": "f1407351-36f5-4c49-a13c-49f8ba11776d",
+            "created_time": "2022-07-15T16:52:00.000Z",
+            "last_edited_time": "2022-07-15T19:16:00.000Z",
+            "created_by": {
+                "object": "user",
+                "id": "9b15170a-9941-4297-8ee6-83fa7649a87a"
+            },
+            "rich_text": [
+                {
+                    "type": "text",
+                    "text": {
+                        "content": "Single comment",
+                        "link": null
+                    },
+                    "annotations": {
+                        "bold": false,
+                        "italic": false,
+                        "strikethrough": false,
+                        "underline": false,
+                        "code": false,
+                        "color": "default"
+                    },
+                    "plain_text": "Single comment",
+                    "href": null
+                }
+            ]
+        }
+        "#;
+
+        let comment = serde_json::from_str::<Comment>(json_data).unwrap();
+
+        assert_eq!(comment.id, "94cc56ab-9f02-409d-9f99-1037e9fe502f");
+        assert_eq!(
+            comment.discussion_id,
+            "f1407351-36f5-4c49-a13c-49f8ba11776d"
+        );
+
+        match comment.parent {
+            Parent::PageParent(parent) => {
+                assert_eq!(parent.page_id, "5c6a2821-6bb1-4a7e-b6e1-c50111515c3d")
+            }
+            _ => panic!("unexpected parent"),
+        }
+
+        match comment.created_by {
+            User::Person(person) => {
+                assert_eq!(person.id, "9b15170a-9941-4297-8ee6-83fa7649a87a")
+            }
+            User::Bot(_) => panic!("unexpected user"),
+        }
+
+        assert_eq!(comment.rich_text[0].to_string(), "Single comment");
+    }
+}
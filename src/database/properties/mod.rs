@@ -63,6 +63,36 @@ pub enum DatabaseProperty {
     Url(url::DatabaseUrlProperty),
 }
 
+impl DatabaseProperty {
+    /// The name of the type of the property, as used by the Notion API (e.g. `rich_text`).
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            DatabaseProperty::Button(_) => "button",
+            DatabaseProperty::Checkbox(_) => "checkbox",
+            DatabaseProperty::CreatedBy(_) => "created_by",
+            DatabaseProperty::CreatedTime(_) => "created_time",
+            DatabaseProperty::Date(_) => "date",
+            DatabaseProperty::Email(_) => "email",
+            DatabaseProperty::Files(_) => "files",
+            DatabaseProperty::Formula(_) => "formula",
+            DatabaseProperty::LastEditedBy(_) => "last_edited_by",
+            DatabaseProperty::LastEditedTime(_) => "last_edited_time",
+            DatabaseProperty::MultiSelect(_) => "multi_select",
+            DatabaseProperty::Number(_) => "number",
+            DatabaseProperty::People(_) => "people",
+            DatabaseProperty::PhoneNumber(_) => "phone_number",
+            DatabaseProperty::Relation(_) => "relation",
+            DatabaseProperty::RichText(_) => "rich_text",
+            DatabaseProperty::Rollup(_) => "rollup",
+            DatabaseProperty::Select(_) => "select",
+            DatabaseProperty::Status(_) => "status",
+            DatabaseProperty::Title(_) => "title",
+            DatabaseProperty::UniqueId(_) => "unique_id",
+            DatabaseProperty::Url(_) => "url",
+        }
+    }
+}
+
 // # --------------------------------------------------------------------------------
 //
 // unit test
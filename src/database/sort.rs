@@ -1,12 +1,25 @@
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Eq, Default)]
-pub struct Sort {
-    /// Specify the name of the property
-    pub property: String,
+/// <https://developers.notion.com/reference/post-database-query-sort>
+///
+/// Pages are sorted by the value of a property, or by when they were created or last edited.
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Eq)]
+#[serde(untagged)]
+pub enum Sort {
+    Property {
+        /// Specify the name of the property
+        property: String,
 
-    /// Specify the sort order
-    pub direction: SortDirection,
+        /// Specify the sort order
+        direction: SortDirection,
+    },
+    Timestamp {
+        /// Specify the timestamp to sort by
+        timestamp: SortTimestamp,
+
+        /// Specify the sort order
+        direction: SortDirection,
+    },
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq, Eq, Default)]
@@ -17,6 +30,13 @@ pub enum SortDirection {
     Descending,
 }
 
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum SortTimestamp {
+    CreatedTime,
+    LastEditedTime,
+}
+
 impl Sort {
     /// Helper function to create an ascending order filter
     /// - property_name: The name of the database property to sort by
@@ -24,7 +44,7 @@ impl Sort {
     where
         T: AsRef<str>,
     {
-        Self {
+        Sort::Property {
             property: property_name.as_ref().to_string(),
             direction: SortDirection::Ascending,
         }
@@ -36,11 +56,50 @@ impl Sort {
     where
         T: AsRef<str>,
     {
-        Self {
+        Sort::Property {
             property: property_name.as_ref().to_string(),
             direction: SortDirection::Descending,
         }
     }
+
+    /// Oldest pages first.
+    pub fn created_time_asc() -> Self {
+        Sort::Timestamp {
+            timestamp: SortTimestamp::CreatedTime,
+            direction: SortDirection::Ascending,
+        }
+    }
+
+    /// Newest pages first.
+    pub fn created_time_desc() -> Self {
+        Sort::Timestamp {
+            timestamp: SortTimestamp::CreatedTime,
+            direction: SortDirection::Descending,
+        }
+    }
+
+    /// Least recently edited pages first.
+    pub fn last_edited_time_asc() -> Self {
+        Sort::Timestamp {
+            timestamp: SortTimestamp::LastEditedTime,
+            direction: SortDirection::Ascending,
+        }
+    }
+
+    /// Most recently edited pages first.
+    pub fn last_edited_time_desc() -> Self {
+        Sort::Timestamp {
+            timestamp: SortTimestamp::LastEditedTime,
+            direction: SortDirection::Descending,
+        }
+    }
+
+    /// The sort order.
+    pub fn direction(&self) -> SortDirection {
+        match self {
+            Sort::Property { direction, .. } | Sort::Timestamp { direction, .. } => *direction,
+        }
+    }
 }
 
 // # --------------------------------------------------------------------------------
@@ -80,4 +139,21 @@ mod unit_tests {
             "Serialized JSON does not match the expected JSON"
         );
     }
+
+    #[test]
+    fn serialize_timestamp_sort() {
+        let sort = Sort::last_edited_time_desc();
+
+        assert_eq!(
+            serde_json::to_string(&sort).unwrap(),
+            r#"{"timestamp":"last_edited_time","direction":"descending"}"#
+        );
+
+        let deserialized = serde_json::from_str::<Sort>(
+            r#"{"timestamp":"last_edited_time","direction":"descending"}"#,
+        )
+        .unwrap();
+
+        assert_eq!(deserialized, sort);
+    }
 }
@@ -23,7 +23,7 @@ pub struct ApiError {
     status: u16,
 
     /// Error code
-    code: String,
+    code: ErrorCode,
 
     /// Error details
     message: String,
@@ -33,4 +33,234 @@ pub struct ApiError {
 
     /// URL for the developer survey
     developer_survey: Option<String>,
+
+    /// The value of the `Retry-After` header, if the response had one.
+    #[serde(skip)]
+    retry_after: Option<std::time::Duration>,
+}
+
+impl ApiError {
+    /// HTTP Status Code ( `4xx` or `5xx` )
+    pub fn status(&self) -> u16 {
+        self.status
+    }
+
+    /// The error code, e.g. [`ErrorCode::ObjectNotFound`].
+    pub fn code(&self) -> &ErrorCode {
+        &self.code
+    }
+
+    /// A human-readable description of the error.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// The request identifier, useful when contacting Notion support.
+    pub fn request_id(&self) -> Option<&str> {
+        self.request_id.as_deref()
+    }
+
+    /// URL for the developer survey
+    pub fn developer_survey(&self) -> Option<&str> {
+        self.developer_survey.as_deref()
+    }
+
+    /// How long Notion asked to wait before retrying (the `Retry-After` header).
+    /// It is usually sent with `rate_limited` errors.
+    pub fn retry_after(&self) -> Option<std::time::Duration> {
+        self.retry_after
+    }
+
+    pub(crate) fn with_retry_after(mut self, retry_after: Option<std::time::Duration>) -> Self {
+        self.retry_after = retry_after;
+        self
+    }
+
+    /// Whether the request may succeed if it is sent again later.
+    pub fn is_retryable(&self) -> bool {
+        self.code.is_retryable()
+    }
+}
+
+/// <https://developers.notion.com/reference/status-codes#error-codes>
+///
+/// Codes that are not known to this crate are kept as [`ErrorCode::Other`].
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(from = "String", into = "String")]
+pub enum ErrorCode {
+    /// `400`: The request body could not be decoded as JSON.
+    InvalidJson,
+
+    /// `400`: The request URL is not valid.
+    InvalidRequestUrl,
+
+    /// `400`: This request is not supported.
+    InvalidRequest,
+
+    /// `400`: The provided authorization grant or refresh token is invalid.
+    InvalidGrant,
+
+    /// `400`: The request body does not match the schema for the expected parameters.
+    ValidationError,
+
+    /// `400`: The request is missing the required `Notion-Version` header.
+    MissingVersion,
+
+    /// `401`: The bearer token is not valid.
+    Unauthorized,
+
+    /// `403`: The integration doesn't have permission to perform the operation.
+    RestrictedResource,
+
+    /// `404`: The resource does not exist, or it hasn't been shared with the integration.
+    ObjectNotFound,
+
+    /// `409`: The transaction could not be completed, potentially due to a data collision.
+    ConflictError,
+
+    /// `429`: The request exceeded the number of requests allowed.
+    RateLimited,
+
+    /// `500`: An unexpected error occurred.
+    InternalServerError,
+
+    /// `502`: Notion encountered an issue while attempting to complete the request.
+    BadGateway,
+
+    /// `503`: Notion is unavailable, or the request took longer than 60 seconds.
+    ServiceUnavailable,
+
+    /// `503`: Notion's database is unavailable or in a state that can't be queried.
+    DatabaseConnectionUnavailable,
+
+    /// `504`: Notion timed out while attempting to complete the request.
+    GatewayTimeout,
+
+    /// Any other error code.
+    Other(String),
+}
+
+impl ErrorCode {
+    /// The code as it appears in the response, e.g. `"object_not_found"`.
+    pub fn as_str(&self) -> &str {
+        match self {
+            ErrorCode::InvalidJson => "invalid_json",
+            ErrorCode::InvalidRequestUrl => "invalid_request_url",
+            ErrorCode::InvalidRequest => "invalid_request",
+            ErrorCode::InvalidGrant => "invalid_grant",
+            ErrorCode::ValidationError => "validation_error",
+            ErrorCode::MissingVersion => "missing_version",
+            ErrorCode::Unauthorized => "unauthorized",
+            ErrorCode::RestrictedResource => "restricted_resource",
+            ErrorCode::ObjectNotFound => "object_not_found",
+            ErrorCode::ConflictError => "conflict_error",
+            ErrorCode::RateLimited => "rate_limited",
+            ErrorCode::InternalServerError => "internal_server_error",
+            ErrorCode::BadGateway => "bad_gateway",
+            ErrorCode::ServiceUnavailable => "service_unavailable",
+            ErrorCode::DatabaseConnectionUnavailable => "database_connection_unavailable",
+            ErrorCode::GatewayTimeout => "gateway_timeout",
+            ErrorCode::Other(code) => code,
+        }
+    }
+
+    /// Whether a request that failed with this code may succeed if it is sent again later.
+    pub fn is_retryable(&self) -> bool {
+        matches!(
+            self,
+            ErrorCode::ConflictError
+                | ErrorCode::RateLimited
+                | ErrorCode::InternalServerError
+                | ErrorCode::BadGateway
+                | ErrorCode::ServiceUnavailable
+                | ErrorCode::DatabaseConnectionUnavailable
+                | ErrorCode::GatewayTimeout
+        )
+    }
+}
+
+impl From<String> for ErrorCode {
+    fn from(code: String) -> Self {
+        match code.as_str() {
+            "invalid_json" => ErrorCode::InvalidJson,
+            "invalid_request_url" => ErrorCode::InvalidRequestUrl,
+            "invalid_request" => ErrorCode::InvalidRequest,
+            "invalid_grant" => ErrorCode::InvalidGrant,
+            "validation_error" => ErrorCode::ValidationError,
+            "missing_version" => ErrorCode::MissingVersion,
+            "unauthorized" => ErrorCode::Unauthorized,
+            "restricted_resource" => ErrorCode::RestrictedResource,
+            "object_not_found" => ErrorCode::ObjectNotFound,
+            "conflict_error" => ErrorCode::ConflictError,
+            "rate_limited" => ErrorCode::RateLimited,
+            "internal_server_error" => ErrorCode::InternalServerError,
+            "bad_gateway" => ErrorCode::BadGateway,
+            "service_unavailable" => ErrorCode::ServiceUnavailable,
+            "database_connection_unavailable" => ErrorCode::DatabaseConnectionUnavailable,
+            "gateway_timeout" => ErrorCode::GatewayTimeout,
+            _ => ErrorCode::Other(code),
+        }
+    }
+}
+
+impl From<ErrorCode> for String {
+    fn from(code: ErrorCode) -> Self {
+        code.as_str().to_string()
+    }
+}
+
+impl std::fmt::Display for ErrorCode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+// # --------------------------------------------------------------------------------
+//
+// unit test
+//
+// # --------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod unit_tests {
+
+    use super::*;
+
+    #[test]
+    fn deserialize_api_error() {
+        let json_data = r#"
+        {
+            "object": "error",
+            "status": 404,
+            "code": "object_not_found",
+            "message": "Could not find page with ID: 5c6a2821-6bb1-4a7e-b6e1-c50111515c3d.",
+            "request_id": "2cccb738-bf60-4e9b-bb6b-24a87fb17ef3"
+        }
+        "#;
+
+        let api_error = serde_json::from_str::<ApiError>(json_data).unwrap();
+
+        assert_eq!(api_error.status(), 404);
+        assert_eq!(api_error.code(), &ErrorCode::ObjectNotFound);
+        assert_eq!(
+            api_error.request_id(),
+            Some("2cccb738-bf60-4e9b-bb6b-24a87fb17ef3")
+        );
+        assert!(!api_error.is_retryable());
+    }
+
+    #[test]
+    fn unknown_error_code() {
+        let code = serde_json::from_str::<ErrorCode>(r#""brand_new_error""#).unwrap();
+
+        assert_eq!(code, ErrorCode::Other("brand_new_error".to_string()));
+        assert_eq!(
+            serde_json::to_string(&code).unwrap(),
+            r#""brand_new_error""#
+        );
+        assert_eq!(
+            serde_json::to_string(&ErrorCode::RateLimited).unwrap(),
+            r#""rate_limited""#
+        );
+    }
 }
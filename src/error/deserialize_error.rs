@@ -0,0 +1,117 @@
+use thiserror::Error;
+
+/// The number of characters shown before and after the failed position in the body snippet.
+const SNIPPET_RADIUS: usize = 100;
+
+/// A successful response that could not be deserialized into the expected type.
+///
+/// This usually means that the Notion API returned a field that this crate doesn't support yet.
+#[derive(Error, Debug)]
+#[error("failed to deserialize the response at `{path}`: {source} (near: {snippet})")]
+pub struct DeserializeError {
+    /// The JSON path of the value that failed, e.g. `results[3].properties.Status`.
+    path: String,
+
+    /// The underlying error.
+    source: serde_json::Error,
+
+    /// The part of the response body around the failed position.
+    snippet: String,
+}
+
+impl DeserializeError {
+    pub(crate) fn new(error: serde_path_to_error::Error<serde_json::Error>, body: &str) -> Self {
+        let path = error.path().to_string();
+        let source = error.into_inner();
+        let snippet = snippet(body, source.line(), source.column());
+
+        Self {
+            path,
+            source,
+            snippet,
+        }
+    }
+
+    /// The JSON path of the value that failed, e.g. `results[3].properties.Status`.
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// The part of the response body around the failed position.
+    pub fn snippet(&self) -> &str {
+        &self.snippet
+    }
+}
+
+/// Returns up to `SNIPPET_RADIUS` characters around the 1-based `line` and `column`.
+fn snippet(body: &str, line: usize, column: usize) -> String {
+    let offset = body
+        .split_inclusive('\n')
+        .take(line.saturating_sub(1))
+        .map(str::len)
+        .sum::<usize>()
+        + column;
+
+    let mut offset = offset.min(body.len());
+
+    // The column counts bytes, so the offset can fall inside a multi-byte character.
+    while !body.is_char_boundary(offset) {
+        offset -= 1;
+    }
+
+    let start = body[..offset]
+        .char_indices()
+        .rev()
+        .nth(SNIPPET_RADIUS)
+        .map(|(index, _)| index)
+        .unwrap_or(0);
+
+    super::http_error::truncate(&body[start..], SNIPPET_RADIUS * 2)
+}
+
+// # --------------------------------------------------------------------------------
+//
+// unit test
+//
+// # --------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod unit_tests {
+
+    #[test]
+    fn report_path_and_snippet() {
+        #[derive(serde::Deserialize, Debug)]
+        #[allow(dead_code)]
+        struct Page {
+            id: String,
+            archived: bool,
+        }
+
+        let body = r#"{"results":[{"id":"a","archived":false},{"id":"b","archived":"no"}]}"#;
+
+        let error =
+            crate::client::deserialize_body::<crate::list_response::ListResponse<Page>>(body)
+                .unwrap_err();
+
+        match error {
+            crate::error::Error::Deserialize(error) => {
+                assert_eq!(error.path(), "results[1].archived");
+                assert!(error.snippet().contains(r#""archived":"no""#));
+            }
+            _ => panic!("unexpected error: {:?}", error),
+        }
+    }
+
+    #[test]
+    fn snippet_of_non_ascii_body() {
+        let bodies = [r#""あ""#, "あいう", r#"{"name":"日本語"}"#];
+
+        for body in bodies {
+            let error = crate::client::deserialize_body::<crate::user::User>(body).unwrap_err();
+
+            assert!(matches!(error, crate::error::Error::Deserialize(_)));
+        }
+
+        assert_eq!(super::snippet("あいう", 1, 2), "あいう");
+    }
+}
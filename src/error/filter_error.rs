@@ -0,0 +1,47 @@
+use thiserror::Error;
+
+/// A filter is not valid for a database, found by
+/// [`Filter::validate`](crate::filter::Filter::validate).
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[error("invalid filter: {}", .problems.iter().map(|p| p.to_string()).collect::<Vec<_>>().join("; "))]
+pub struct FilterError {
+    pub(crate) problems: Vec<FilterProblem>,
+}
+
+impl FilterError {
+    /// All problems found in the filter.
+    pub fn problems(&self) -> &[FilterProblem] {
+        &self.problems
+    }
+}
+
+/// A problem in a filter.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum FilterProblem {
+    /// The database doesn't have a property with this name.
+    #[error("property `{property}` does not exist")]
+    UnknownProperty { property: String },
+
+    /// The condition cannot be applied to the type of the property
+    /// (e.g. a `select` condition on a `status` property).
+    #[error(
+        "the `{condition}` condition cannot be applied to property `{property}` of type `{found}`"
+    )]
+    IncompatibleCondition {
+        property: String,
+        condition: &'static str,
+        found: &'static str,
+    },
+
+    /// A filter has a condition but neither `property` nor `timestamp`.
+    #[error("the `{condition}` condition has no property")]
+    MissingProperty { condition: &'static str },
+
+    /// A filter has a `property` but no condition.
+    #[error("property `{property}` has no condition")]
+    MissingCondition { property: String },
+
+    /// Compound filters (`and` / `or`) are nested deeper than the API allows.
+    #[error("compound filters are nested {depth} levels deep, but the limit is {limit}")]
+    TooDeep { depth: usize, limit: usize },
+}
@@ -0,0 +1,140 @@
+use thiserror::Error;
+
+/// The maximum number of characters of the response body kept in errors.
+pub(crate) const MAX_BODY_LENGTH: usize = 1024;
+
+/// The headers kept in [`HttpError`]. Other headers are dropped.
+const KEPT_HEADERS: [&str; 4] = ["content-type", "retry-after", "x-request-id", "cf-ray"];
+
+/// A non-2xx response whose body is not a Notion error object,
+/// such as an HTML page returned by a gateway or an empty body.
+#[derive(Error, Debug)]
+#[error("HTTP error: status {status}, body: {body}")]
+pub struct HttpError {
+    /// HTTP Status Code
+    status: u16,
+
+    /// The headers that help identify the failure (`Content-Type`, `Retry-After`, ...).
+    headers: reqwest::header::HeaderMap,
+
+    /// The response body, truncated to 1024 characters.
+    body: String,
+}
+
+impl HttpError {
+    pub(crate) fn new(status: u16, headers: &reqwest::header::HeaderMap, body: &str) -> Self {
+        let headers = headers
+            .iter()
+            .filter(|(name, _)| KEPT_HEADERS.contains(&name.as_str()))
+            .map(|(name, value)| (name.clone(), value.clone()))
+            .collect();
+
+        Self {
+            status,
+            headers,
+            body: truncate(body, MAX_BODY_LENGTH),
+        }
+    }
+
+    /// HTTP Status Code
+    pub fn status(&self) -> u16 {
+        self.status
+    }
+
+    /// The headers that help identify the failure:
+    /// `Content-Type`, `Retry-After`, `X-Request-Id` and `CF-Ray`.
+    pub fn headers(&self) -> &reqwest::header::HeaderMap {
+        &self.headers
+    }
+
+    /// The response body, truncated to 1024 characters.
+    pub fn body(&self) -> &str {
+        &self.body
+    }
+
+    /// Whether the request may succeed if it is sent again later (`429` or `5xx`).
+    pub fn is_retryable(&self) -> bool {
+        self.status == 429 || (500..600).contains(&self.status)
+    }
+
+    /// How long the server asked to wait before retrying (the `Retry-After` header).
+    pub fn retry_after(&self) -> Option<std::time::Duration> {
+        crate::client::retry::parse_retry_after(&self.headers)
+    }
+}
+
+/// Truncates `text` to `max_chars` characters, appending `...` if anything was cut off.
+pub(crate) fn truncate(text: &str, max_chars: usize) -> String {
+    match text.char_indices().nth(max_chars) {
+        Some((index, _)) => format!("{}...", &text[..index]),
+        None => text.to_string(),
+    }
+}
+
+// # --------------------------------------------------------------------------------
+//
+// unit test
+//
+// # --------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod unit_tests {
+
+    use super::*;
+
+    #[test]
+    fn keep_relevant_headers_and_truncate_body() {
+        let mut headers = reqwest::header::HeaderMap::new();
+        headers.insert("content-type", "text/html".parse().unwrap());
+        headers.insert("set-cookie", "secret".parse().unwrap());
+
+        let body = "あ".repeat(MAX_BODY_LENGTH + 1);
+
+        let error = HttpError::new(502, &headers, &body);
+
+        assert_eq!(error.status(), 502);
+        assert_eq!(error.headers().len(), 1);
+        assert_eq!(error.headers()["content-type"], "text/html");
+        assert_eq!(error.body().chars().count(), MAX_BODY_LENGTH + 3);
+        assert!(error.is_retryable());
+    }
+
+    #[derive(Debug)]
+    struct GatewayTransport;
+
+    #[async_trait::async_trait]
+    impl crate::client::Transport for GatewayTransport {
+        async fn execute(
+            &self,
+            _request: reqwest::Request,
+        ) -> Result<reqwest::Response, crate::error::Error> {
+            let response = http::Response::builder()
+                .status(502)
+                .header("content-type", "text/html")
+                .body("<html><body>502 Bad Gateway</body></html>")
+                .unwrap();
+
+            Ok(reqwest::Response::from(response))
+        }
+    }
+
+    #[tokio::test]
+    async fn non_json_error_body() {
+        let error = crate::client::Client::builder()
+            .secret("secret_test")
+            .transport(GatewayTransport)
+            .build()
+            .get_self()
+            .send()
+            .await
+            .unwrap_err();
+
+        match error {
+            crate::error::Error::Http(error) => {
+                assert_eq!(error.status(), 502);
+                assert_eq!(error.body(), "<html><body>502 Bad Gateway</body></html>");
+            }
+            _ => panic!("unexpected error: {:?}", error),
+        }
+    }
+}
@@ -0,0 +1,15 @@
+use thiserror::Error;
+
+/// A string is not a Notion ID, nor a Notion URL containing one.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[error("invalid Notion ID: `{input}`")]
+pub struct IdError {
+    pub(crate) input: String,
+}
+
+impl IdError {
+    /// The string that could not be parsed.
+    pub fn input(&self) -> &str {
+        &self.input
+    }
+}
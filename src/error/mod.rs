@@ -1,4 +1,16 @@
 pub mod api_error;
+pub mod deserialize_error;
+pub mod filter_error;
+pub mod http_error;
+pub mod id_error;
+pub mod property_error;
+
+pub use api_error::{ApiError, ErrorCode};
+pub use deserialize_error::DeserializeError;
+pub use filter_error::{FilterError, FilterProblem};
+pub use http_error::HttpError;
+pub use id_error::IdError;
+pub use property_error::PropertyError;
 
 #[derive(thiserror::Error, Debug)]
 pub enum Error {
@@ -8,15 +20,45 @@ pub enum Error {
     #[error("notion api error: {0}")]
     Api(Box<api_error::ApiError>),
 
+    /// A non-2xx response whose body is not a Notion error object
+    /// (e.g. an HTML page returned by a gateway).
+    #[error("{0}")]
+    Http(Box<http_error::HttpError>),
+
+    /// A successful response could not be deserialized into the expected type.
+    #[error("{0}")]
+    Deserialize(Box<deserialize_error::DeserializeError>),
+
+    /// A page property could not be converted to the requested type.
+    #[error("{0}")]
+    Property(Box<property_error::PropertyError>),
+
+    /// A filter is not valid for the database it is sent to.
+    #[error("{0}")]
+    Filter(Box<filter_error::FilterError>),
+
+    /// A string could not be parsed as a Notion ID.
+    #[error("{0}")]
+    Id(Box<id_error::IdError>),
+
     /// Since we are using the Builder pattern, it is possible to send
     /// a request even if some parameters are missing. In such cases
     /// where the request parameters are insufficient, we will throw this error.
     #[error("notion request parameter error: {0}")]
     RequestParameter(String),
 
+    /// A request has no recorded response in the [`Cassette`](crate::client::Cassette)
+    /// being replayed.
+    #[error("cassette error: {0}")]
+    Cassette(String),
+
     #[error("deserialization error: {0}")]
     Serde(#[from] serde_json::Error),
 
+    /// Reading a local file (e.g. for uploading) failed.
+    #[error("io error: {0}")]
+    Io(#[from] std::io::Error),
+
     #[error("unknown error: {0}")]
     Unknown(String),
 
@@ -25,3 +67,53 @@ pub enum Error {
     #[error("custom error: {0}")]
     Custom(String),
 }
+
+impl From<property_error::PropertyError> for Error {
+    fn from(error: property_error::PropertyError) -> Self {
+        Error::Property(Box::new(error))
+    }
+}
+
+impl From<filter_error::FilterError> for Error {
+    fn from(error: filter_error::FilterError) -> Self {
+        Error::Filter(Box::new(error))
+    }
+}
+
+impl From<id_error::IdError> for Error {
+    fn from(error: id_error::IdError) -> Self {
+        Error::Id(Box::new(error))
+    }
+}
+
+impl Error {
+    /// Whether the request may succeed if it is sent again later,
+    /// e.g. `rate_limited`, `5xx` errors, or a network timeout.
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            Error::Api(api_error) => api_error.is_retryable(),
+            Error::Http(http_error) => http_error.is_retryable(),
+            Error::Network(error) => error.is_timeout() || error.is_connect(),
+            _ => false,
+        }
+    }
+
+    /// Whether the resource doesn't exist or hasn't been shared with the integration
+    /// (`object_not_found`).
+    pub fn is_not_found(&self) -> bool {
+        match self {
+            Error::Api(api_error) => api_error.code() == &ErrorCode::ObjectNotFound,
+            Error::Http(http_error) => http_error.status() == 404,
+            _ => false,
+        }
+    }
+
+    /// How long Notion asked to wait before retrying (the `Retry-After` header).
+    pub fn retry_after(&self) -> Option<std::time::Duration> {
+        match self {
+            Error::Api(api_error) => api_error.retry_after(),
+            Error::Http(http_error) => http_error.retry_after(),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,43 @@
+use thiserror::Error;
+
+/// A page property could not be converted to (or from) a Rust value,
+/// e.g. in [`NotionRow::from_page`](crate::page::row::NotionRow::from_page).
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum PropertyError {
+    /// The page doesn't have a property with this name.
+    #[error("property `{property}` is missing")]
+    Missing { property: String },
+
+    /// The property has a different type than expected.
+    #[error("property `{property}` is expected to be `{expected}`, but it is `{found}`")]
+    TypeMismatch {
+        property: String,
+        expected: &'static str,
+        found: &'static str,
+    },
+
+    /// The property has no value (e.g. an empty number or date), but the field is not an `Option`.
+    #[error("property `{property}` is empty")]
+    Empty { property: String },
+
+    /// The number cannot be converted to the integer type of the field without losing
+    /// information, e.g. `3.7` or `-1` for a `u32`.
+    #[error("property `{property}` has the value `{value}`, which is not a valid `{expected}`")]
+    InvalidNumber {
+        property: String,
+        value: String,
+        expected: &'static str,
+    },
+}
+
+impl PropertyError {
+    /// The name of the property.
+    pub fn property(&self) -> &str {
+        match self {
+            PropertyError::Missing { property }
+            | PropertyError::TypeMismatch { property, .. }
+            | PropertyError::Empty { property }
+            | PropertyError::InvalidNumber { property, .. } => property,
+        }
+    }
+}
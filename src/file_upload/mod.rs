@@ -0,0 +1,97 @@
+use serde::{Deserialize, Serialize};
+
+/// <https://developers.notion.com/reference/file-upload>
+///
+/// A file upload created with [`Client::upload_file`](crate::Client::upload_file).
+/// Attach it to blocks, properties, icons or covers with
+/// [`File::file_upload`](crate::File::file_upload) using its `id`.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct FileUpload {
+    /// always "file_upload"
+    pub object: String,
+
+    /// Unique identifier of the file upload.
+    pub id: String,
+
+    pub created_time: chrono::DateTime<chrono::FixedOffset>,
+
+    pub last_edited_time: chrono::DateTime<chrono::FixedOffset>,
+
+    /// The file upload expires if it is not attached to anything before this time.
+    pub expiry_time: Option<chrono::DateTime<chrono::FixedOffset>>,
+
+    pub status: FileUploadStatus,
+
+    pub filename: Option<String>,
+
+    pub content_type: Option<String>,
+
+    pub content_length: Option<u64>,
+
+    /// The URL to which the file contents are sent.
+    pub upload_url: Option<String>,
+
+    /// The URL used to complete a multi-part upload.
+    pub complete_url: Option<String>,
+
+    #[serde(default)]
+    pub archived: bool,
+
+    pub request_id: Option<String>,
+}
+
+/// The status of a [`FileUpload`].
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum FileUploadStatus {
+    /// Waiting for the file contents (or the remaining parts).
+    Pending,
+
+    /// The file contents have been received, and the upload can be attached.
+    Uploaded,
+
+    /// The upload was not completed or attached in time.
+    Expired,
+
+    /// The upload failed.
+    Failed,
+}
+
+// # --------------------------------------------------------------------------------
+//
+// unit test
+//
+// # --------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod unit_tests {
+
+    use super::*;
+
+    #[test]
+    fn deserialize_file_upload() {
+        let json_data = r#"
+        {
+            "object": "file_upload",
+            "id": "43833259-72ae-404e-8441-b6577f3159b4",
+            "created_time": "2025-03-15T20:53:00.000Z",
+            "last_edited_time": "2025-03-15T20:53:00.000Z",
+            "expiry_time": "2025-03-15T21:53:00.000Z",
+            "upload_url": "https://api.notion.com/v1/file_uploads/43833259-72ae-404e-8441-b6577f3159b4/send",
+            "archived": false,
+            "status": "pending",
+            "filename": "report.pdf",
+            "content_type": "application/pdf",
+            "content_length": null,
+            "request_id": "b1b8bd83-1e2a-4fcb-95b0-1d1c0e6e3b7c"
+        }
+        "#;
+
+        let file_upload = serde_json::from_str::<FileUpload>(json_data).unwrap();
+
+        assert_eq!(file_upload.id, "43833259-72ae-404e-8441-b6577f3159b4");
+        assert_eq!(file_upload.status, FileUploadStatus::Pending);
+        assert_eq!(file_upload.filename, Some("report.pdf".to_string()));
+        assert_eq!(file_upload.complete_url, None);
+    }
+}
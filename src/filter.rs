@@ -1,5 +1,10 @@
 use serde::{Deserialize, Serialize};
 
+mod matches;
+mod validate;
+
+pub use validate::MAX_NESTING_DEPTH;
+
 // # --------------------------------------------------------------------------------
 //
 // Filter
@@ -89,31 +94,160 @@ pub struct DateFilter {
     pub is_not_empty: Option<bool>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub next_month: Option<std::collections::HashMap<(), ()>>,
+    pub on_or_after: Option<String>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub next_week: Option<std::collections::HashMap<(), ()>>,
+    pub on_or_before: Option<String>,
 
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub next_year: Option<std::collections::HashMap<(), ()>>,
+    /// A range relative to today, e.g. `{ "past_week": {} }`.
+    #[serde(flatten, skip_serializing_if = "Option::is_none")]
+    pub relative: Option<RelativeDate>,
+}
 
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub on_or_after: Option<String>,
+/// A date to compare a date or timestamp with, in the format the Notion API expects.
+///
+/// - A `&str` or `String` is sent as it is, e.g. `"2021-05-10"` or `"2021-10-15T12:00:00-07:00"`.
+/// - A [`chrono::NaiveDate`] is sent as a date without time, e.g. `"2021-05-10"`.
+/// - A [`chrono::DateTime`] is sent as a date-time with its offset, e.g. `"2021-10-15T12:00:00-07:00"`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct FilterDate(String);
 
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub on_or_before: Option<String>,
+impl FilterDate {
+    /// The date as it is sent to the API.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
 
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub past_month: Option<std::collections::HashMap<(), ()>>,
+impl std::fmt::Display for FilterDate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
 
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub past_week: Option<std::collections::HashMap<(), ()>>,
+impl From<&str> for FilterDate {
+    fn from(date: &str) -> Self {
+        Self(date.to_string())
+    }
+}
 
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub past_year: Option<std::collections::HashMap<(), ()>>,
+impl From<String> for FilterDate {
+    fn from(date: String) -> Self {
+        Self(date)
+    }
+}
 
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub this_week: Option<std::collections::HashMap<(), ()>>,
+impl From<&String> for FilterDate {
+    fn from(date: &String) -> Self {
+        Self(date.clone())
+    }
+}
+
+impl From<chrono::NaiveDate> for FilterDate {
+    fn from(date: chrono::NaiveDate) -> Self {
+        Self(date.format("%Y-%m-%d").to_string())
+    }
+}
+
+impl<Tz> From<chrono::DateTime<Tz>> for FilterDate
+where
+    Tz: chrono::TimeZone,
+    Tz::Offset: std::fmt::Display,
+{
+    fn from(date_time: chrono::DateTime<Tz>) -> Self {
+        Self(date_time.to_rfc3339_opts(chrono::SecondsFormat::AutoSi, false))
+    }
+}
+
+impl From<FilterDate> for String {
+    fn from(date: FilterDate) -> Self {
+        date.0
+    }
+}
+
+/// A range of dates relative to today.
+///
+/// Serialized as an object with an empty object, e.g. `{ "past_week": {} }`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RelativeDate {
+    PastWeek,
+    PastMonth,
+    PastYear,
+    ThisWeek,
+    NextWeek,
+    NextMonth,
+    NextYear,
+}
+
+impl RelativeDate {
+    const ALL: [RelativeDate; 7] = [
+        RelativeDate::PastWeek,
+        RelativeDate::PastMonth,
+        RelativeDate::PastYear,
+        RelativeDate::ThisWeek,
+        RelativeDate::NextWeek,
+        RelativeDate::NextMonth,
+        RelativeDate::NextYear,
+    ];
+
+    /// The name of the condition in the request body, e.g. `"past_week"`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RelativeDate::PastWeek => "past_week",
+            RelativeDate::PastMonth => "past_month",
+            RelativeDate::PastYear => "past_year",
+            RelativeDate::ThisWeek => "this_week",
+            RelativeDate::NextWeek => "next_week",
+            RelativeDate::NextMonth => "next_month",
+            RelativeDate::NextYear => "next_year",
+        }
+    }
+}
+
+impl Serialize for RelativeDate {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeMap;
+
+        let mut map = serializer.serialize_map(Some(1))?;
+        map.serialize_entry(self.as_str(), &serde_json::Map::new())?;
+        map.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for RelativeDate {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct RelativeDateVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for RelativeDateVisitor {
+            type Value = RelativeDate;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+                formatter.write_str("an object with a relative date, e.g. `past_week`")
+            }
+
+            fn visit_map<A: serde::de::MapAccess<'de>>(
+                self,
+                mut map: A,
+            ) -> Result<Self::Value, A::Error> {
+                let mut relative = None;
+
+                // Other conditions of a date filter are also in the map when it is flattened.
+                while let Some(key) = map.next_key::<String>()? {
+                    map.next_value::<serde::de::IgnoredAny>()?;
+
+                    if relative.is_none() {
+                        relative = RelativeDate::ALL
+                            .into_iter()
+                            .find(|relative| relative.as_str() == key);
+                    }
+                }
+
+                relative.ok_or_else(|| serde::de::Error::custom("no relative date"))
+            }
+        }
+
+        deserializer.deserialize_map(RelativeDateVisitor)
+    }
 }
 
 // # --------------------------------------------------------------------------------
@@ -404,32 +538,15 @@ pub struct TimestampFilter {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub is_not_empty: Option<bool>,
 
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub next_month: Option<std::collections::HashMap<(), ()>>,
-
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub next_week: Option<std::collections::HashMap<(), ()>>,
-
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub next_year: Option<std::collections::HashMap<(), ()>>,
-
     #[serde(skip_serializing_if = "Option::is_none")]
     pub on_or_after: Option<String>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
     pub on_or_before: Option<String>,
 
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub past_month: Option<std::collections::HashMap<(), ()>>,
-
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub past_week: Option<std::collections::HashMap<(), ()>>,
-
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub past_year: Option<std::collections::HashMap<(), ()>>,
-
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub this_week: Option<std::collections::HashMap<(), ()>>,
+    /// A range relative to today, e.g. `{ "past_week": {} }`.
+    #[serde(flatten, skip_serializing_if = "Option::is_none")]
+    pub relative: Option<RelativeDate>,
 }
 
 // # --------------------------------------------------------------------------------
@@ -521,11 +638,12 @@ impl Filter {
     /// - `property_name`: Property Name (Column Name) in Notion Database
     /// - `date`: ISO 8601 date
     ///   - e.g.) `"2021-05-10"`, `"2021-05-10T12:00:00"`, `"2021-10-15T12:00:00-07:00"`
-    pub fn date_after<S: AsRef<str>, T: AsRef<str>>(property_name: S, date: T) -> Self {
+    ///   - or a `chrono::NaiveDate` / `chrono::DateTime` (see [`FilterDate`])
+    pub fn date_after<S: AsRef<str>, T: Into<FilterDate>>(property_name: S, date: T) -> Self {
         Filter {
             property: Some(property_name.as_ref().to_string()),
             condition: Some(Condition::Date(Box::new(DateFilter {
-                after: Some(date.as_ref().to_string()),
+                after: Some(date.into().into()),
                 ..Default::default()
             }))),
             ..Default::default()
@@ -537,11 +655,12 @@ impl Filter {
     /// - `property_name`: Property Name (Column Name) in Notion Database
     /// - `date`: The value to compare the date property value against. (ISO 8601 date)
     ///   - e.g.) `"2021-05-10"`, `"2021-05-10T12:00:00"`, `"2021-10-15T12:00:00-07:00"`
-    pub fn date_before<S: AsRef<str>, T: AsRef<str>>(property_name: S, date: T) -> Self {
+    ///   - or a `chrono::NaiveDate` / `chrono::DateTime` (see [`FilterDate`])
+    pub fn date_before<S: AsRef<str>, T: Into<FilterDate>>(property_name: S, date: T) -> Self {
         Filter {
             property: Some(property_name.as_ref().to_string()),
             condition: Some(Condition::Date(Box::new(DateFilter {
-                before: Some(date.as_ref().to_string()),
+                before: Some(date.into().into()),
                 ..Default::default()
             }))),
             ..Default::default()
@@ -553,11 +672,12 @@ impl Filter {
     /// - `property_name`: Property Name (Column Name) in Notion Database
     /// - `date`: The value to compare the date property value against. (ISO 8601 date)
     ///   - e.g.) `"2021-05-10"`, `"2021-05-10T12:00:00"`, `"2021-10-15T12:00:00-07:00"`
-    pub fn date_equals<S: AsRef<str>, T: AsRef<str>>(property_name: S, date: T) -> Self {
+    ///   - or a `chrono::NaiveDate` / `chrono::DateTime` (see [`FilterDate`])
+    pub fn date_equals<S: AsRef<str>, T: Into<FilterDate>>(property_name: S, date: T) -> Self {
         Filter {
             property: Some(property_name.as_ref().to_string()),
             condition: Some(Condition::Date(Box::new(DateFilter {
-                equals: Some(date.as_ref().to_string()),
+                equals: Some(date.into().into()),
                 ..Default::default()
             }))),
             ..Default::default()
@@ -600,7 +720,7 @@ impl Filter {
         Filter {
             property: Some(property_name.as_ref().to_string()),
             condition: Some(Condition::Date(Box::new(DateFilter {
-                next_month: Some(std::collections::HashMap::new()),
+                relative: Some(RelativeDate::NextMonth),
                 ..Default::default()
             }))),
             ..Default::default()
@@ -615,7 +735,7 @@ impl Filter {
         Filter {
             property: Some(property_name.as_ref().to_string()),
             condition: Some(Condition::Date(Box::new(DateFilter {
-                next_week: Some(std::collections::HashMap::new()),
+                relative: Some(RelativeDate::NextWeek),
                 ..Default::default()
             }))),
             ..Default::default()
@@ -630,7 +750,7 @@ impl Filter {
         Filter {
             property: Some(property_name.as_ref().to_string()),
             condition: Some(Condition::Date(Box::new(DateFilter {
-                next_year: Some(std::collections::HashMap::new()),
+                relative: Some(RelativeDate::NextYear),
                 ..Default::default()
             }))),
             ..Default::default()
@@ -643,11 +763,12 @@ impl Filter {
     /// - `property_name`: Property Name (Column Name) in Notion Database
     /// - `date`: The value to compare the date property value against. (ISO 8601 date)
     ///   - e.g.) `"2021-05-10"`, `"2021-05-10T12:00:00"`, `"2021-10-15T12:00:00-07:00"`
-    pub fn date_on_or_after<S: AsRef<str>, T: AsRef<str>>(property_name: S, date: T) -> Self {
+    ///   - or a `chrono::NaiveDate` / `chrono::DateTime` (see [`FilterDate`])
+    pub fn date_on_or_after<S: AsRef<str>, T: Into<FilterDate>>(property_name: S, date: T) -> Self {
         Filter {
             property: Some(property_name.as_ref().to_string()),
             condition: Some(Condition::Date(Box::new(DateFilter {
-                on_or_after: Some(date.as_ref().to_string()),
+                on_or_after: Some(date.into().into()),
                 ..Default::default()
             }))),
             ..Default::default()
@@ -659,11 +780,15 @@ impl Filter {
     /// - `property_name`: Property Name (Column Name) in Notion Database
     /// - `date`: The value to compare the date property value against. (ISO 8601 date)
     ///   - e.g.) `"2021-05-10"`, `"2021-05-10T12:00:00"`, `"2021-10-15T12:00:00-07:00"`
-    pub fn date_on_or_before<S: AsRef<str>, T: AsRef<str>>(property_name: S, date: T) -> Self {
+    ///   - or a `chrono::NaiveDate` / `chrono::DateTime` (see [`FilterDate`])
+    pub fn date_on_or_before<S: AsRef<str>, T: Into<FilterDate>>(
+        property_name: S,
+        date: T,
+    ) -> Self {
         Filter {
             property: Some(property_name.as_ref().to_string()),
             condition: Some(Condition::Date(Box::new(DateFilter {
-                on_or_before: Some(date.as_ref().to_string()),
+                on_or_before: Some(date.into().into()),
                 ..Default::default()
             }))),
             ..Default::default()
@@ -678,7 +803,7 @@ impl Filter {
         Filter {
             property: Some(property_name.as_ref().to_string()),
             condition: Some(Condition::Date(Box::new(DateFilter {
-                past_month: Some(std::collections::HashMap::new()),
+                relative: Some(RelativeDate::PastMonth),
                 ..Default::default()
             }))),
             ..Default::default()
@@ -693,7 +818,7 @@ impl Filter {
         Filter {
             property: Some(property_name.as_ref().to_string()),
             condition: Some(Condition::Date(Box::new(DateFilter {
-                past_week: Some(std::collections::HashMap::new()),
+                relative: Some(RelativeDate::PastWeek),
                 ..Default::default()
             }))),
             ..Default::default()
@@ -708,7 +833,7 @@ impl Filter {
         Filter {
             property: Some(property_name.as_ref().to_string()),
             condition: Some(Condition::Date(Box::new(DateFilter {
-                past_year: Some(std::collections::HashMap::new()),
+                relative: Some(RelativeDate::PastYear),
                 ..Default::default()
             }))),
             ..Default::default()
@@ -723,7 +848,7 @@ impl Filter {
         Filter {
             property: Some(property_name.as_ref().to_string()),
             condition: Some(Condition::Date(Box::new(DateFilter {
-                this_week: Some(std::collections::HashMap::new()),
+                relative: Some(RelativeDate::ThisWeek),
                 ..Default::default()
             }))),
             ..Default::default()
@@ -1043,12 +1168,15 @@ impl Filter {
     }
 
     // Formula Date Filters
-    pub fn formula_date_after<S: AsRef<str>, T: AsRef<str>>(property_name: S, date: T) -> Self {
+    pub fn formula_date_after<S: AsRef<str>, T: Into<FilterDate>>(
+        property_name: S,
+        date: T,
+    ) -> Self {
         Filter {
             property: Some(property_name.as_ref().to_string()),
             condition: Some(Condition::Formula(Box::new(FormulaFilter {
                 date: Some(DateFilter {
-                    after: Some(date.as_ref().to_string()),
+                    after: Some(date.into().into()),
                     ..Default::default()
                 }),
                 ..Default::default()
@@ -1057,12 +1185,15 @@ impl Filter {
         }
     }
 
-    pub fn formula_date_before<S: AsRef<str>, T: AsRef<str>>(property_name: S, date: T) -> Self {
+    pub fn formula_date_before<S: AsRef<str>, T: Into<FilterDate>>(
+        property_name: S,
+        date: T,
+    ) -> Self {
         Filter {
             property: Some(property_name.as_ref().to_string()),
             condition: Some(Condition::Formula(Box::new(FormulaFilter {
                 date: Some(DateFilter {
-                    before: Some(date.as_ref().to_string()),
+                    before: Some(date.into().into()),
                     ..Default::default()
                 }),
                 ..Default::default()
@@ -1071,12 +1202,15 @@ impl Filter {
         }
     }
 
-    pub fn formula_date_equals<S: AsRef<str>, T: AsRef<str>>(property_name: S, date: T) -> Self {
+    pub fn formula_date_equals<S: AsRef<str>, T: Into<FilterDate>>(
+        property_name: S,
+        date: T,
+    ) -> Self {
         Filter {
             property: Some(property_name.as_ref().to_string()),
             condition: Some(Condition::Formula(Box::new(FormulaFilter {
                 date: Some(DateFilter {
-                    equals: Some(date.as_ref().to_string()),
+                    equals: Some(date.into().into()),
                     ..Default::default()
                 }),
                 ..Default::default()
@@ -1118,7 +1252,7 @@ impl Filter {
             property: Some(property_name.as_ref().to_string()),
             condition: Some(Condition::Formula(Box::new(FormulaFilter {
                 date: Some(DateFilter {
-                    next_month: Some(std::collections::HashMap::new()),
+                    relative: Some(RelativeDate::NextMonth),
                     ..Default::default()
                 }),
                 ..Default::default()
@@ -1132,7 +1266,7 @@ impl Filter {
             property: Some(property_name.as_ref().to_string()),
             condition: Some(Condition::Formula(Box::new(FormulaFilter {
                 date: Some(DateFilter {
-                    next_week: Some(std::collections::HashMap::new()),
+                    relative: Some(RelativeDate::NextWeek),
                     ..Default::default()
                 }),
                 ..Default::default()
@@ -1146,7 +1280,7 @@ impl Filter {
             property: Some(property_name.as_ref().to_string()),
             condition: Some(Condition::Formula(Box::new(FormulaFilter {
                 date: Some(DateFilter {
-                    next_year: Some(std::collections::HashMap::new()),
+                    relative: Some(RelativeDate::NextYear),
                     ..Default::default()
                 }),
                 ..Default::default()
@@ -1155,7 +1289,7 @@ impl Filter {
         }
     }
 
-    pub fn formula_date_on_or_after<S: AsRef<str>, T: AsRef<str>>(
+    pub fn formula_date_on_or_after<S: AsRef<str>, T: Into<FilterDate>>(
         property_name: S,
         date: T,
     ) -> Self {
@@ -1163,7 +1297,7 @@ impl Filter {
             property: Some(property_name.as_ref().to_string()),
             condition: Some(Condition::Formula(Box::new(FormulaFilter {
                 date: Some(DateFilter {
-                    on_or_after: Some(date.as_ref().to_string()),
+                    on_or_after: Some(date.into().into()),
                     ..Default::default()
                 }),
                 ..Default::default()
@@ -1172,7 +1306,7 @@ impl Filter {
         }
     }
 
-    pub fn formula_date_on_or_before<S: AsRef<str>, T: AsRef<str>>(
+    pub fn formula_date_on_or_before<S: AsRef<str>, T: Into<FilterDate>>(
         property_name: S,
         date: T,
     ) -> Self {
@@ -1180,7 +1314,7 @@ impl Filter {
             property: Some(property_name.as_ref().to_string()),
             condition: Some(Condition::Formula(Box::new(FormulaFilter {
                 date: Some(DateFilter {
-                    on_or_before: Some(date.as_ref().to_string()),
+                    on_or_before: Some(date.into().into()),
                     ..Default::default()
                 }),
                 ..Default::default()
@@ -1194,7 +1328,7 @@ impl Filter {
             property: Some(property_name.as_ref().to_string()),
             condition: Some(Condition::Formula(Box::new(FormulaFilter {
                 date: Some(DateFilter {
-                    past_month: Some(std::collections::HashMap::new()),
+                    relative: Some(RelativeDate::PastMonth),
                     ..Default::default()
                 }),
                 ..Default::default()
@@ -1208,7 +1342,7 @@ impl Filter {
             property: Some(property_name.as_ref().to_string()),
             condition: Some(Condition::Formula(Box::new(FormulaFilter {
                 date: Some(DateFilter {
-                    past_week: Some(std::collections::HashMap::new()),
+                    relative: Some(RelativeDate::PastWeek),
                     ..Default::default()
                 }),
                 ..Default::default()
@@ -1222,7 +1356,7 @@ impl Filter {
             property: Some(property_name.as_ref().to_string()),
             condition: Some(Condition::Formula(Box::new(FormulaFilter {
                 date: Some(DateFilter {
-                    past_year: Some(std::collections::HashMap::new()),
+                    relative: Some(RelativeDate::PastYear),
                     ..Default::default()
                 }),
                 ..Default::default()
@@ -1236,7 +1370,7 @@ impl Filter {
             property: Some(property_name.as_ref().to_string()),
             condition: Some(Condition::Formula(Box::new(FormulaFilter {
                 date: Some(DateFilter {
-                    this_week: Some(std::collections::HashMap::new()),
+                    relative: Some(RelativeDate::ThisWeek),
                     ..Default::default()
                 }),
                 ..Default::default()
@@ -2128,10 +2262,11 @@ impl Filter {
     /// - `property_name`: Property Name (Column Name) in Notion Database
     /// - `timestamp`: ISO 8601 timestamp
     ///   - e.g.) `"2021-05-10"`, `"2021-05-10T12:00:00"`, `"2021-10-15T12:00:00-07:00"`
-    pub fn timestamp_after<T: AsRef<str>>(timestamp: T) -> Self {
+    ///   - or a `chrono::NaiveDate` / `chrono::DateTime` (see [`FilterDate`])
+    pub fn timestamp_after<T: Into<FilterDate>>(timestamp: T) -> Self {
         Filter {
             condition: Some(Condition::Timestamp(Box::new(TimestampFilter {
-                after: Some(timestamp.as_ref().to_string()),
+                after: Some(timestamp.into().into()),
                 ..Default::default()
             }))),
             timestamp: Some("created_time".to_string()),
@@ -2144,10 +2279,11 @@ impl Filter {
     /// - `property_name`: Property Name (Column Name) in Notion Database
     /// - `timestamp`: The value to compare the timestamp property value against. (ISO 8601 timestamp)
     ///   - e.g.) `"2021-05-10"`, `"2021-05-10T12:00:00"`, `"2021-10-15T12:00:00-07:00"`
-    pub fn timestamp_before<T: AsRef<str>>(timestamp: T) -> Self {
+    ///   - or a `chrono::NaiveDate` / `chrono::DateTime` (see [`FilterDate`])
+    pub fn timestamp_before<T: Into<FilterDate>>(timestamp: T) -> Self {
         Filter {
             condition: Some(Condition::Timestamp(Box::new(TimestampFilter {
-                before: Some(timestamp.as_ref().to_string()),
+                before: Some(timestamp.into().into()),
                 ..Default::default()
             }))),
             timestamp: Some("created_time".to_string()),
@@ -2160,10 +2296,11 @@ impl Filter {
     /// - `property_name`: Property Name (Column Name) in Notion Database
     /// - `timestamp`: The value to compare the timestamp property value against. (ISO 8601 timestamp)
     ///   - e.g.) `"2021-05-10"`, `"2021-05-10T12:00:00"`, `"2021-10-15T12:00:00-07:00"`
-    pub fn timestamp_equals<T: AsRef<str>>(timestamp: T) -> Self {
+    ///   - or a `chrono::NaiveDate` / `chrono::DateTime` (see [`FilterDate`])
+    pub fn timestamp_equals<T: Into<FilterDate>>(timestamp: T) -> Self {
         Filter {
             condition: Some(Condition::Timestamp(Box::new(TimestampFilter {
-                equals: Some(timestamp.as_ref().to_string()),
+                equals: Some(timestamp.into().into()),
                 ..Default::default()
             }))),
             timestamp: Some("created_time".to_string()),
@@ -2206,7 +2343,7 @@ impl Filter {
     pub fn timestamp_next_month() -> Self {
         Filter {
             condition: Some(Condition::Timestamp(Box::new(TimestampFilter {
-                next_month: Some(std::collections::HashMap::new()),
+                relative: Some(RelativeDate::NextMonth),
                 ..Default::default()
             }))),
             timestamp: Some("created_time".to_string()),
@@ -2221,7 +2358,7 @@ impl Filter {
     pub fn timestamp_next_week() -> Self {
         Filter {
             condition: Some(Condition::Timestamp(Box::new(TimestampFilter {
-                next_week: Some(std::collections::HashMap::new()),
+                relative: Some(RelativeDate::NextWeek),
                 ..Default::default()
             }))),
             timestamp: Some("created_time".to_string()),
@@ -2236,7 +2373,7 @@ impl Filter {
     pub fn timestamp_next_year() -> Self {
         Filter {
             condition: Some(Condition::Timestamp(Box::new(TimestampFilter {
-                next_year: Some(std::collections::HashMap::new()),
+                relative: Some(RelativeDate::NextYear),
                 ..Default::default()
             }))),
             timestamp: Some("created_time".to_string()),
@@ -2250,10 +2387,11 @@ impl Filter {
     /// - `property_name`: Property Name (Column Name) in Notion Database
     /// - `timestamp`: The value to compare the timestamp property value against. (ISO 8601 timestamp)
     ///   - e.g.) `"2021-05-10"`, `"2021-05-10T12:00:00"`, `"2021-10-15T12:00:00-07:00"`
-    pub fn timestamp_on_or_after<T: AsRef<str>>(timestamp: T) -> Self {
+    ///   - or a `chrono::NaiveDate` / `chrono::DateTime` (see [`FilterDate`])
+    pub fn timestamp_on_or_after<T: Into<FilterDate>>(timestamp: T) -> Self {
         Filter {
             condition: Some(Condition::Timestamp(Box::new(TimestampFilter {
-                on_or_after: Some(timestamp.as_ref().to_string()),
+                on_or_after: Some(timestamp.into().into()),
                 ..Default::default()
             }))),
             timestamp: Some("created_time".to_string()),
@@ -2266,10 +2404,11 @@ impl Filter {
     /// - `property_name`: Property Name (Column Name) in Notion Database
     /// - `timestamp`: The value to compare the timestamp property value against. (ISO 8601 timestamp)
     ///   - e.g.) `"2021-05-10"`, `"2021-05-10T12:00:00"`, `"2021-10-15T12:00:00-07:00"`
-    pub fn timestamp_on_or_before<T: AsRef<str>>(timestamp: T) -> Self {
+    ///   - or a `chrono::NaiveDate` / `chrono::DateTime` (see [`FilterDate`])
+    pub fn timestamp_on_or_before<T: Into<FilterDate>>(timestamp: T) -> Self {
         Filter {
             condition: Some(Condition::Timestamp(Box::new(TimestampFilter {
-                on_or_before: Some(timestamp.as_ref().to_string()),
+                on_or_before: Some(timestamp.into().into()),
                 ..Default::default()
             }))),
             timestamp: Some("created_time".to_string()),
@@ -2284,7 +2423,7 @@ impl Filter {
     pub fn timestamp_past_month() -> Self {
         Filter {
             condition: Some(Condition::Timestamp(Box::new(TimestampFilter {
-                past_month: Some(std::collections::HashMap::new()),
+                relative: Some(RelativeDate::PastMonth),
                 ..Default::default()
             }))),
             timestamp: Some("created_time".to_string()),
@@ -2299,7 +2438,7 @@ impl Filter {
     pub fn timestamp_past_week() -> Self {
         Filter {
             condition: Some(Condition::Timestamp(Box::new(TimestampFilter {
-                past_week: Some(std::collections::HashMap::new()),
+                relative: Some(RelativeDate::PastWeek),
                 ..Default::default()
             }))),
             timestamp: Some("created_time".to_string()),
@@ -2314,7 +2453,7 @@ impl Filter {
     pub fn timestamp_past_year() -> Self {
         Filter {
             condition: Some(Condition::Timestamp(Box::new(TimestampFilter {
-                past_year: Some(std::collections::HashMap::new()),
+                relative: Some(RelativeDate::PastYear),
                 ..Default::default()
             }))),
             timestamp: Some("created_time".to_string()),
@@ -2329,7 +2468,7 @@ impl Filter {
     pub fn timestamp_this_week() -> Self {
         Filter {
             condition: Some(Condition::Timestamp(Box::new(TimestampFilter {
-                this_week: Some(std::collections::HashMap::new()),
+                relative: Some(RelativeDate::ThisWeek),
                 ..Default::default()
             }))),
             timestamp: Some("created_time".to_string()),
@@ -2451,3 +2590,67 @@ impl Filter {
         }
     }
 }
+
+// # --------------------------------------------------------------------------------
+//
+// unit test
+//
+// # --------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod unit_tests {
+    use super::*;
+
+    use chrono::{FixedOffset, NaiveDate, TimeZone, Utc};
+
+    #[test]
+    fn serialize_chrono_dates() {
+        let date = NaiveDate::from_ymd_opt(2021, 5, 10).unwrap();
+
+        let date_time = FixedOffset::west_opt(7 * 3600)
+            .unwrap()
+            .with_ymd_and_hms(2021, 10, 15, 12, 0, 0)
+            .unwrap();
+
+        assert_eq!(FilterDate::from(date).as_str(), "2021-05-10");
+        assert_eq!(
+            FilterDate::from(date_time).as_str(),
+            "2021-10-15T12:00:00-07:00"
+        );
+        assert_eq!(
+            FilterDate::from(Utc.with_ymd_and_hms(2021, 10, 15, 19, 0, 0).unwrap()).as_str(),
+            "2021-10-15T19:00:00+00:00"
+        );
+
+        let filter = Filter::date_on_or_after("Due", date);
+
+        assert_eq!(
+            serde_json::to_value(&filter).unwrap(),
+            serde_json::json!({ "property": "Due", "date": { "on_or_after": "2021-05-10" } })
+        );
+    }
+
+    #[test]
+    fn relative_dates() {
+        let filter = Filter::date_past_week("Due");
+
+        let json = serde_json::json!({ "property": "Due", "date": { "past_week": {} } });
+
+        assert_eq!(serde_json::to_value(&filter).unwrap(), json);
+        assert_eq!(serde_json::from_value::<Filter>(json).unwrap(), filter);
+
+        let filter = serde_json::from_str::<Filter>(
+            r#"{ "timestamp": "created_time", "created_time": { "this_week": {} } }"#,
+        )
+        .unwrap();
+
+        assert_eq!(filter, Filter::timestamp_this_week());
+
+        let filter = serde_json::from_str::<Filter>(
+            r#"{ "property": "Due", "formula": { "date": { "equals": "2021-05-10" } } }"#,
+        )
+        .unwrap();
+
+        assert_eq!(filter, Filter::formula_date_equals("Due", "2021-05-10"));
+    }
+}
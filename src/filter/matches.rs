@@ -0,0 +1,940 @@
+//! Evaluation of filters against pages, without sending a request.
+
+use std::cmp::Ordering;
+
+use chrono::{DateTime, Datelike, Days, FixedOffset, Months, NaiveDate, NaiveDateTime, Utc};
+
+use super::{
+    CheckboxFilter, Condition, DateFilter, FilesFilter, Filter, FormulaFilter, MultiSelectFilter,
+    NumberFilter, PeopleFilter, RelationFilter, RelativeDate, RichTextFilter, RollupFilter,
+    SelectFilter, StatusFilter, TimestampFilter, UniqueIdFilter,
+};
+use crate::{
+    error::{Error, PropertyError},
+    page::{
+        date::PageDatePropertyParameter, formula::Formula, rollup::RollupValue, PageDateProperty,
+        PageNumberProperty, PageProperty, PageResponse,
+    },
+    user::User,
+};
+
+impl Filter {
+    /// Evaluates the filter against a page locally, with the semantics of the Notion API.
+    ///
+    /// Relative dates (e.g. `past_week`) are relative to the current time in UTC.
+    /// Use [`Filter::matches_at`] to supply another time.
+    ///
+    /// Returns an error if a property of the filter is missing in the page, has a type that
+    /// the condition cannot be applied to, or if a date in the filter is not valid.
+    pub fn matches(&self, page: &PageResponse) -> Result<bool, Error> {
+        self.matches_at(page, Utc::now().fixed_offset())
+    }
+
+    /// Same as [`Filter::matches`], with relative dates evaluated relative to `now`.
+    ///
+    /// - Text conditions are case-insensitive.
+    /// - A date-only value in the filter (e.g. `"2021-05-10"`) is compared with the date of the
+    ///   property value, and a date-time with the exact time.
+    /// - `past_*` and `next_*` include today. Weeks start on Sunday.
+    pub fn matches_at(
+        &self,
+        page: &PageResponse,
+        now: DateTime<FixedOffset>,
+    ) -> Result<bool, Error> {
+        Evaluator { page, now }.filter(self, None)
+    }
+}
+
+struct Evaluator<'a> {
+    page: &'a PageResponse,
+    now: DateTime<FixedOffset>,
+}
+
+/// A property being filtered and its name.
+type Target<'a> = (&'a str, &'a PageProperty);
+
+impl Evaluator<'_> {
+    /// `item` is set when evaluating the filter of a rollup against one of its values.
+    fn filter(&self, filter: &Filter, item: Option<Target>) -> Result<bool, Error> {
+        if let Some(filters) = &filter.and {
+            for filter in filters {
+                if !self.filter(filter, item)? {
+                    return Ok(false);
+                }
+            }
+        }
+
+        if let Some(filters) = &filter.or {
+            let mut matched = false;
+            for filter in filters {
+                if self.filter(filter, item)? {
+                    matched = true;
+                    break;
+                }
+            }
+            if !matched {
+                return Ok(false);
+            }
+        }
+
+        let Some(condition) = &filter.condition else {
+            return Ok(true);
+        };
+
+        if let (Condition::Timestamp(timestamp_filter), Some(timestamp)) =
+            (condition, filter.timestamp.as_deref())
+        {
+            let timestamp = match timestamp {
+                "last_edited_time" => self.page.last_edited_time,
+                _ => self.page.created_time,
+            };
+            return self.timestamp(timestamp_filter, timestamp);
+        }
+
+        let target = match (&filter.property, item) {
+            (Some(name), _) => {
+                let property =
+                    self.page
+                        .properties
+                        .get(name)
+                        .ok_or_else(|| PropertyError::Missing {
+                            property: name.to_string(),
+                        })?;
+                (name.as_str(), property)
+            }
+            (None, Some(item)) => item,
+            (None, None) => {
+                return Err(Error::RequestParameter(
+                    "`property` of the filter has not been set.".to_string(),
+                ))
+            }
+        };
+
+        self.condition(condition, target)
+    }
+
+    fn condition(&self, condition: &Condition, target: Target) -> Result<bool, Error> {
+        match condition {
+            Condition::Checkbox(filter) => checkbox(filter, target),
+            Condition::Date(filter) => self.date_property(filter, target),
+            Condition::Files(filter) => files(filter, target),
+            Condition::Formula(filter) => self.formula(filter, target),
+            Condition::MultiSelect(filter) => multi_select(filter, target),
+            Condition::Number(filter) => number_property(filter, target),
+            Condition::People(filter) => people(filter, target),
+            Condition::PhoneNumber(filter) => match target.1 {
+                PageProperty::PhoneNumber(phone_number) => {
+                    let rich_text_filter = RichTextFilter {
+                        contains: filter.contains.clone(),
+                        does_not_contain: filter.does_not_contain.clone(),
+                        does_not_equal: filter.does_not_equal.clone(),
+                        ends_with: filter.ends_with.clone(),
+                        equals: filter.equals.clone(),
+                        is_empty: filter.is_empty,
+                        is_not_empty: filter.is_not_empty,
+                        starts_with: filter.starts_with.clone(),
+                    };
+                    Ok(text(
+                        &rich_text_filter,
+                        phone_number.phone_number.as_deref().unwrap_or_default(),
+                    ))
+                }
+                _ => Err(mismatch(target, "phone_number")),
+            },
+            Condition::Rollup(filter) => self.rollup(filter, target),
+            Condition::Relation(filter) => relation(filter, target),
+            Condition::RichText(filter) => rich_text(filter, target),
+            Condition::Select(filter) => select(filter, target),
+            Condition::Status(filter) => status(filter, target),
+            Condition::Timestamp(filter) => match target.1 {
+                PageProperty::CreatedTime(created_time) => {
+                    self.timestamp(filter, created_time.created_time)
+                }
+                PageProperty::LastEditedTime(last_edited_time) => {
+                    self.timestamp(filter, last_edited_time.last_edited_time)
+                }
+                _ => Err(mismatch(target, "created_time")),
+            },
+            Condition::UniqueId(filter) => unique_id(filter, target),
+        }
+    }
+
+    // # --------------------------------------------------------------------------------
+    //
+    // date
+    //
+    // # --------------------------------------------------------------------------------
+
+    fn date_property(&self, filter: &DateFilter, target: Target) -> Result<bool, Error> {
+        let parameter = |date: &Option<PageDatePropertyParameter>| match date {
+            Some(date) => (date.start, date.date_only),
+            None => (None, false),
+        };
+
+        let (value, date_only) = match target.1 {
+            PageProperty::Date(date) => parameter(&date.date),
+            PageProperty::CreatedTime(created_time) => (Some(created_time.created_time), false),
+            PageProperty::LastEditedTime(last_edited_time) => {
+                (Some(last_edited_time.last_edited_time), false)
+            }
+            PageProperty::Formula(formula) => match &formula.formula {
+                Formula::Date(date) => (date.date, false),
+                _ => return Err(mismatch(target, "date")),
+            },
+            PageProperty::Rollup(rollup) => match &rollup.rollup.value {
+                RollupValue::Date(date) => parameter(&date.date),
+                _ => return Err(mismatch(target, "date")),
+            },
+            _ => return Err(mismatch(target, "date")),
+        };
+
+        self.date(filter, value, date_only)
+    }
+
+    fn timestamp(
+        &self,
+        filter: &TimestampFilter,
+        value: DateTime<FixedOffset>,
+    ) -> Result<bool, Error> {
+        let filter = DateFilter {
+            after: filter.after.clone(),
+            before: filter.before.clone(),
+            equals: filter.equals.clone(),
+            is_empty: filter.is_empty,
+            is_not_empty: filter.is_not_empty,
+            on_or_after: filter.on_or_after.clone(),
+            on_or_before: filter.on_or_before.clone(),
+            relative: filter.relative,
+        };
+
+        self.date(&filter, Some(value), false)
+    }
+
+    /// `date_only` values have no time, and are compared by their date as is.
+    fn date(
+        &self,
+        filter: &DateFilter,
+        value: Option<DateTime<FixedOffset>>,
+        date_only: bool,
+    ) -> Result<bool, Error> {
+        if let Some(is_empty) = filter.is_empty {
+            return Ok(value.is_none() == is_empty);
+        }
+
+        if let Some(is_not_empty) = filter.is_not_empty {
+            return Ok(value.is_some() == is_not_empty);
+        }
+
+        let Some(value) = value else {
+            return Ok(false);
+        };
+
+        let comparisons: [DateComparison; 5] = [
+            (&filter.after, Ordering::is_gt),
+            (&filter.before, Ordering::is_lt),
+            (&filter.equals, Ordering::is_eq),
+            (&filter.on_or_after, Ordering::is_ge),
+            (&filter.on_or_before, Ordering::is_le),
+        ];
+
+        for (date, predicate) in comparisons {
+            if let Some(date) = date {
+                return Ok(predicate(compare_date(value, &parse_date(date)?)));
+            }
+        }
+
+        // Relative ranges are compared by date in the time zone of `now`.
+        let today = self.now.date_naive();
+        let date = if date_only {
+            value.date_naive()
+        } else {
+            value.with_timezone(&self.now.timezone()).date_naive()
+        };
+
+        let range = match filter.relative {
+            Some(RelativeDate::PastWeek) => today
+                .checked_sub_days(Days::new(7))
+                .map(|start| (start, today)),
+            Some(RelativeDate::PastMonth) => today
+                .checked_sub_months(Months::new(1))
+                .map(|start| (start, today)),
+            Some(RelativeDate::PastYear) => today
+                .checked_sub_months(Months::new(12))
+                .map(|start| (start, today)),
+            Some(RelativeDate::NextWeek) => {
+                today.checked_add_days(Days::new(7)).map(|end| (today, end))
+            }
+            Some(RelativeDate::NextMonth) => today
+                .checked_add_months(Months::new(1))
+                .map(|end| (today, end)),
+            Some(RelativeDate::NextYear) => today
+                .checked_add_months(Months::new(12))
+                .map(|end| (today, end)),
+            Some(RelativeDate::ThisWeek) => {
+                let days_from_sunday = today.weekday().num_days_from_sunday() as u64;
+                today
+                    .checked_sub_days(Days::new(days_from_sunday))
+                    .and_then(|start| Some((start, start.checked_add_days(Days::new(6))?)))
+            }
+            None => return Ok(true),
+        };
+
+        Ok(range.is_some_and(|(start, end)| start <= date && date <= end))
+    }
+
+    // # --------------------------------------------------------------------------------
+    //
+    // formula
+    //
+    // # --------------------------------------------------------------------------------
+
+    fn formula(&self, filter: &FormulaFilter, target: Target) -> Result<bool, Error> {
+        let PageProperty::Formula(formula) = target.1 else {
+            return Err(mismatch(target, "formula"));
+        };
+
+        // A condition for another type of result doesn't match.
+        let matched = match &formula.formula {
+            Formula::Boolean(boolean) => match &filter.checkbox {
+                Some(filter) => filter
+                    .equals
+                    .is_none_or(|equals| equals == boolean.boolean.unwrap_or(false)),
+                None => false,
+            },
+            Formula::Date(date) => match &filter.date {
+                Some(filter) => self.date(filter, date.date, false)?,
+                None => false,
+            },
+            Formula::Number(value) => match &filter.number {
+                Some(filter) => number(filter, value.number),
+                None => false,
+            },
+            Formula::String(string) => match &filter.string {
+                Some(filter) => text(filter, string.string.as_deref().unwrap_or_default()),
+                None => false,
+            },
+        };
+
+        Ok(matched)
+    }
+
+    // # --------------------------------------------------------------------------------
+    //
+    // rollup
+    //
+    // # --------------------------------------------------------------------------------
+
+    fn rollup(&self, filter: &RollupFilter, target: Target) -> Result<bool, Error> {
+        let PageProperty::Rollup(rollup) = target.1 else {
+            return Err(mismatch(target, "rollup"));
+        };
+
+        // `number` and `date` rollups are evaluated as an array with a single value.
+        let single;
+        let items: &[PageProperty] = match &rollup.rollup.value {
+            RollupValue::Array(array) => &array.array,
+            RollupValue::Number(number) => {
+                single = [PageProperty::Number(PageNumberProperty {
+                    id: None,
+                    number: number.number,
+                })];
+                &single
+            }
+            RollupValue::Date(date) => {
+                single = [PageProperty::Date(PageDateProperty {
+                    id: None,
+                    date: date.date.clone(),
+                })];
+                &single
+            }
+            RollupValue::Incomplete | RollupValue::Unsupported => &[],
+        };
+
+        let name = target.0;
+
+        if let Some(filter) = &filter.any {
+            for item in items {
+                if self.filter(filter, Some((name, item)))? {
+                    return Ok(true);
+                }
+            }
+            return Ok(false);
+        }
+
+        if let Some(filter) = &filter.every {
+            for item in items {
+                if !self.filter(filter, Some((name, item)))? {
+                    return Ok(false);
+                }
+            }
+            return Ok(true);
+        }
+
+        if let Some(filter) = &filter.none {
+            for item in items {
+                if self.filter(filter, Some((name, item)))? {
+                    return Ok(false);
+                }
+            }
+            return Ok(true);
+        }
+
+        Ok(true)
+    }
+}
+
+// # --------------------------------------------------------------------------------
+//
+// helpers
+//
+// # --------------------------------------------------------------------------------
+
+/// An operand of a condition and how the value is compared with it.
+type Comparison<T> = (Option<T>, fn(T, T) -> bool);
+
+/// A date of a condition and the orderings of the value that match.
+type DateComparison<'a> = (&'a Option<String>, fn(Ordering) -> bool);
+
+/// Evaluates the first comparison whose operand is specified.
+fn compare<T, const N: usize>(value: T, comparisons: [Comparison<T>; N]) -> bool
+where
+    T: Copy,
+{
+    comparisons
+        .into_iter()
+        .find_map(|(operand, predicate)| operand.map(|operand| predicate(value, operand)))
+        .unwrap_or(true)
+}
+
+fn mismatch(target: Target, expected: &'static str) -> Error {
+    PropertyError::TypeMismatch {
+        property: target.0.to_string(),
+        expected,
+        found: target.1.type_name(),
+    }
+    .into()
+}
+
+/// A date in a filter, either a date or a date-time.
+enum ParsedDate {
+    Date(NaiveDate),
+    DateTime(DateTime<FixedOffset>),
+}
+
+/// Parses an ISO 8601 date of a filter, e.g. `"2021-05-10"`, `"2021-05-10T12:00:00"`
+/// (UTC) or `"2021-10-15T12:00:00-07:00"`.
+fn parse_date(date: &str) -> Result<ParsedDate, Error> {
+    if let Ok(date) = NaiveDate::parse_from_str(date, "%Y-%m-%d") {
+        return Ok(ParsedDate::Date(date));
+    }
+
+    if let Ok(date_time) = DateTime::parse_from_rfc3339(date) {
+        return Ok(ParsedDate::DateTime(date_time));
+    }
+
+    if let Ok(date_time) = NaiveDateTime::parse_from_str(date, "%Y-%m-%dT%H:%M:%S%.f") {
+        return Ok(ParsedDate::DateTime(date_time.and_utc().fixed_offset()));
+    }
+
+    Err(Error::RequestParameter(format!(
+        "`{}` in the filter is not an ISO 8601 date.",
+        date
+    )))
+}
+
+fn compare_date(value: DateTime<FixedOffset>, date: &ParsedDate) -> Ordering {
+    match date {
+        ParsedDate::Date(date) => value.date_naive().cmp(date),
+        ParsedDate::DateTime(date_time) => value.cmp(date_time),
+    }
+}
+
+/// IDs are compared without dashes, as the API accepts both forms.
+fn same_id(a: &str, b: &str) -> bool {
+    a.replace('-', "").eq_ignore_ascii_case(&b.replace('-', ""))
+}
+
+fn user_id(user: &User) -> &str {
+    match user {
+        User::Bot(user) => &user.id,
+        User::Person(user) => &user.id,
+    }
+}
+
+// # --------------------------------------------------------------------------------
+//
+// conditions
+//
+// # --------------------------------------------------------------------------------
+
+fn checkbox(filter: &CheckboxFilter, target: Target) -> Result<bool, Error> {
+    let PageProperty::Checkbox(checkbox) = target.1 else {
+        return Err(mismatch(target, "checkbox"));
+    };
+
+    Ok(filter
+        .equals
+        .is_none_or(|equals| equals == checkbox.checkbox))
+}
+
+fn files(filter: &FilesFilter, target: Target) -> Result<bool, Error> {
+    let PageProperty::Files(files) = target.1 else {
+        return Err(mismatch(target, "files"));
+    };
+
+    Ok(is_empty(filter.is_empty, filter.is_not_empty, files.files.is_empty()).unwrap_or(true))
+}
+
+/// Evaluates `is_empty` and `is_not_empty`, if specified.
+fn is_empty(is_empty: Option<bool>, is_not_empty: Option<bool>, empty: bool) -> Option<bool> {
+    is_empty
+        .map(|is_empty| empty == is_empty)
+        .or(is_not_empty.map(|is_not_empty| empty != is_not_empty))
+}
+
+fn multi_select(filter: &MultiSelectFilter, target: Target) -> Result<bool, Error> {
+    let PageProperty::MultiSelect(multi_select) = target.1 else {
+        return Err(mismatch(target, "multi_select"));
+    };
+
+    let options = &multi_select.multi_select;
+
+    if let Some(matched) = is_empty(filter.is_empty, filter.is_not_empty, options.is_empty()) {
+        return Ok(matched);
+    }
+
+    let contains = |name: &str| options.iter().any(|option| option.name == name);
+
+    if let Some(name) = &filter.contains {
+        return Ok(contains(name));
+    }
+
+    if let Some(name) = &filter.does_not_contain {
+        return Ok(!contains(name));
+    }
+
+    Ok(true)
+}
+
+fn number_property(filter: &NumberFilter, target: Target) -> Result<bool, Error> {
+    let value = match target.1 {
+        PageProperty::Number(number) => number.number,
+        PageProperty::Formula(formula) => match &formula.formula {
+            Formula::Number(number) => number.number,
+            _ => return Err(mismatch(target, "number")),
+        },
+        PageProperty::Rollup(rollup) => match &rollup.rollup.value {
+            RollupValue::Number(number) => number.number,
+            _ => return Err(mismatch(target, "number")),
+        },
+        _ => return Err(mismatch(target, "number")),
+    };
+
+    Ok(number(filter, value))
+}
+
+fn number(filter: &NumberFilter, value: Option<f64>) -> bool {
+    if let Some(matched) = is_empty(filter.is_empty, filter.is_not_empty, value.is_none()) {
+        return matched;
+    }
+
+    // Only `does_not_equal` matches an empty value.
+    let Some(value) = value else {
+        return filter.does_not_equal.is_some();
+    };
+
+    let comparisons: [Comparison<f64>; 6] = [
+        (filter.equals, |v, n| v == n),
+        (filter.does_not_equal, |v, n| v != n),
+        (filter.greater_than, |v, n| v > n),
+        (filter.greater_than_or_equal_to, |v, n| v >= n),
+        (filter.less_than, |v, n| v < n),
+        (filter.less_than_or_equal_to, |v, n| v <= n),
+    ];
+
+    compare(value, comparisons)
+}
+
+fn people(filter: &PeopleFilter, target: Target) -> Result<bool, Error> {
+    let users: Vec<&User> = match target.1 {
+        PageProperty::People(people) => people.people.iter().collect(),
+        PageProperty::CreatedBy(created_by) => vec![&created_by.created_by],
+        PageProperty::LastEditedBy(last_edited_by) => vec![&last_edited_by.last_edited_by],
+        _ => return Err(mismatch(target, "people")),
+    };
+
+    if let Some(matched) = is_empty(filter.is_empty, filter.is_not_empty, users.is_empty()) {
+        return Ok(matched);
+    }
+
+    let contains = |id: &str| users.iter().any(|user| same_id(user_id(user), id));
+
+    if let Some(id) = &filter.contains {
+        return Ok(contains(id));
+    }
+
+    if let Some(id) = &filter.does_not_contain {
+        return Ok(!contains(id));
+    }
+
+    Ok(true)
+}
+
+fn relation(filter: &RelationFilter, target: Target) -> Result<bool, Error> {
+    let PageProperty::Relation(relation) = target.1 else {
+        return Err(mismatch(target, "relation"));
+    };
+
+    let pages = &relation.relation;
+
+    if let Some(matched) = is_empty(filter.is_empty, filter.is_not_empty, pages.is_empty()) {
+        return Ok(matched);
+    }
+
+    let contains = |id: &str| pages.iter().any(|page| same_id(&page.id, id));
+
+    if let Some(id) = &filter.contains {
+        return Ok(contains(id));
+    }
+
+    if let Some(id) = &filter.does_not_contain {
+        return Ok(!contains(id));
+    }
+
+    Ok(true)
+}
+
+/// The `rich_text` condition also applies to `title`, `url`, `email` and `phone_number`.
+fn rich_text(filter: &RichTextFilter, target: Target) -> Result<bool, Error> {
+    let value = match target.1 {
+        PageProperty::Title(_) | PageProperty::RichText(_) => {
+            target.1.as_plain_text().unwrap_or_default()
+        }
+        PageProperty::Url(url) => url.url.clone().unwrap_or_default(),
+        PageProperty::Email(email) => email.email.clone().unwrap_or_default(),
+        PageProperty::PhoneNumber(phone_number) => {
+            phone_number.phone_number.clone().unwrap_or_default()
+        }
+        _ => return Err(mismatch(target, "rich_text")),
+    };
+
+    Ok(text(filter, &value))
+}
+
+fn text(filter: &RichTextFilter, value: &str) -> bool {
+    if let Some(matched) = is_empty(filter.is_empty, filter.is_not_empty, value.is_empty()) {
+        return matched;
+    }
+
+    let value = value.to_lowercase();
+    let lower = |text: &String| text.to_lowercase();
+
+    if let Some(text) = filter.equals.as_ref().map(lower) {
+        return value == text;
+    }
+
+    if let Some(text) = filter.does_not_equal.as_ref().map(lower) {
+        return value != text;
+    }
+
+    if let Some(text) = filter.contains.as_ref().map(lower) {
+        return value.contains(&text);
+    }
+
+    if let Some(text) = filter.does_not_contain.as_ref().map(lower) {
+        return !value.contains(&text);
+    }
+
+    if let Some(text) = filter.starts_with.as_ref().map(lower) {
+        return value.starts_with(&text);
+    }
+
+    if let Some(text) = filter.ends_with.as_ref().map(lower) {
+        return value.ends_with(&text);
+    }
+
+    true
+}
+
+fn select(filter: &SelectFilter, target: Target) -> Result<bool, Error> {
+    let PageProperty::Select(_) = target.1 else {
+        return Err(mismatch(target, "select"));
+    };
+
+    Ok(option(
+        &filter.equals,
+        &filter.does_not_equal,
+        filter.is_empty,
+        filter.is_not_empty,
+        target.1.as_select_name(),
+    ))
+}
+
+fn status(filter: &StatusFilter, target: Target) -> Result<bool, Error> {
+    let PageProperty::Status(_) = target.1 else {
+        return Err(mismatch(target, "status"));
+    };
+
+    Ok(option(
+        &filter.equals,
+        &filter.does_not_equal,
+        filter.is_empty,
+        filter.is_not_empty,
+        target.1.as_status_name(),
+    ))
+}
+
+fn option(
+    equals: &Option<String>,
+    does_not_equal: &Option<String>,
+    is_empty_filter: Option<bool>,
+    is_not_empty_filter: Option<bool>,
+    value: Option<&str>,
+) -> bool {
+    if let Some(matched) = is_empty(is_empty_filter, is_not_empty_filter, value.is_none()) {
+        return matched;
+    }
+
+    if let Some(name) = equals {
+        return value == Some(name.as_str());
+    }
+
+    if let Some(name) = does_not_equal {
+        return value != Some(name.as_str());
+    }
+
+    true
+}
+
+fn unique_id(filter: &UniqueIdFilter, target: Target) -> Result<bool, Error> {
+    let PageProperty::UniqueId(unique_id) = target.1 else {
+        return Err(mismatch(target, "unique_id"));
+    };
+
+    let value = unique_id.unique_id.number;
+
+    let comparisons: [Comparison<u64>; 6] = [
+        (filter.equals, |v, n| v == n),
+        (filter.does_not_equal, |v, n| v != n),
+        (filter.greater_than, |v, n| v > n),
+        (filter.greater_than_or_equal_to, |v, n| v >= n),
+        (filter.less_than, |v, n| v < n),
+        (filter.less_than_or_equal_to, |v, n| v <= n),
+    ];
+
+    Ok(compare(value, comparisons))
+}
+
+// # --------------------------------------------------------------------------------
+//
+// unit test
+//
+// # --------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod unit_tests {
+    use super::*;
+
+    fn page() -> PageResponse {
+        let json_data = r#"
+        {
+            "object": "page",
+            "id": "59833787-2cf9-4fdf-8782-e53db20768a5",
+            "created_time": "2024-10-01T10:00:00.000Z",
+            "last_edited_time": "2024-10-11T10:00:00.000Z",
+            "created_by": { "object": "user", "id": "ee5f0f84-409a-440f-983a-a5315961c6e4" },
+            "last_edited_by": { "object": "user", "id": "ee5f0f84-409a-440f-983a-a5315961c6e4" },
+            "cover": null,
+            "icon": null,
+            "parent": { "type": "database_id", "database_id": "d9824bdc-8445-4327-be8b-5b47500af6ce" },
+            "archived": false,
+            "in_trash": false,
+            "properties": {
+                "Name": { "id": "title", "type": "title", "title": [
+                    { "type": "text", "text": { "content": "Tuscan Kale", "link": null },
+                      "annotations": { "bold": false, "italic": false, "strikethrough": false, "underline": false, "code": false, "color": "default" },
+                      "plain_text": "Tuscan Kale", "href": null }
+                ] },
+                "Price": { "id": "a", "type": "number", "number": 2.5 },
+                "Stock": { "id": "b", "type": "number", "number": null },
+                "Tags": { "id": "c", "type": "multi_select", "multi_select": [
+                    { "id": "t1", "name": "Vegetable", "color": "green" }
+                ] },
+                "Store": { "id": "d", "type": "select", "select": null },
+                "Status": { "id": "e", "type": "status", "status": { "id": "s", "name": "Done", "color": "green" } },
+                "Harvest": { "id": "f", "type": "date", "date": { "start": "2024-10-10", "end": null, "time_zone": null } },
+                "In stock": { "id": "g", "type": "checkbox", "checkbox": true },
+                "Recipes": { "id": "h", "type": "relation", "relation": [{ "id": "7a774ba0-a2f2-4a31-9e3c-9f3d4e7a8b9c" }], "has_more": false },
+                "Owner": { "id": "i", "type": "people", "people": [{ "object": "user", "id": "ee5f0f84-409a-440f-983a-a5315961c6e4" }] },
+                "Prices": { "id": "j", "type": "rollup", "rollup": { "type": "array", "array": [
+                    { "type": "number", "number": 12 },
+                    { "type": "number", "number": 30 }
+                ], "function": "show_original" } },
+                "Total": { "id": "k", "type": "rollup", "rollup": { "type": "number", "number": 42, "function": "sum" } },
+                "Label": { "id": "l", "type": "formula", "formula": { "type": "string", "string": "Kale (2.5)" } },
+                "ID": { "id": "m", "type": "unique_id", "unique_id": { "prefix": "VEG", "number": 7 } },
+                "Photos": { "id": "n", "type": "files", "files": [] }
+            },
+            "url": "https://www.notion.so/Tuscan-Kale-598337872cf94fdf8782e53db20768a5",
+            "public_url": null
+        }
+        "#;
+
+        serde_json::from_str(json_data).unwrap()
+    }
+
+    /// Saturday
+    fn now() -> DateTime<FixedOffset> {
+        DateTime::parse_from_rfc3339("2024-10-12T12:00:00+00:00").unwrap()
+    }
+
+    fn matches(filter: Filter) -> bool {
+        filter.matches_at(&page(), now()).unwrap()
+    }
+
+    #[test]
+    fn match_properties() {
+        assert!(matches(Filter::rich_text_contains("Name", "kale")));
+        assert!(matches(Filter::rich_text_starts_with("Name", "Tuscan")));
+        assert!(!matches(Filter::rich_text_equals("Name", "Kale")));
+
+        assert!(matches(Filter::number_greater_than("Price", 2)));
+        assert!(!matches(Filter::number_equals("Stock", 0)));
+        assert!(matches(Filter::number_is_empty("Stock")));
+        assert!(matches(Filter::number_does_not_equal("Stock", 1)));
+
+        assert!(matches(Filter::multi_select_contains("Tags", "Vegetable")));
+        assert!(matches(Filter::select_is_empty("Store")));
+        assert!(matches(Filter::status_equals("Status", "Done")));
+        assert!(matches(Filter::checkbox_is_checked("In stock")));
+        assert!(matches(Filter::files_is_empty("Photos")));
+
+        assert!(matches(Filter::relation_contains(
+            "Recipes",
+            "7a774ba0a2f24a319e3c9f3d4e7a8b9c"
+        )));
+        assert!(matches(Filter::people_contains(
+            "Owner",
+            "ee5f0f84-409a-440f-983a-a5315961c6e4"
+        )));
+
+        assert!(matches(Filter::formula_string_ends_with("Label", "(2.5)")));
+        assert!(!matches(Filter::formula_number_equals("Label", 2.5)));
+
+        assert!(matches(Filter::unique_id_greater_than("ID", 6)));
+        assert!(!matches(Filter::unique_id_equals("ID", 6)));
+    }
+
+    #[test]
+    fn match_dates() {
+        assert!(matches(Filter::date_after("Harvest", "2024-10-09")));
+        assert!(matches(Filter::date_equals("Harvest", "2024-10-10")));
+        assert!(matches(Filter::date_on_or_before("Harvest", "2024-10-10")));
+        assert!(!matches(Filter::date_before(
+            "Harvest",
+            "2024-10-09T23:00:00Z"
+        )));
+
+        assert!(matches(Filter::date_past_week("Harvest")));
+        assert!(matches(Filter::date_this_week("Harvest")));
+        assert!(!matches(Filter::date_next_week("Harvest")));
+
+        assert!(matches(Filter::timestamp_before("2024-10-02")));
+        assert!(matches(Filter::timestamp_past_month()));
+        assert!(!matches(Filter::timestamp_past_week()));
+
+        let harvest = NaiveDate::from_ymd_opt(2024, 10, 10).unwrap();
+
+        assert!(matches(Filter::date_equals("Harvest", harvest)));
+        assert!(matches(Filter::date_on_or_after(
+            "Harvest",
+            harvest.and_hms_opt(0, 0, 0).unwrap().and_utc()
+        )));
+    }
+
+    #[test]
+    fn match_date_only_in_other_time_zone() {
+        let mut page = page();
+        page.properties.insert(
+            "Harvest".to_string(),
+            PageProperty::Date(PageDateProperty::from(
+                NaiveDate::from_ymd_opt(2024, 10, 13).unwrap(),
+            )),
+        );
+
+        // Sunday
+        let now = DateTime::parse_from_rfc3339("2024-10-13T01:00:00-07:00").unwrap();
+
+        assert!(Filter::date_this_week("Harvest")
+            .matches_at(&page, now)
+            .unwrap());
+        assert!(Filter::date_next_week("Harvest")
+            .matches_at(&page, now)
+            .unwrap());
+        assert!(Filter::date_equals("Harvest", "2024-10-13")
+            .matches_at(&page, now)
+            .unwrap());
+    }
+
+    #[test]
+    fn match_compound_and_rollup() {
+        assert!(matches(Filter::and(vec![
+            Filter::checkbox_is_checked("In stock"),
+            Filter::or(vec![
+                Filter::number_less_than("Price", 1),
+                Filter::rich_text_contains("Name", "kale"),
+            ]),
+        ])));
+
+        assert!(!matches(Filter::and(vec![
+            Filter::checkbox_is_checked("In stock"),
+            Filter::or(vec![
+                Filter::number_less_than("Price", 1),
+                Filter::rich_text_contains("Name", "spinach"),
+            ]),
+        ])));
+
+        let number = |filter: NumberFilter| Filter {
+            condition: Some(Condition::Number(filter)),
+            ..Default::default()
+        };
+
+        let greater_than = |n: f64| {
+            number(NumberFilter {
+                greater_than: Some(n),
+                ..Default::default()
+            })
+        };
+
+        assert!(matches(Filter::rollup_any("Prices", greater_than(20.0))));
+        assert!(!matches(Filter::rollup_every("Prices", greater_than(20.0))));
+        assert!(matches(Filter::rollup_every("Prices", greater_than(10.0))));
+        assert!(matches(Filter::rollup_none("Prices", greater_than(30.0))));
+        assert!(matches(Filter::rollup_any("Total", greater_than(40.0))));
+    }
+
+    #[test]
+    fn match_errors() {
+        let page = page();
+
+        match Filter::number_equals("Name", 1).matches_at(&page, now()) {
+            Err(Error::Property(error)) => assert_eq!(
+                *error,
+                PropertyError::TypeMismatch {
+                    property: "Name".to_string(),
+                    expected: "number",
+                    found: "title",
+                }
+            ),
+            other => panic!("unexpected result: {:?}", other),
+        }
+
+        assert!(Filter::checkbox_is_checked("Unknown")
+            .matches_at(&page, now())
+            .is_err());
+
+        assert!(Filter::date_after("Harvest", "yesterday")
+            .matches_at(&page, now())
+            .is_err());
+    }
+}
@@ -0,0 +1,286 @@
+//! Validation of filters against the schema of a database, without sending a request.
+
+use super::{Condition, Filter};
+use crate::{
+    database::{DatabaseProperty, DatabaseResponse},
+    error::{FilterError, FilterProblem},
+};
+
+/// The maximum depth of nested compound filters accepted by the Notion API.
+pub const MAX_NESTING_DEPTH: usize = 2;
+
+impl Filter {
+    /// Checks the filter against the properties of a database:
+    ///
+    /// - Every `property` exists in the database.
+    /// - The condition can be applied to the type of the property
+    ///   (e.g. `select_equals` cannot be used for a `status` property).
+    /// - Compound filters (`and` / `or`) are nested at most [`MAX_NESTING_DEPTH`] levels deep.
+    ///
+    /// All problems are collected in the returned [`FilterError`].
+    pub fn validate(&self, database: &DatabaseResponse) -> Result<(), FilterError> {
+        let mut problems = Vec::new();
+
+        let depth = validate_filter(self, database, &mut problems);
+
+        if depth > MAX_NESTING_DEPTH {
+            problems.push(FilterProblem::TooDeep {
+                depth,
+                limit: MAX_NESTING_DEPTH,
+            });
+        }
+
+        if problems.is_empty() {
+            Ok(())
+        } else {
+            Err(FilterError { problems })
+        }
+    }
+}
+
+/// Validates the filter and its children, and returns the depth of nested compound filters.
+fn validate_filter(
+    filter: &Filter,
+    database: &DatabaseResponse,
+    problems: &mut Vec<FilterProblem>,
+) -> usize {
+    let compound = filter
+        .and
+        .iter()
+        .chain(filter.or.iter())
+        .collect::<Vec<_>>();
+
+    let depth = compound
+        .iter()
+        .flat_map(|filters| filters.iter())
+        .map(|filter| validate_filter(filter, database, problems))
+        .max()
+        .unwrap_or(0);
+
+    let depth = if compound.is_empty() { 0 } else { depth + 1 };
+
+    match (&filter.property, &filter.condition) {
+        (Some(property), None) => {
+            if compound.is_empty() {
+                problems.push(FilterProblem::MissingCondition {
+                    property: property.clone(),
+                })
+            }
+        }
+        (Some(name), Some(condition)) => match database.properties.get(name) {
+            Some(property) => {
+                if !is_compatible(condition, property) {
+                    problems.push(FilterProblem::IncompatibleCondition {
+                        property: name.clone(),
+                        condition: condition_name(condition),
+                        found: property.type_name(),
+                    });
+                }
+            }
+            None => problems.push(FilterProblem::UnknownProperty {
+                property: name.clone(),
+            }),
+        },
+        (None, Some(condition)) => {
+            let is_timestamp =
+                matches!(condition, Condition::Timestamp(_)) && filter.timestamp.is_some();
+
+            if !is_timestamp {
+                problems.push(FilterProblem::MissingProperty {
+                    condition: condition_name(condition),
+                });
+            }
+        }
+        (None, None) => {}
+    }
+
+    depth
+}
+
+/// The name of the condition in the request body.
+fn condition_name(condition: &Condition) -> &'static str {
+    match condition {
+        Condition::Checkbox(_) => "checkbox",
+        Condition::Date(_) => "date",
+        Condition::Files(_) => "files",
+        Condition::Formula(_) => "formula",
+        Condition::MultiSelect(_) => "multi_select",
+        Condition::Number(_) => "number",
+        Condition::People(_) => "people",
+        Condition::PhoneNumber(_) => "phone_number",
+        Condition::Rollup(_) => "rollup",
+        Condition::Relation(_) => "relation",
+        Condition::RichText(_) => "rich_text",
+        Condition::Select(_) => "select",
+        Condition::Status(_) => "status",
+        Condition::Timestamp(_) => "created_time",
+        Condition::UniqueId(_) => "unique_id",
+    }
+}
+
+/// Whether the condition can be applied to the type of the property.
+///
+/// <https://developers.notion.com/reference/post-database-query-filter#type-specific-filter-conditions>
+fn is_compatible(condition: &Condition, property: &DatabaseProperty) -> bool {
+    use DatabaseProperty as P;
+
+    match condition {
+        Condition::Checkbox(_) => matches!(property, P::Checkbox(_)),
+        Condition::Date(_) => matches!(
+            property,
+            P::Date(_) | P::CreatedTime(_) | P::LastEditedTime(_)
+        ),
+        Condition::Files(_) => matches!(property, P::Files(_)),
+        Condition::Formula(_) => matches!(property, P::Formula(_)),
+        Condition::MultiSelect(_) => matches!(property, P::MultiSelect(_)),
+        Condition::Number(_) => matches!(property, P::Number(_)),
+        Condition::People(_) => {
+            matches!(
+                property,
+                P::People(_) | P::CreatedBy(_) | P::LastEditedBy(_)
+            )
+        }
+        Condition::PhoneNumber(_) => matches!(property, P::PhoneNumber(_)),
+        Condition::Rollup(_) => matches!(property, P::Rollup(_)),
+        Condition::Relation(_) => matches!(property, P::Relation(_)),
+        Condition::RichText(_) => matches!(
+            property,
+            P::RichText(_) | P::Title(_) | P::Url(_) | P::Email(_) | P::PhoneNumber(_)
+        ),
+        Condition::Select(_) => matches!(property, P::Select(_)),
+        Condition::Status(_) => matches!(property, P::Status(_)),
+        Condition::Timestamp(_) => matches!(property, P::CreatedTime(_)),
+        Condition::UniqueId(_) => matches!(property, P::UniqueId(_)),
+    }
+}
+
+// # --------------------------------------------------------------------------------
+//
+// unit test
+//
+// # --------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod unit_tests {
+    use super::*;
+
+    fn database() -> DatabaseResponse {
+        let json_data = r#"
+        {
+            "object": "database",
+            "id": "d9824bdc-8445-4327-be8b-5b47500af6ce",
+            "created_time": "2024-10-01T10:00:00.000Z",
+            "last_edited_time": "2024-10-11T10:00:00.000Z",
+            "created_by": { "object": "user", "id": "ee5f0f84-409a-440f-983a-a5315961c6e4" },
+            "last_edited_by": { "object": "user", "id": "ee5f0f84-409a-440f-983a-a5315961c6e4" },
+            "cover": null,
+            "icon": null,
+            "url": "https://www.notion.so/d9824bdc84454327be8b5b47500af6ce",
+            "public_url": null,
+            "archived": false,
+            "in_trash": false,
+            "is_inline": false,
+            "title": [],
+            "description": [],
+            "properties": {
+                "Name": { "id": "title", "name": "Name", "type": "title", "title": {} },
+                "Price": { "id": "a", "name": "Price", "type": "number", "number": { "format": "dollar" } },
+                "Status": { "id": "b", "name": "Status", "type": "status", "status": { "options": [], "groups": [] } },
+                "Website": { "id": "c", "name": "Website", "type": "url", "url": {} },
+                "Owner": { "id": "d", "name": "Owner", "type": "created_by", "created_by": {} },
+                "Due": { "id": "e", "name": "Due", "type": "date", "date": {} },
+                "Created": { "id": "f", "name": "Created", "type": "created_time", "created_time": {} },
+                "Edited": { "id": "g", "name": "Edited", "type": "last_edited_time", "last_edited_time": {} }
+            }
+        }
+        "#;
+
+        serde_json::from_str(json_data).unwrap()
+    }
+
+    #[test]
+    fn valid_filter() {
+        let filter = Filter::and(vec![
+            Filter::rich_text_contains("Name", "kale"),
+            Filter::rich_text_contains("Website", "example.com"),
+            Filter::people_contains("Owner", "ee5f0f84-409a-440f-983a-a5315961c6e4"),
+            Filter::or(vec![
+                Filter::number_greater_than("Price", 1),
+                Filter::status_equals("Status", "Done"),
+                Filter::timestamp_past_week(),
+            ]),
+        ]);
+
+        assert_eq!(filter.validate(&database()), Ok(()));
+    }
+
+    #[test]
+    fn date_filter_on_timestamp_properties() {
+        let filter = Filter::and(vec![
+            Filter::date_after("Due", "2024-10-01"),
+            Filter::date_after("Created", "2024-10-01"),
+            Filter::date_past_week("Edited"),
+        ]);
+
+        assert_eq!(filter.validate(&database()), Ok(()));
+
+        let error = Filter::date_after("Price", "2024-10-01")
+            .validate(&database())
+            .unwrap_err();
+
+        assert_eq!(
+            error.problems(),
+            [FilterProblem::IncompatibleCondition {
+                property: "Price".to_string(),
+                condition: "date",
+                found: "number",
+            }]
+        );
+    }
+
+    #[test]
+    fn invalid_filter() {
+        let filter = Filter::or(vec![
+            Filter::select_equals("Status", "Done"),
+            Filter::number_greater_than("Prise", 1),
+        ]);
+
+        let error = filter.validate(&database()).unwrap_err();
+
+        assert_eq!(
+            error.problems(),
+            [
+                FilterProblem::IncompatibleCondition {
+                    property: "Status".to_string(),
+                    condition: "select",
+                    found: "status",
+                },
+                FilterProblem::UnknownProperty {
+                    property: "Prise".to_string(),
+                },
+            ]
+        );
+
+        assert_eq!(
+            error.to_string(),
+            "invalid filter: the `select` condition cannot be applied to property `Status` of type `status`; property `Prise` does not exist"
+        );
+    }
+
+    #[test]
+    fn too_deep_filter() {
+        let filter = Filter::and(vec![Filter::or(vec![Filter::and(vec![
+            Filter::number_greater_than("Price", 1),
+        ])])]);
+
+        let error = filter.validate(&database()).unwrap_err();
+
+        assert_eq!(
+            error.problems(),
+            [FilterProblem::TooDeep {
+                depth: 3,
+                limit: MAX_NESTING_DEPTH,
+            }]
+        );
+    }
+}
@@ -0,0 +1,50 @@
+use crate::{block::Block, others::rich_text::mention::Mention};
+
+/// Overrides how individual blocks and mentions are rendered by
+/// [`HtmlRenderer`](super::HtmlRenderer).
+///
+/// Every method has a default implementation, so implement only the ones you need.
+/// Returning `None` from [`HtmlHooks::block`] or [`HtmlHooks::mention`] falls back to
+/// the default rendering.
+///
+/// ```
+/// use notionrs::html::{HtmlHooks, HtmlRenderer};
+///
+/// #[derive(Debug)]
+/// struct Portal;
+///
+/// impl HtmlHooks for Portal {
+///     fn page_url(&self, page_id: &str) -> String {
+///         format!("/docs/{}", page_id)
+///     }
+/// }
+///
+/// let renderer = HtmlRenderer::with_hooks(Portal);
+/// ```
+pub trait HtmlHooks {
+    /// Renders a block. `id` is available if the block was retrieved from the API,
+    /// and `children` is the HTML already rendered for its child blocks.
+    fn block(&self, block: &Block, id: Option<&str>, children: &str) -> Option<String> {
+        let _ = (block, id, children);
+        None
+    }
+
+    /// Renders a mention. The annotations of the rich text (bold, color, etc.)
+    /// are applied to the returned HTML.
+    fn mention(&self, mention: &Mention, plain_text: &str) -> Option<String> {
+        let _ = (mention, plain_text);
+        None
+    }
+
+    /// The URL that child pages and page/database mentions link to.
+    /// Defaults to the page on `notion.so`.
+    fn page_url(&self, page_id: &str) -> String {
+        crate::block::tree::notion_url(page_id)
+    }
+}
+
+/// Renders everything with the default HTML.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DefaultHooks;
+
+impl HtmlHooks for DefaultHooks {}
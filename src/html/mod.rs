@@ -0,0 +1,828 @@
+//! Rendering blocks and rich text as HTML.
+
+pub mod hooks;
+
+pub use hooks::{DefaultHooks, HtmlHooks};
+
+use crate::{
+    block::{Block, TreeNode},
+    others::{
+        color::Color,
+        icon::Icon,
+        language::Language,
+        rich_text::{mention::Mention, RichText, RichTextAnnotations},
+    },
+    File,
+};
+
+/// Renders blocks as HTML with the default hooks. See [`HtmlRenderer`].
+pub fn render<N>(blocks: &[N]) -> String
+where
+    N: TreeNode,
+{
+    HtmlRenderer::new().render(blocks)
+}
+
+/// Renders rich text as HTML with the default hooks. See [`HtmlRenderer`].
+pub fn render_rich_text(rich_text: &[RichText]) -> String {
+    HtmlRenderer::new().render_rich_text(rich_text)
+}
+
+/// Renders blocks (and their children) as semantic HTML.
+///
+/// - All text and attributes are escaped. Links, embeds, images, videos and audio with a
+///   scheme other than `http`, `https` or `mailto` (e.g. `javascript:`) are dropped.
+/// - Colors become CSS classes prefixed with `notion-` (e.g. `notion-blue`,
+///   `notion-red-background`). No stylesheet is included.
+/// - Consecutive list items are grouped into `<ul>` / `<ol>`, toggles become `<details>`,
+///   callouts become `<aside>`, and tables become `<table>` (with `<thead>` if the table
+///   has a column header).
+/// - Column lists become flex containers, sized by the `width_ratio` of each column.
+///
+/// Accepts `&[Block]`, `&[BlockResponse]` or the nodes of a [`BlockTree`](crate::block::BlockTree),
+/// see [`TreeNode`]. Use [`HtmlRenderer::with_hooks`] to customize the output.
+///
+/// ```
+/// use notionrs::{block::{Block, ParagraphBlock}, html::HtmlRenderer, RichText};
+///
+/// let blocks = vec![Block::Paragraph {
+///     paragraph: ParagraphBlock::new().rich_text(vec![RichText::from("1 < 2").bold()]),
+/// }];
+///
+/// let html = HtmlRenderer::new().render(&blocks);
+///
+/// assert_eq!(html, "<p><strong>1 &lt; 2</strong></p>");
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct HtmlRenderer<H = DefaultHooks> {
+    hooks: H,
+}
+
+impl HtmlRenderer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl<H> HtmlRenderer<H>
+where
+    H: HtmlHooks,
+{
+    /// Creates a renderer that consults `hooks` before rendering each block and mention.
+    pub fn with_hooks(hooks: H) -> Self {
+        Self { hooks }
+    }
+
+    /// Renders blocks and their children.
+    pub fn render<N>(&self, blocks: &[N]) -> String
+    where
+        N: TreeNode,
+    {
+        let mut html = String::new();
+
+        // The list element (`ul` / `ol`) currently open.
+        let mut open_list: Option<&str> = None;
+
+        for node in blocks {
+            let list = match node.block() {
+                Block::BulletedListItem { .. } | Block::ToDo { .. } => Some("ul"),
+                Block::NumberedListItem { .. } => Some("ol"),
+                _ => None,
+            };
+
+            if open_list != list {
+                if let Some(tag) = open_list {
+                    html.push_str(&format!("</{}>", tag));
+                }
+                if let Some(tag) = list {
+                    html.push_str(&format!("<{}>", tag));
+                }
+                open_list = list;
+            }
+
+            html.push_str(&self.render_block(node));
+        }
+
+        if let Some(tag) = open_list {
+            html.push_str(&format!("</{}>", tag));
+        }
+
+        html
+    }
+
+    /// Renders rich text as inline HTML.
+    pub fn render_rich_text(&self, rich_text: &[RichText]) -> String {
+        rich_text
+            .iter()
+            .map(|rich_text| self.render_rich_text_item(rich_text))
+            .collect()
+    }
+
+    fn render_block<N>(&self, node: &N) -> String
+    where
+        N: TreeNode,
+    {
+        let block = node.block();
+        let children = self.render(node.children());
+
+        if let Some(html) = self.hooks.block(block, node.id(), &children) {
+            return html;
+        }
+
+        match block {
+            Block::Paragraph { paragraph } => {
+                let html = format!(
+                    "<p{}>{}</p>",
+                    class_attribute(None, paragraph.color),
+                    self.render_rich_text(&paragraph.rich_text)
+                );
+
+                // Nested blocks are indented under the paragraph in Notion.
+                if children.is_empty() {
+                    html
+                } else {
+                    format!(
+                        "{}<div class=\"notion-paragraph-children\">{}</div>",
+                        html, children
+                    )
+                }
+            }
+            Block::Heading1 { heading_1 } => self.heading("h1", heading_1, &children),
+            Block::Heading2 { heading_2 } => self.heading("h2", heading_2, &children),
+            Block::Heading3 { heading_3 } => self.heading("h3", heading_3, &children),
+            Block::BulletedListItem { bulleted_list_item } => format!(
+                "<li{}>{}{}</li>",
+                class_attribute(None, bulleted_list_item.color),
+                self.render_rich_text(&bulleted_list_item.rich_text),
+                children
+            ),
+            Block::NumberedListItem { numbered_list_item } => format!(
+                "<li{}>{}{}</li>",
+                class_attribute(None, numbered_list_item.color),
+                self.render_rich_text(&numbered_list_item.rich_text),
+                children
+            ),
+            Block::ToDo { to_do } => format!(
+                "<li{}><input type=\"checkbox\" disabled{}> {}{}</li>",
+                class_attribute(Some("notion-to-do"), to_do.color),
+                if to_do.checked { " checked" } else { "" },
+                self.render_rich_text(&to_do.rich_text),
+                children
+            ),
+            Block::Toggle { toggle } => format!(
+                "<details{}><summary>{}</summary>{}</details>",
+                class_attribute(None, toggle.color),
+                self.render_rich_text(&toggle.rich_text),
+                children
+            ),
+            Block::Quote { quote } => format!(
+                "<blockquote{}>{}{}</blockquote>",
+                class_attribute(None, quote.color),
+                self.render_rich_text(&quote.rich_text),
+                children
+            ),
+            Block::Callout { callout } => {
+                let icon = match &callout.icon {
+                    Icon::Emoji(emoji) => escape(&emoji.emoji.to_string()),
+                    Icon::File(file) if is_safe_url(&file.get_url()) => {
+                        format!("<img src=\"{}\" alt=\"\">", escape(&file.get_url()))
+                    }
+                    Icon::File(_) => String::new(),
+                };
+
+                format!(
+                    "<aside{}><span class=\"notion-callout-icon\">{}</span><div class=\"notion-callout-text\">{}{}</div></aside>",
+                    class_attribute(Some("notion-callout"), callout.color),
+                    icon,
+                    self.render_rich_text(&callout.rich_text),
+                    children
+                )
+            }
+            Block::Code { code } => {
+                let content = code
+                    .rich_text
+                    .iter()
+                    .map(|rich_text| rich_text.to_string())
+                    .collect::<String>();
+
+                let class = match code.language {
+                    Language::PlainText => String::new(),
+                    language => format!(
+                        " class=\"language-{}\"",
+                        escape(&language.to_string().replace(' ', "-"))
+                    ),
+                };
+
+                self.figure(
+                    "notion-code",
+                    format!("<pre><code{}>{}</code></pre>", class, escape(&content)),
+                    &code.caption,
+                )
+            }
+            Block::Equation { equation } => format!(
+                "<div class=\"notion-equation\">{}</div>",
+                escape(&equation.expression)
+            ),
+            Block::Divider { .. } => "<hr>".to_string(),
+            Block::Table { table } => {
+                let mut rows = node.children().iter().filter_map(|row| match row.block() {
+                    Block::TableRow { table_row } => Some(&table_row.cells),
+                    _ => None,
+                });
+
+                let mut html = String::from("<table class=\"notion-table\">");
+
+                if table.has_column_header {
+                    if let Some(cells) = rows.next() {
+                        html.push_str("<thead><tr>");
+                        for cell in cells {
+                            html.push_str(&format!(
+                                "<th scope=\"col\">{}</th>",
+                                self.render_rich_text(cell)
+                            ));
+                        }
+                        html.push_str("</tr></thead>");
+                    }
+                }
+
+                html.push_str("<tbody>");
+                for cells in rows {
+                    html.push_str("<tr>");
+                    for (index, cell) in cells.iter().enumerate() {
+                        let cell = self.render_rich_text(cell);
+                        if index == 0 && table.has_row_header {
+                            html.push_str(&format!("<th scope=\"row\">{}</th>", cell));
+                        } else {
+                            html.push_str(&format!("<td>{}</td>", cell));
+                        }
+                    }
+                    html.push_str("</tr>");
+                }
+                html.push_str("</tbody></table>");
+
+                html
+            }
+            Block::Image { image } => {
+                let alt = image
+                    .get_caption()
+                    .map(|caption| caption.iter().map(|t| t.to_string()).collect::<String>())
+                    .unwrap_or_default();
+
+                self.file_figure(
+                    "notion-image",
+                    image,
+                    format!(
+                        "<img src=\"{}\" alt=\"{}\">",
+                        escape(&image.get_url()),
+                        escape(&alt)
+                    ),
+                )
+            }
+            Block::Video { video } => self.file_figure(
+                "notion-video",
+                video,
+                format!(
+                    "<video src=\"{}\" controls></video>",
+                    escape(&video.get_url())
+                ),
+            ),
+            Block::Audio { audio } => self.file_figure(
+                "notion-audio",
+                audio,
+                format!(
+                    "<audio src=\"{}\" controls></audio>",
+                    escape(&audio.get_url())
+                ),
+            ),
+            Block::File { file: pdf_or_file } | Block::Pdf { pdf: pdf_or_file } => {
+                let url = pdf_or_file.get_url();
+                let name = pdf_or_file
+                    .get_name()
+                    .map(String::from)
+                    .unwrap_or_else(|| url.clone());
+
+                self.file_figure(
+                    "notion-file",
+                    pdf_or_file,
+                    format!("<a{}>{}</a>", href_attribute(&url), escape(&name)),
+                )
+            }
+            Block::Bookmark { bookmark } => {
+                let label = if bookmark.caption.is_empty() {
+                    escape(&bookmark.url)
+                } else {
+                    self.render_rich_text(&bookmark.caption)
+                };
+
+                format!(
+                    "<a class=\"notion-bookmark\"{}>{}</a>",
+                    href_attribute(&bookmark.url),
+                    label
+                )
+            }
+            Block::Embed { embed } => match is_safe_url(&embed.url) {
+                true => format!(
+                    "<iframe class=\"notion-embed\" src=\"{}\"></iframe>",
+                    escape(&embed.url)
+                ),
+                false => String::new(),
+            },
+            Block::LinkPreview { link_preview } => format!(
+                "<a class=\"notion-link-preview\"{}>{}</a>",
+                href_attribute(&link_preview.url),
+                escape(&link_preview.url)
+            ),
+            Block::ChildPage { child_page } => {
+                self.page_link("notion-child-page", &child_page.title, node.id())
+            }
+            Block::ChildDatabase { child_database } => {
+                self.page_link("notion-child-database", &child_database.title, node.id())
+            }
+            Block::ColumnList { .. } => format!(
+                "<div class=\"notion-column-list\" style=\"display: flex; gap: 1em;\">{}</div>",
+                children
+            ),
+            Block::Column { column } => format!(
+                "<div class=\"notion-column\" style=\"flex: {} 1 0; min-width: 0;\">{}</div>",
+                column.width_ratio.unwrap_or(1.0),
+                children
+            ),
+            Block::LinkToPage { link_to_page } => match link_to_page.get_id() {
+                Some(id) => {
+                    let url = escape(&self.hooks.page_url(id));
+                    format!(
+                        "<p class=\"notion-link-to-page\"><a href=\"{}\">{}</a></p>",
+                        url, url
+                    )
+                }
+                None => String::new(),
+            },
+            Block::SyncedBlock { .. } => children,
+            Block::Breadcrumb { .. }
+            | Block::TableOfContents { .. }
+            | Block::TableRow { .. }
+            | Block::Template { .. }
+            | Block::Unsupported { .. }
+            | Block::Unknown(_) => String::new(),
+        }
+    }
+
+    fn heading(&self, tag: &str, heading: &crate::block::HeadingBlock, children: &str) -> String {
+        let html = format!(
+            "<{tag}{}>{}</{tag}>",
+            class_attribute(None, heading.color),
+            self.render_rich_text(&heading.rich_text)
+        );
+
+        if heading.is_toggleable {
+            format!("<details><summary>{}</summary>{}</details>", html, children)
+        } else {
+            html + children
+        }
+    }
+
+    fn figure(&self, class: &str, content: String, caption: &[RichText]) -> String {
+        if caption.is_empty() {
+            format!("<figure class=\"{}\">{}</figure>", class, content)
+        } else {
+            format!(
+                "<figure class=\"{}\">{}<figcaption>{}</figcaption></figure>",
+                class,
+                content,
+                self.render_rich_text(caption)
+            )
+        }
+    }
+
+    /// Files sent with the File Upload API have no URL yet, so they are skipped,
+    /// as are files with an unsafe URL.
+    fn file_figure(&self, class: &str, file: &File, content: String) -> String {
+        let url = file.get_url();
+
+        if url.is_empty() || !is_safe_url(&url) {
+            return String::new();
+        }
+
+        self.figure(class, content, file.get_caption().unwrap_or_default())
+    }
+
+    fn page_link(&self, class: &str, title: &str, id: Option<&str>) -> String {
+        match id {
+            Some(id) => format!(
+                "<p class=\"{}\"><a href=\"{}\">{}</a></p>",
+                class,
+                escape(&self.hooks.page_url(id)),
+                escape(title)
+            ),
+            None => format!("<p class=\"{}\">{}</p>", class, escape(title)),
+        }
+    }
+
+    fn render_rich_text_item(&self, rich_text: &RichText) -> String {
+        match rich_text {
+            RichText::Text {
+                text,
+                annotations,
+                href,
+                ..
+            } => {
+                let url = href
+                    .as_deref()
+                    .or_else(|| text.link.as_ref().map(|link| link.url.as_str()));
+
+                let content = escape(&text.content).replace('\n', "<br>");
+
+                match url {
+                    Some(url) => decorate(
+                        format!("<a{}>{}</a>", href_attribute(url), content),
+                        annotations,
+                    ),
+                    None => decorate(content, annotations),
+                }
+            }
+            RichText::Mention {
+                mention,
+                annotations,
+                plain_text,
+                href,
+            } => {
+                let html = self
+                    .hooks
+                    .mention(mention, plain_text)
+                    .unwrap_or_else(|| self.render_mention(mention, plain_text, href.as_deref()));
+
+                decorate(html, annotations)
+            }
+            RichText::Equation {
+                equation,
+                annotations,
+                ..
+            } => decorate(
+                format!(
+                    "<span class=\"notion-equation\">{}</span>",
+                    escape(&equation.expression)
+                ),
+                annotations,
+            ),
+        }
+    }
+
+    fn render_mention(&self, mention: &Mention, plain_text: &str, href: Option<&str>) -> String {
+        let (class, url) = match mention {
+            Mention::Page(page) => ("notion-page-mention", Some(self.hooks.page_url(&page.id))),
+            Mention::Database(database) => (
+                "notion-database-mention",
+                Some(self.hooks.page_url(&database.id)),
+            ),
+            Mention::User(_) => ("notion-user-mention", None),
+            Mention::Date(_) => ("notion-date-mention", None),
+            Mention::TemplateMention(_) => ("notion-template-mention", None),
+            Mention::LinkPreview(link_preview) => {
+                ("notion-link-mention", Some(link_preview.url.clone()))
+            }
+            Mention::LinkMention(link_mention) => {
+                ("notion-link-mention", link_mention.href.clone())
+            }
+        };
+
+        match url.as_deref().or(href) {
+            Some(url) => format!(
+                "<a class=\"{}\"{}>{}</a>",
+                class,
+                href_attribute(url),
+                escape(plain_text)
+            ),
+            None => format!("<span class=\"{}\">{}</span>", class, escape(plain_text)),
+        }
+    }
+}
+
+/// Wraps inline HTML with the elements of the annotations.
+fn decorate(mut html: String, annotations: &RichTextAnnotations) -> String {
+    if annotations.code {
+        html = format!("<code>{}</code>", html);
+    }
+    if annotations.strikethrough {
+        html = format!("<s>{}</s>", html);
+    }
+    if annotations.underline {
+        html = format!("<u>{}</u>", html);
+    }
+    if annotations.italic {
+        html = format!("<em>{}</em>", html);
+    }
+    if annotations.bold {
+        html = format!("<strong>{}</strong>", html);
+    }
+    if let Some(class) = color_class(annotations.color) {
+        html = format!("<span class=\"{}\">{}</span>", class, html);
+    }
+
+    html
+}
+
+/// The CSS class of a color, e.g. `notion-blue-background`. `None` for the default color.
+fn color_class(color: Color) -> Option<String> {
+    match color {
+        Color::Default => None,
+        color => Some(format!("notion-{}", color.as_str().replace('_', "-"))),
+    }
+}
+
+/// Builds a ` class="..."` attribute from a fixed class and a color.
+fn class_attribute(class: Option<&str>, color: Color) -> String {
+    let classes = class
+        .map(String::from)
+        .into_iter()
+        .chain(color_class(color))
+        .collect::<Vec<_>>();
+
+    if classes.is_empty() {
+        String::new()
+    } else {
+        format!(" class=\"{}\"", classes.join(" "))
+    }
+}
+
+/// Builds a ` href="..."` attribute. URLs with a scheme other than `http`, `https` or
+/// `mailto` (e.g. `javascript:`) are dropped, so the link is not clickable.
+fn href_attribute(url: &str) -> String {
+    if is_safe_url(url) {
+        format!(" href=\"{}\"", escape(url))
+    } else {
+        String::new()
+    }
+}
+
+/// Whether the URL is relative or uses the `http`, `https` or `mailto` scheme.
+fn is_safe_url(url: &str) -> bool {
+    // Browsers ignore whitespace and control characters in the scheme, e.g. `java\tscript:`.
+    let url = url
+        .chars()
+        .filter(|c| !c.is_ascii_whitespace() && !c.is_ascii_control())
+        .collect::<String>();
+
+    match url.split_once(':') {
+        Some((scheme, _)) if !scheme.contains(['/', '?', '#']) => ["http", "https", "mailto"]
+            .iter()
+            .any(|allowed| scheme.eq_ignore_ascii_case(allowed)),
+        _ => true,
+    }
+}
+
+/// Escapes text for use in HTML content and attribute values.
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(c),
+        }
+    }
+
+    escaped
+}
+
+// # --------------------------------------------------------------------------------
+//
+// unit test
+//
+// # --------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod unit_tests {
+    use super::*;
+    use crate::{
+        block::{
+            BlockNode, BulletedListItemBlock, ColumnBlock, ColumnListBlock, NumberedListItemBlock,
+            ParagraphBlock, TableBlock, TableRowBlock, ToggleBlock,
+        },
+        others::rich_text::mention::PageMention,
+    };
+
+    fn paragraph(text: &str) -> Block {
+        Block::Paragraph {
+            paragraph: ParagraphBlock::new().rich_text(vec![RichText::from(text)]),
+        }
+    }
+
+    /// A node of a block tree, as returned by `get_block_tree`.
+    fn node(block: Block, children: Vec<BlockNode>) -> BlockNode {
+        let mut response = serde_json::json!({
+            "object": "block",
+            "id": "c02fc1d3-db8b-45c5-a222-27595b15aea7",
+            "parent": { "type": "page_id", "page_id": "59833787-2cf9-4fdf-8782-e53db20768a5" },
+            "created_time": "2022-03-01T19:05:00.000Z",
+            "last_edited_time": "2022-03-01T19:05:00.000Z",
+            "created_by": { "object": "user", "id": "ee5f0f84-409a-440f-983a-a5315961c6e4" },
+            "last_edited_by": { "object": "user", "id": "ee5f0f84-409a-440f-983a-a5315961c6e4" },
+            "has_children": !children.is_empty(),
+            "archived": false,
+            "in_trash": false,
+        });
+
+        if let (Some(response), serde_json::Value::Object(block)) = (
+            response.as_object_mut(),
+            serde_json::to_value(block).unwrap(),
+        ) {
+            response.extend(block);
+        }
+
+        BlockNode {
+            block: serde_json::from_value(response).unwrap(),
+            children,
+        }
+    }
+
+    #[test]
+    fn render_rich_text_annotations() {
+        let rich_text = vec![
+            RichText::from("<b>"),
+            RichText::from("bold").bold().italic(),
+            RichText::from("red").color(Color::RedBackground),
+            RichText::from("link").href("https://example.com/?a=1&b=2"),
+        ];
+
+        assert_eq!(
+            render_rich_text(&rich_text),
+            "&lt;b&gt;<strong><em>bold</em></strong><span class=\"notion-red-background\">red</span><a href=\"https://example.com/?a=1&amp;b=2\">link</a>"
+        );
+    }
+
+    #[test]
+    fn render_nested_paragraph() {
+        let nodes = vec![node(
+            paragraph("parent"),
+            vec![node(paragraph("NESTED CHILD"), vec![])],
+        )];
+
+        assert_eq!(
+            render(&nodes),
+            "<p>parent</p><div class=\"notion-paragraph-children\"><p>NESTED CHILD</p></div>"
+        );
+    }
+
+    #[test]
+    fn drop_unsafe_links() {
+        let rich_text = vec![
+            RichText::from("a").href("javascript:alert(1)"),
+            RichText::from("b").href(" Java\tScript:alert(1)"),
+            RichText::from("c").href("data:text/html,<script>"),
+            RichText::from("d").href("mailto:a@example.com"),
+            RichText::from("e").href("/relative?x=a:b"),
+        ];
+
+        assert_eq!(
+            render_rich_text(&rich_text),
+            "<a>a</a><a>b</a><a>c</a><a href=\"mailto:a@example.com\">d</a><a href=\"/relative?x=a:b\">e</a>"
+        );
+
+        let blocks = vec![Block::Bookmark {
+            bookmark: crate::block::BookmarkBlock::new().url("javascript:alert(1)"),
+        }];
+
+        assert_eq!(
+            render(&blocks),
+            "<a class=\"notion-bookmark\">javascript:alert(1)</a>"
+        );
+
+        let blocks = vec![
+            Block::Image {
+                image: File::new().url("javascript:alert(1)"),
+            },
+            Block::Video {
+                video: File::new().url("data:video/mp4;base64,AAAA"),
+            },
+            Block::Audio {
+                audio: File::new().url("vbscript:msgbox(1)"),
+            },
+            Block::Callout {
+                callout: crate::block::CalloutBlock::new()
+                    .rich_text(vec![RichText::from("Note")])
+                    .icon_file("javascript:alert(1)"),
+            },
+        ];
+
+        assert_eq!(
+            render(&blocks),
+            "<aside class=\"notion-callout\"><span class=\"notion-callout-icon\"></span><div class=\"notion-callout-text\">Note</div></aside>"
+        );
+    }
+
+    #[test]
+    fn render_lists_and_toggle() {
+        let blocks = vec![
+            Block::BulletedListItem {
+                bulleted_list_item: BulletedListItemBlock::new()
+                    .rich_text(vec![RichText::from("a")]),
+            },
+            Block::BulletedListItem {
+                bulleted_list_item: BulletedListItemBlock::new()
+                    .rich_text(vec![RichText::from("b")])
+                    .children(vec![Block::NumberedListItem {
+                        numbered_list_item: NumberedListItemBlock::new()
+                            .rich_text(vec![RichText::from("c")]),
+                    }]),
+            },
+            Block::Toggle {
+                toggle: ToggleBlock::new()
+                    .rich_text(vec![RichText::from("More")])
+                    .children(vec![paragraph("Hidden")]),
+            },
+        ];
+
+        assert_eq!(
+            render(&blocks),
+            "<ul><li>a</li><li>b<ol><li>c</li></ol></li></ul><details><summary>More</summary><p>Hidden</p></details>"
+        );
+    }
+
+    #[test]
+    fn render_columns_and_table() {
+        let row = |cells: [&str; 2]| Block::TableRow {
+            table_row: TableRowBlock::new()
+                .cells(cells.iter().map(|c| vec![RichText::from(*c)]).collect()),
+        };
+
+        let blocks = vec![
+            Block::ColumnList {
+                column_list: ColumnListBlock {
+                    children: Some(vec![
+                        Block::Column {
+                            column: ColumnBlock::new()
+                                .width_ratio(0.25)
+                                .children(vec![paragraph("left")]),
+                        },
+                        Block::Column {
+                            column: ColumnBlock::new()
+                                .width_ratio(0.75)
+                                .children(vec![paragraph("right")]),
+                        },
+                    ]),
+                },
+            },
+            Block::Table {
+                table: TableBlock::new()
+                    .table_width(2)
+                    .has_column_header(true)
+                    .children(vec![row(["a", "b"]), row(["1", "2"])]),
+            },
+        ];
+
+        let html = render(&blocks);
+
+        assert!(html.starts_with(
+            "<div class=\"notion-column-list\" style=\"display: flex; gap: 1em;\"><div class=\"notion-column\" style=\"flex: 0.25 1 0; min-width: 0;\"><p>left</p></div>"
+        ));
+        assert!(html.ends_with(
+            "<table class=\"notion-table\"><thead><tr><th scope=\"col\">a</th><th scope=\"col\">b</th></tr></thead><tbody><tr><td>1</td><td>2</td></tr></tbody></table>"
+        ));
+    }
+
+    #[derive(Debug)]
+    struct Portal;
+
+    impl HtmlHooks for Portal {
+        fn block(&self, block: &Block, _id: Option<&str>, _children: &str) -> Option<String> {
+            match block {
+                Block::Divider { .. } => Some("<hr class=\"portal\">".to_string()),
+                _ => None,
+            }
+        }
+
+        fn page_url(&self, page_id: &str) -> String {
+            format!("/docs/{}", page_id)
+        }
+    }
+
+    #[test]
+    fn render_with_hooks() {
+        let mention = RichText::Mention {
+            mention: Mention::Page(PageMention {
+                id: "page-id".to_string(),
+            }),
+            annotations: RichTextAnnotations::default(),
+            plain_text: "Guide".to_string(),
+            href: Some("https://www.notion.so/pageid".to_string()),
+        };
+
+        let blocks = vec![
+            Block::Paragraph {
+                paragraph: ParagraphBlock::new().rich_text(vec![mention]),
+            },
+            Block::Divider {
+                divider: std::collections::HashMap::new(),
+            },
+        ];
+
+        assert_eq!(
+            HtmlRenderer::with_hooks(Portal).render(&blocks),
+            "<p><a class=\"notion-page-mention\" href=\"/docs/page-id\">Guide</a></p><hr class=\"portal\">"
+        );
+    }
+}
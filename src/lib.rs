@@ -1,25 +1,39 @@
 #![deny(missing_debug_implementations)]
 #![deny(missing_copy_implementations)]
 
+// Allows `#[derive(NotionRow)]` (which refers to `::notionrs`) to be used inside this crate.
+extern crate self as notionrs;
+
 pub mod block;
 pub mod client;
+pub mod comment;
 pub mod database;
 pub mod error;
+pub mod file_upload;
 pub mod filter;
+pub mod html;
 pub mod list_response;
+pub mod markdown;
 pub mod others;
 pub mod page;
 pub mod prelude;
 pub mod search;
+#[cfg(feature = "test-util")]
+pub mod testing;
 pub mod to_json;
 pub mod user;
 
 pub use crate::client::Client;
 pub use crate::others::color::Color;
 pub use crate::others::emoji::Emoji;
-pub use crate::others::file::{ExternalFile, File, UploadedFile};
+pub use crate::others::file::{ExternalFile, File, FileUploadFile, UploadedFile};
 pub use crate::others::icon::Icon;
+pub use crate::others::id::{BlockId, DatabaseId, PageId, UserId};
 pub use crate::others::language::Language;
 pub use crate::others::rich_text::RichText;
 pub use crate::others::select::{Select, SelectColor, SelectGroup};
+pub use crate::page::row::NotionRow;
 pub use crate::user::{bot::*, person::*, User};
+
+#[cfg(feature = "derive")]
+pub use notionrs_macro::NotionRow;
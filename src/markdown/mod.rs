@@ -0,0 +1,9 @@
+//! Conversion between Markdown and Notion blocks.
+
+#[cfg(feature = "markdown")]
+mod parse;
+mod render;
+
+#[cfg(feature = "markdown")]
+pub use parse::parse;
+pub use render::{render, render_rich_text};
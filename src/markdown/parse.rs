@@ -0,0 +1,728 @@
+use pulldown_cmark::{CodeBlockKind, Event, HeadingLevel, Options, Parser, Tag, TagEnd};
+
+use crate::{
+    block::{
+        Block, BulletedListItemBlock, CodeBlock, EquationBlock, HeadingBlock,
+        NumberedListItemBlock, ParagraphBlock, QuoteBlock, TableBlock, TableRowBlock, ToDoBlock,
+    },
+    others::{
+        language::Language,
+        rich_text::{equation::Equation, RichText, RichTextAnnotations},
+    },
+    File,
+};
+
+/// The maximum length of the content of one rich text object accepted by Notion.
+const MAX_TEXT_LENGTH: usize = 2000;
+
+/// Parses CommonMark (with the GFM extensions) into blocks that can be sent with
+/// `append_block_children` or `create_page`.
+///
+/// | Markdown | Block |
+/// | --- | --- |
+/// | Paragraph | `Paragraph` |
+/// | `#`, `##`, `###` (and deeper) | `Heading1`, `Heading2`, `Heading3` |
+/// | `-`, `*` / `1.` | `BulletedListItem` / `NumberedListItem` (nested lists become children) |
+/// | `- [ ]`, `- [x]` | `ToDo` |
+/// | `>` | `Quote` |
+/// | Fenced / indented code | `Code` (the info string is mapped to [`Language`]) |
+/// | GFM table | `Table` with `TableRow` children |
+/// | `---` | `Divider` |
+/// | `![alt](url)` | `Image` (placed after the paragraph that contains it) |
+/// | `$$...$$` | `Equation` |
+/// | HTML block | `Paragraph` with the HTML as plain text |
+///
+/// Inline `**bold**`, `*italic*`, `~~strikethrough~~` and `` `code` `` become
+/// [`RichTextAnnotations`], links become `href`, and `$...$` becomes an equation rich text.
+///
+/// ```
+/// use notionrs::block::Block;
+///
+/// let blocks = notionrs::markdown::parse("# Title\n\n- [x] **done**\n- [ ] todo");
+///
+/// assert!(matches!(blocks[0], Block::Heading1 { .. }));
+/// assert!(matches!(blocks[1], Block::ToDo { .. }));
+/// ```
+pub fn parse(markdown: &str) -> Vec<Block> {
+    let options = Options::ENABLE_TABLES
+        | Options::ENABLE_STRIKETHROUGH
+        | Options::ENABLE_TASKLISTS
+        | Options::ENABLE_MATH;
+
+    let mut converter = Converter {
+        frames: vec![Frame::new(FrameKind::Root)],
+        ..Default::default()
+    };
+
+    for event in Parser::new_ext(markdown, options) {
+        converter.handle(event);
+    }
+
+    converter.finish()
+}
+
+/// A block that can contain other blocks.
+#[derive(Debug)]
+enum FrameKind {
+    Root,
+    Item {
+        ordered: bool,
+        checked: Option<bool>,
+    },
+    Quote,
+}
+
+#[derive(Debug)]
+struct Frame {
+    kind: FrameKind,
+
+    /// The text of the block itself (e.g. the first paragraph of a list item).
+    rich_text: Vec<RichText>,
+
+    children: Vec<Block>,
+}
+
+impl Frame {
+    fn new(kind: FrameKind) -> Self {
+        Self {
+            kind,
+            rich_text: vec![],
+            children: vec![],
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct Table {
+    width: usize,
+    has_column_header: bool,
+    rows: Vec<Vec<Vec<RichText>>>,
+    current_row: Vec<Vec<RichText>>,
+}
+
+#[derive(Debug, Default)]
+struct Converter {
+    frames: Vec<Frame>,
+
+    /// The lists currently open. `true` if ordered.
+    lists: Vec<bool>,
+
+    /// The text of the paragraph, heading or table cell being parsed.
+    inline: Option<Vec<RichText>>,
+
+    heading: Option<HeadingLevel>,
+
+    code: Option<(Language, String)>,
+
+    table: Option<Table>,
+
+    /// The URL and the alt text of the image being parsed.
+    image: Option<(String, Vec<RichText>)>,
+
+    /// Blocks found inside a paragraph (images, display math),
+    /// which are emitted after it.
+    pending: Vec<Block>,
+
+    bold: usize,
+    italic: usize,
+    strikethrough: usize,
+    links: Vec<String>,
+}
+
+impl Converter {
+    fn handle(&mut self, event: Event) {
+        match event {
+            Event::Start(tag) => self.start(tag),
+            Event::End(tag) => self.end(tag),
+            Event::Text(text) => {
+                if let Some((_, code)) = &mut self.code {
+                    code.push_str(&text);
+                } else {
+                    self.push_text(&text, false);
+                }
+            }
+            Event::Code(code) => self.push_text(&code, true),
+            Event::InlineMath(expression) => self.push_rich_text(equation(&expression)),
+            Event::DisplayMath(expression) => self.pending.push(Block::Equation {
+                equation: EquationBlock::new().expression(expression.trim()),
+            }),
+            Event::Html(html) | Event::InlineHtml(html) => self.push_text(&html, false),
+            Event::SoftBreak => self.push_text(" ", false),
+            Event::HardBreak => self.push_text("\n", false),
+            Event::Rule => self.push_block(Block::Divider {
+                divider: std::collections::HashMap::new(),
+            }),
+            Event::TaskListMarker(checked) => {
+                if let Some(Frame {
+                    kind:
+                        FrameKind::Item {
+                            checked: marker, ..
+                        },
+                    ..
+                }) = self.frames.last_mut()
+                {
+                    *marker = Some(checked);
+                }
+            }
+            Event::FootnoteReference(_) => {}
+        }
+    }
+
+    fn start(&mut self, tag: Tag) {
+        match tag {
+            // Raw HTML has no block equivalent, so it is kept as the text of a paragraph.
+            Tag::Paragraph | Tag::HtmlBlock => self.inline = Some(vec![]),
+            Tag::Heading { level, .. } => {
+                self.heading = Some(level);
+                self.inline = Some(vec![]);
+            }
+            Tag::BlockQuote(_) => self.frames.push(Frame::new(FrameKind::Quote)),
+            Tag::CodeBlock(kind) => {
+                let language = match kind {
+                    CodeBlockKind::Fenced(info) => info
+                        .split([',', ' '])
+                        .next()
+                        .and_then(|name| name.parse::<Language>().ok())
+                        .unwrap_or_default(),
+                    CodeBlockKind::Indented => Language::default(),
+                };
+                self.code = Some((language, String::new()));
+            }
+            Tag::List(start) => self.lists.push(start.is_some()),
+            Tag::Item => {
+                let ordered = self.lists.last().copied().unwrap_or(false);
+                self.frames.push(Frame::new(FrameKind::Item {
+                    ordered,
+                    checked: None,
+                }));
+            }
+            Tag::Table(alignments) => {
+                self.table = Some(Table {
+                    width: alignments.len(),
+                    ..Default::default()
+                })
+            }
+            Tag::TableCell => self.inline = Some(vec![]),
+            Tag::Emphasis => self.italic += 1,
+            Tag::Strong => self.bold += 1,
+            Tag::Strikethrough => self.strikethrough += 1,
+            Tag::Link { dest_url, .. } => self.links.push(dest_url.to_string()),
+            Tag::Image { dest_url, .. } => self.image = Some((dest_url.to_string(), vec![])),
+            _ => {}
+        }
+    }
+
+    fn end(&mut self, tag: TagEnd) {
+        match tag {
+            TagEnd::Paragraph | TagEnd::HtmlBlock => {
+                if let Some(RichText::Text {
+                    text, plain_text, ..
+                }) = self.inline.as_mut().and_then(|inline| inline.last_mut())
+                {
+                    // Each line of an HTML block ends with a line break.
+                    text.content
+                        .truncate(text.content.trim_end_matches('\n').len());
+                    plain_text.truncate(plain_text.trim_end_matches('\n').len());
+                }
+
+                let rich_text = self.take_inline();
+
+                let frame = self.frame();
+
+                if !rich_text.is_empty() {
+                    if matches!(frame.kind, FrameKind::Root)
+                        || !frame.rich_text.is_empty()
+                        || !frame.children.is_empty()
+                    {
+                        frame.children.push(Block::Paragraph {
+                            paragraph: ParagraphBlock::new().rich_text(rich_text),
+                        });
+                    } else {
+                        frame.rich_text = rich_text;
+                    }
+                }
+
+                self.flush_pending();
+            }
+            TagEnd::Heading(_) => {
+                let rich_text = self.take_inline();
+                let heading = HeadingBlock::new().rich_text(rich_text);
+
+                let block = match self.heading.take() {
+                    Some(HeadingLevel::H1) => Block::Heading1 { heading_1: heading },
+                    Some(HeadingLevel::H2) => Block::Heading2 { heading_2: heading },
+                    _ => Block::Heading3 { heading_3: heading },
+                };
+
+                self.push_block(block);
+                self.flush_pending();
+            }
+            TagEnd::BlockQuote(_) => {
+                let frame = self.pop_frame();
+
+                let mut quote = QuoteBlock::new().rich_text(frame.rich_text);
+
+                if !frame.children.is_empty() {
+                    quote = quote.children(frame.children);
+                }
+
+                self.push_block(Block::Quote { quote });
+            }
+            TagEnd::CodeBlock => {
+                if let Some((language, code)) = self.code.take() {
+                    let code = code.strip_suffix('\n').unwrap_or(&code);
+
+                    self.push_block(Block::Code {
+                        code: CodeBlock::new()
+                            .rich_text(split_long_text(vec![RichText::from(code)]))
+                            .lnaguage(language),
+                    });
+                }
+            }
+            TagEnd::List(_) => {
+                self.lists.pop();
+            }
+            TagEnd::Item => {
+                self.flush_pending();
+
+                let frame = self.pop_frame();
+
+                let FrameKind::Item { ordered, checked } = frame.kind else {
+                    return;
+                };
+
+                let children = (!frame.children.is_empty()).then_some(frame.children);
+
+                let block = match (checked, ordered) {
+                    (Some(checked), _) => Block::ToDo {
+                        to_do: ToDoBlock {
+                            rich_text: frame.rich_text,
+                            checked,
+                            children,
+                            ..Default::default()
+                        },
+                    },
+                    (None, true) => Block::NumberedListItem {
+                        numbered_list_item: NumberedListItemBlock {
+                            rich_text: frame.rich_text,
+                            children,
+                            ..Default::default()
+                        },
+                    },
+                    (None, false) => Block::BulletedListItem {
+                        bulleted_list_item: BulletedListItemBlock {
+                            rich_text: frame.rich_text,
+                            children,
+                            ..Default::default()
+                        },
+                    },
+                };
+
+                self.push_block(block);
+            }
+            TagEnd::TableCell => {
+                let cell = self.take_inline();
+
+                if let Some(table) = &mut self.table {
+                    table.current_row.push(cell);
+                }
+            }
+            TagEnd::TableHead | TagEnd::TableRow => {
+                if let Some(table) = &mut self.table {
+                    let row = std::mem::take(&mut table.current_row);
+                    table.rows.push(row);
+
+                    if tag == TagEnd::TableHead {
+                        table.has_column_header = true;
+                    }
+                }
+            }
+            TagEnd::Table => {
+                if let Some(table) = self.table.take() {
+                    let rows = table
+                        .rows
+                        .into_iter()
+                        .map(|mut cells| {
+                            cells.resize(table.width, vec![]);
+                            Block::TableRow {
+                                table_row: TableRowBlock::new().cells(cells),
+                            }
+                        })
+                        .collect();
+
+                    self.push_block(Block::Table {
+                        table: TableBlock::new()
+                            .table_width(table.width as u16)
+                            .has_column_header(table.has_column_header)
+                            .children(rows),
+                    });
+                }
+
+                self.flush_pending();
+            }
+            TagEnd::Emphasis => self.italic = self.italic.saturating_sub(1),
+            TagEnd::Strong => self.bold = self.bold.saturating_sub(1),
+            TagEnd::Strikethrough => self.strikethrough = self.strikethrough.saturating_sub(1),
+            TagEnd::Link => {
+                self.links.pop();
+            }
+            TagEnd::Image => {
+                if let Some((url, alt)) = self.image.take() {
+                    let mut file = File::new().url(url);
+
+                    if !alt.is_empty() {
+                        file = file.caption(alt);
+                    }
+
+                    self.pending.push(Block::Image { image: file });
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn finish(mut self) -> Vec<Block> {
+        self.flush_pending();
+
+        // Close the blocks left open by malformed input.
+        while self.frames.len() > 1 {
+            self.end(match self.frames.last().map(|frame| &frame.kind) {
+                Some(FrameKind::Quote) => TagEnd::BlockQuote(None),
+                _ => TagEnd::Item,
+            });
+        }
+
+        self.frames
+            .pop()
+            .map(|frame| frame.children)
+            .unwrap_or_default()
+    }
+
+    /// The innermost open block.
+    fn frame(&mut self) -> &mut Frame {
+        self.frames.last_mut().expect("the root frame exists")
+    }
+
+    /// Closes the innermost block. The root frame is never popped.
+    fn pop_frame(&mut self) -> Frame {
+        match self.frames.len() {
+            0 | 1 => Frame::new(FrameKind::Root),
+            _ => self.frames.pop().expect("the frame exists"),
+        }
+    }
+
+    fn push_block(&mut self, block: Block) {
+        self.frame().children.push(block);
+    }
+
+    fn flush_pending(&mut self) {
+        let pending = std::mem::take(&mut self.pending);
+        self.frame().children.extend(pending);
+    }
+
+    fn take_inline(&mut self) -> Vec<RichText> {
+        split_long_text(self.inline.take().unwrap_or_default())
+    }
+
+    fn annotations(&self, code: bool) -> RichTextAnnotations {
+        RichTextAnnotations {
+            bold: self.bold > 0,
+            italic: self.italic > 0,
+            strikethrough: self.strikethrough > 0,
+            code,
+            ..Default::default()
+        }
+    }
+
+    fn push_text(&mut self, text: &str, code: bool) {
+        let mut rich_text = RichText::from(text).annotations(self.annotations(code));
+
+        if let Some(href) = self.links.last() {
+            rich_text = rich_text.href(href);
+        }
+
+        self.push_rich_text(rich_text);
+    }
+
+    /// Appends the rich text to the current paragraph, heading, table cell or image caption.
+    /// Adjacent texts with the same style are merged.
+    fn push_rich_text(&mut self, rich_text: RichText) {
+        let target = if let Some((_, alt)) = &mut self.image {
+            alt
+        } else if let Some(inline) = &mut self.inline {
+            inline
+        } else {
+            // The text of a tight list item is not wrapped in a paragraph.
+            &mut self.frame().rich_text
+        };
+
+        if let (
+            Some(RichText::Text {
+                text,
+                annotations,
+                plain_text,
+                href,
+            }),
+            RichText::Text {
+                text: next_text,
+                annotations: next_annotations,
+                href: next_href,
+                ..
+            },
+        ) = (target.last_mut(), &rich_text)
+        {
+            if annotations == next_annotations && href == next_href {
+                text.content.push_str(&next_text.content);
+                plain_text.push_str(&next_text.content);
+                return;
+            }
+        }
+
+        target.push(rich_text);
+    }
+}
+
+fn equation(expression: &str) -> RichText {
+    RichText::Equation {
+        equation: Equation::from(expression),
+        annotations: RichTextAnnotations::default(),
+        plain_text: expression.to_string(),
+        href: None,
+    }
+}
+
+/// Splits texts longer than the limit of the Notion API into multiple rich text objects.
+fn split_long_text(rich_text: Vec<RichText>) -> Vec<RichText> {
+    let mut result = Vec::with_capacity(rich_text.len());
+
+    for item in rich_text {
+        match &item {
+            RichText::Text {
+                text,
+                annotations,
+                href,
+                ..
+            } if text.content.chars().count() > MAX_TEXT_LENGTH => {
+                let chars = text.content.chars().collect::<Vec<char>>();
+
+                for chunk in chars.chunks(MAX_TEXT_LENGTH) {
+                    let mut part =
+                        RichText::from(chunk.iter().collect::<String>()).annotations(*annotations);
+
+                    if let Some(href) = href {
+                        part = part.href(href);
+                    }
+
+                    result.push(part);
+                }
+            }
+            _ => result.push(item),
+        }
+    }
+
+    result
+}
+
+// # --------------------------------------------------------------------------------
+//
+// unit test
+//
+// # --------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod unit_tests {
+    use super::*;
+
+    fn plain_text(rich_text: &[RichText]) -> String {
+        rich_text.iter().map(|t| t.to_string()).collect()
+    }
+
+    #[test]
+    fn parse_headings_and_paragraphs() {
+        let blocks = parse("# Title\n\n## Section\n\n#### Deep\n\nHello **world**");
+
+        assert_eq!(blocks.len(), 4);
+        assert!(matches!(blocks[0], Block::Heading1 { .. }));
+        assert!(matches!(blocks[1], Block::Heading2 { .. }));
+        assert!(matches!(blocks[2], Block::Heading3 { .. }));
+
+        match &blocks[3] {
+            Block::Paragraph { paragraph } => {
+                assert_eq!(paragraph.rich_text.len(), 2);
+                assert_eq!(plain_text(&paragraph.rich_text), "Hello world");
+
+                match &paragraph.rich_text[1] {
+                    RichText::Text { annotations, .. } => assert!(annotations.bold),
+                    _ => panic!("unexpected rich text"),
+                }
+            }
+            _ => panic!("unexpected block"),
+        }
+    }
+
+    #[test]
+    fn parse_html_block() {
+        let blocks = parse("<div align=\"center\">\n  <img src=\"logo.png\">\n</div>\n\nText");
+
+        assert_eq!(blocks.len(), 2);
+
+        let Block::Paragraph { paragraph } = &blocks[0] else {
+            panic!("unexpected block");
+        };
+
+        assert_eq!(
+            plain_text(&paragraph.rich_text),
+            "<div align=\"center\">\n  <img src=\"logo.png\">\n</div>"
+        );
+    }
+
+    #[test]
+    fn parse_inline_styles() {
+        let blocks = parse("*a* ~~b~~ `c` [d](https://example.com) $e=mc^2$");
+
+        let Block::Paragraph { paragraph } = &blocks[0] else {
+            panic!("unexpected block");
+        };
+
+        let rich_text = &paragraph.rich_text;
+
+        let RichText::Text { annotations, .. } = &rich_text[0] else {
+            panic!("unexpected rich text");
+        };
+        assert!(annotations.italic);
+
+        let RichText::Text { annotations, .. } = &rich_text[2] else {
+            panic!("unexpected rich text");
+        };
+        assert!(annotations.strikethrough);
+
+        let RichText::Text { annotations, .. } = &rich_text[4] else {
+            panic!("unexpected rich text");
+        };
+        assert!(annotations.code);
+
+        let RichText::Text { href, text, .. } = &rich_text[6] else {
+            panic!("unexpected rich text");
+        };
+        assert_eq!(href.as_deref(), Some("https://example.com"));
+        assert_eq!(text.link.as_ref().unwrap().url, "https://example.com");
+
+        let RichText::Equation { equation, .. } = &rich_text[8] else {
+            panic!("unexpected rich text");
+        };
+        assert_eq!(equation.expression, "e=mc^2");
+    }
+
+    #[test]
+    fn parse_nested_lists() {
+        let blocks = parse("- a\n  1. b\n  2. c\n- d\n\n- [x] done\n- [ ] todo");
+
+        assert_eq!(blocks.len(), 4);
+
+        let Block::BulletedListItem { bulleted_list_item } = &blocks[0] else {
+            panic!("unexpected block");
+        };
+
+        assert_eq!(plain_text(&bulleted_list_item.rich_text), "a");
+
+        let children = bulleted_list_item.children.as_ref().unwrap();
+        assert_eq!(children.len(), 2);
+        assert!(matches!(children[0], Block::NumberedListItem { .. }));
+
+        match &blocks[2] {
+            Block::ToDo { to_do } => {
+                assert!(to_do.checked);
+                assert_eq!(plain_text(&to_do.rich_text), "done");
+            }
+            _ => panic!("unexpected block"),
+        }
+
+        match &blocks[3] {
+            Block::ToDo { to_do } => assert!(!to_do.checked),
+            _ => panic!("unexpected block"),
+        }
+    }
+
+    #[test]
+    fn parse_loose_list_and_quote() {
+        let blocks = parse("- first\n\n  second\n\n> quoted\n>\n> more");
+
+        let Block::BulletedListItem { bulleted_list_item } = &blocks[0] else {
+            panic!("unexpected block");
+        };
+        assert_eq!(plain_text(&bulleted_list_item.rich_text), "first");
+        assert_eq!(bulleted_list_item.children.as_ref().unwrap().len(), 1);
+
+        let Block::Quote { quote } = &blocks[1] else {
+            panic!("unexpected block");
+        };
+        assert_eq!(plain_text(&quote.rich_text), "quoted");
+        assert_eq!(quote.children.as_ref().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn parse_code_block() {
+        let blocks = parse("```rs\nfn main() {}\n```\n\n```\nplain\n```");
+
+        let Block::Code { code } = &blocks[0] else {
+            panic!("unexpected block");
+        };
+        assert_eq!(code.language, Language::Rust);
+        assert_eq!(plain_text(&code.rich_text), "fn main() {}");
+
+        let Block::Code { code } = &blocks[1] else {
+            panic!("unexpected block");
+        };
+        assert_eq!(code.language, Language::PlainText);
+    }
+
+    #[test]
+    fn parse_table() {
+        let blocks = parse("| a | b |\n| --- | --- |\n| 1 | **2** |\n| 3 |");
+
+        let Block::Table { table } = &blocks[0] else {
+            panic!("unexpected block");
+        };
+
+        assert_eq!(table.table_width, 2);
+        assert!(table.has_column_header);
+
+        let rows = table.children.as_ref().unwrap();
+        assert_eq!(rows.len(), 3);
+
+        let Block::TableRow { table_row } = &rows[2] else {
+            panic!("unexpected block");
+        };
+        assert_eq!(table_row.cells.len(), 2);
+        assert_eq!(plain_text(&table_row.cells[0]), "3");
+    }
+
+    #[test]
+    fn parse_image_divider_and_equation() {
+        let blocks = parse("![logo](https://example.com/logo.png)\n\n---\n\n$$\nx^2\n$$");
+
+        assert_eq!(blocks.len(), 3);
+
+        let Block::Image { image } = &blocks[0] else {
+            panic!("unexpected block");
+        };
+        assert_eq!(image.get_url(), "https://example.com/logo.png");
+
+        assert!(matches!(blocks[1], Block::Divider { .. }));
+
+        let Block::Equation { equation } = &blocks[2] else {
+            panic!("unexpected block");
+        };
+        assert_eq!(equation.expression, "x^2");
+    }
+
+    #[test]
+    fn split_long_paragraph() {
+        let blocks = parse(&"a".repeat(MAX_TEXT_LENGTH + 1));
+
+        let Block::Paragraph { paragraph } = &blocks[0] else {
+            panic!("unexpected block");
+        };
+        assert_eq!(paragraph.rich_text.len(), 2);
+    }
+}
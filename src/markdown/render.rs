@@ -0,0 +1,799 @@
+use crate::{
+    block::{tree::notion_url, Block, TreeNode},
+    others::{
+        icon::Icon,
+        language::Language,
+        rich_text::{mention::Mention, RichText, RichTextAnnotations},
+    },
+    File,
+};
+
+/// Renders blocks (and their children) as GitHub Flavored Markdown.
+///
+/// Accepts `&[Block]`, `&[BlockResponse]` or the nodes of a [`BlockTree`](crate::block::BlockTree)
+/// (e.g. `render(&tree.children)`), see [`TreeNode`].
+///
+/// - Toggles become `<details>` elements, and callouts become quotes starting with the icon.
+/// - Code blocks are fenced with the [`Language`] of the block.
+/// - Images and files are linked with [`File::get_url`].
+///   Files sent with the File Upload API have no URL yet and are skipped.
+/// - Child pages and mentions of pages/databases are linked to `notion.so`.
+/// - Blocks that have no Markdown equivalent (e.g. breadcrumbs, tables of contents, templates)
+///   are skipped.
+///
+/// ```
+/// use notionrs::{block::{Block, ParagraphBlock}, RichText};
+///
+/// let blocks = vec![Block::Paragraph {
+///     paragraph: ParagraphBlock::new().rich_text(vec![RichText::from("Hello").bold()]),
+/// }];
+///
+/// assert_eq!(notionrs::markdown::render(&blocks), "**Hello**\n");
+/// ```
+pub fn render<N>(blocks: &[N]) -> String
+where
+    N: TreeNode,
+{
+    let mut markdown = render_blocks(blocks);
+
+    if !markdown.is_empty() {
+        markdown.push('\n');
+    }
+
+    markdown
+}
+
+/// Renders rich text as inline Markdown.
+///
+/// Bold, italic, strikethrough and code annotations are converted to Markdown syntax.
+/// Underline and colors have no Markdown equivalent and are ignored.
+///
+/// Characters that would start a block at the beginning of a line (e.g. `#`, `-`, `>` or `1.`)
+/// are escaped, so that the text stays a paragraph.
+pub fn render_rich_text(rich_text: &[RichText]) -> String {
+    let markdown = rich_text
+        .iter()
+        .map(render_rich_text_item)
+        .collect::<String>();
+
+    markdown
+        .split('\n')
+        .map(escape_line_start)
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn render_blocks<N>(blocks: &[N]) -> String
+where
+    N: TreeNode,
+{
+    let mut markdown = String::new();
+    let mut previous: Option<&Block> = None;
+    let mut number = 0;
+
+    for node in blocks {
+        let block = node.block();
+
+        number = match block {
+            Block::NumberedListItem { .. } => number + 1,
+            _ => 0,
+        };
+
+        let Some(rendered) = render_block(node, number) else {
+            continue;
+        };
+
+        if let Some(previous) = previous {
+            // Keep list items together so they form a tight list.
+            if is_list_item(previous) && is_list_item(block) {
+                markdown.push('\n');
+            } else {
+                markdown.push_str("\n\n");
+            }
+        }
+
+        markdown.push_str(&rendered);
+        previous = Some(block);
+    }
+
+    markdown
+}
+
+fn render_block<N>(node: &N, number: usize) -> Option<String>
+where
+    N: TreeNode,
+{
+    let children = render_blocks(node.children());
+
+    let markdown = match node.block() {
+        Block::Paragraph { paragraph } => {
+            let text = render_rich_text(&paragraph.rich_text);
+
+            // Nested blocks are indented under the paragraph, as Notion shows them.
+            match (text.is_empty(), children.is_empty()) {
+                (_, true) => text,
+                (true, false) => children,
+                (false, false) => format!("{}\n\n{}", text, indent(&children, "  ")),
+            }
+        }
+        Block::Heading1 { heading_1 } => heading("#", &heading_1.rich_text, children),
+        Block::Heading2 { heading_2 } => heading("##", &heading_2.rich_text, children),
+        Block::Heading3 { heading_3 } => heading("###", &heading_3.rich_text, children),
+        Block::BulletedListItem { bulleted_list_item } => {
+            list_item("- ", &bulleted_list_item.rich_text, children)
+        }
+        Block::NumberedListItem { numbered_list_item } => list_item(
+            &format!("{}. ", number),
+            &numbered_list_item.rich_text,
+            children,
+        ),
+        Block::ToDo { to_do } => {
+            let marker = if to_do.checked { "- [x] " } else { "- [ ] " };
+            list_item(marker, &to_do.rich_text, children)
+        }
+        Block::Toggle { toggle } => format!(
+            "<details>\n<summary>{}</summary>\n\n{}\n\n</details>",
+            render_rich_text(&toggle.rich_text),
+            children
+        ),
+        Block::Quote { quote } => blockquote(render_rich_text(&quote.rich_text), children),
+        Block::Callout { callout } => {
+            let text = render_rich_text(&callout.rich_text);
+
+            let text = match &callout.icon {
+                Icon::Emoji(emoji) => format!("{} {}", emoji.emoji, text),
+                Icon::File(_) => text,
+            };
+
+            blockquote(text, children)
+        }
+        Block::Code { code } => {
+            let content = code
+                .rich_text
+                .iter()
+                .map(|rich_text| rich_text.to_string())
+                .collect::<String>();
+
+            let language = match code.language {
+                Language::PlainText => String::new(),
+                language => language.to_string().replace(' ', "-"),
+            };
+
+            let fence = "`".repeat(longest_backtick_run(&content).max(2) + 1);
+
+            format!("{fence}{language}\n{content}\n{fence}")
+        }
+        Block::Equation { equation } => format!("$$\n{}\n$$", equation.expression),
+        Block::Divider { .. } => "---".to_string(),
+        Block::Table { table } => {
+            let rows = node
+                .children()
+                .iter()
+                .filter_map(|row| match row.block() {
+                    Block::TableRow { table_row } => Some(&table_row.cells),
+                    _ => None,
+                })
+                .collect::<Vec<_>>();
+
+            let width = rows
+                .iter()
+                .map(|cells| cells.len())
+                .max()
+                .unwrap_or(0)
+                .max(usize::from(table.table_width));
+
+            if rows.is_empty() || width == 0 {
+                return None;
+            }
+
+            let mut lines = rows
+                .iter()
+                .map(|cells| table_row(cells, width))
+                .collect::<Vec<_>>();
+
+            // GFM tables always have a header row, so the first row is used even if the
+            // table has no column header.
+            lines.insert(1, format!("|{}", " --- |".repeat(width)));
+
+            lines.join("\n")
+        }
+        Block::Image { image } => {
+            let url = image.get_url();
+
+            if url.is_empty() {
+                return None;
+            }
+
+            let alt = image
+                .get_caption()
+                .map(plain_text)
+                .unwrap_or_default()
+                .replace(['[', ']'], "");
+
+            format!("![{}]({})", alt, destination(&url))
+        }
+        Block::Audio { audio: file }
+        | Block::File { file }
+        | Block::Pdf { pdf: file }
+        | Block::Video { video: file } => file_link(file)?,
+        Block::Bookmark { bookmark } => {
+            let caption = render_rich_text(&bookmark.caption);
+
+            if caption.is_empty() {
+                autolink(&bookmark.url)
+            } else {
+                format!("[{}]({})", caption, destination(&bookmark.url))
+            }
+        }
+        Block::Embed { embed } => autolink(&embed.url),
+        Block::LinkPreview { link_preview } => autolink(&link_preview.url),
+        Block::ChildPage { child_page } => page_link(&child_page.title, node.id()),
+        Block::ChildDatabase { child_database } => page_link(&child_database.title, node.id()),
+        Block::LinkToPage { link_to_page } => format!("<{}>", notion_url(link_to_page.get_id()?)),
+        Block::ColumnList { .. } | Block::Column { .. } | Block::SyncedBlock { .. } => children,
+        Block::Breadcrumb { .. }
+        | Block::TableOfContents { .. }
+        | Block::TableRow { .. }
+        | Block::Template { .. }
+        | Block::Unsupported { .. }
+        | Block::Unknown(_) => return None,
+    };
+
+    (!markdown.is_empty()).then_some(markdown)
+}
+
+fn is_list_item(block: &Block) -> bool {
+    matches!(
+        block,
+        Block::BulletedListItem { .. } | Block::NumberedListItem { .. } | Block::ToDo { .. }
+    )
+}
+
+fn heading(marker: &str, rich_text: &[RichText], children: String) -> String {
+    let heading = format!("{} {}", marker, render_rich_text(rich_text));
+
+    // The children of a toggleable heading follow the heading.
+    if children.is_empty() {
+        heading
+    } else {
+        format!("{}\n\n{}", heading, children)
+    }
+}
+
+fn list_item(marker: &str, rich_text: &[RichText], children: String) -> String {
+    let text = indent(&render_rich_text(rich_text), &" ".repeat(marker.len()));
+
+    let mut item = format!("{}{}", marker, text.trim_start());
+
+    if !children.is_empty() {
+        item.push('\n');
+        item.push_str(&indent(&children, &" ".repeat(marker.len())));
+    }
+
+    item
+}
+
+fn blockquote(text: String, children: String) -> String {
+    let body = match (text.is_empty(), children.is_empty()) {
+        (_, true) => text,
+        (true, false) => children,
+        (false, false) => format!("{}\n\n{}", text, children),
+    };
+
+    indent(&body, "> ")
+}
+
+/// Prefixes every line with `prefix`. Blank lines get the prefix without trailing spaces.
+fn indent(text: &str, prefix: &str) -> String {
+    text.split('\n')
+        .map(|line| {
+            if line.is_empty() {
+                prefix.trim_end().to_string()
+            } else {
+                format!("{}{}", prefix, line)
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn table_row(cells: &[Vec<RichText>], width: usize) -> String {
+    let mut row = String::from("|");
+
+    for index in 0..width {
+        let cell = cells
+            .get(index)
+            .map(|cell| render_rich_text(cell))
+            .unwrap_or_default()
+            .replace("\\\n", "<br>")
+            .replace('|', "\\|");
+
+        row.push_str(&format!(" {} |", cell));
+    }
+
+    row
+}
+
+fn file_link(file: &File) -> Option<String> {
+    let url = file.get_url();
+
+    if url.is_empty() {
+        return None;
+    }
+
+    let label = file
+        .get_caption()
+        .map(plain_text)
+        .filter(|caption| !caption.is_empty())
+        .or_else(|| file.get_name().map(String::from))
+        .unwrap_or_else(|| url.clone());
+
+    Some(format!("[{}]({})", escape(&label), destination(&url)))
+}
+
+fn page_link(title: &str, id: Option<&str>) -> String {
+    match id {
+        Some(id) => format!("[{}]({})", escape(title), notion_url(id)),
+        None => escape(title),
+    }
+}
+
+fn plain_text(rich_text: &[RichText]) -> String {
+    rich_text
+        .iter()
+        .map(|rich_text| rich_text.to_string())
+        .collect()
+}
+
+fn render_rich_text_item(rich_text: &RichText) -> String {
+    match rich_text {
+        RichText::Text {
+            text,
+            annotations,
+            href,
+            ..
+        } => {
+            let content = if annotations.code {
+                code_span(&text.content)
+            } else {
+                escape(&text.content)
+            };
+
+            let url = href
+                .as_deref()
+                .or_else(|| text.link.as_ref().map(|link| link.url.as_str()));
+
+            decorate(content, annotations, url)
+        }
+        RichText::Mention {
+            mention,
+            annotations,
+            plain_text,
+            href,
+        } => {
+            let url = href.clone().or_else(|| match mention {
+                Mention::Page(page) => Some(notion_url(&page.id)),
+                Mention::Database(database) => Some(notion_url(&database.id)),
+                Mention::LinkPreview(link_preview) => Some(link_preview.url.clone()),
+                Mention::LinkMention(link_mention) => link_mention.href.clone(),
+                _ => None,
+            });
+
+            decorate(escape(plain_text), annotations, url.as_deref())
+        }
+        RichText::Equation {
+            equation,
+            annotations,
+            href,
+            ..
+        } => decorate(
+            format!("${}$", equation.expression),
+            annotations,
+            href.as_deref(),
+        ),
+    }
+}
+
+/// Wraps the content with the Markdown syntax of the annotations and the link.
+/// Surrounding whitespace is kept outside of the delimiters, as `** bold **` is not bold.
+fn decorate(content: String, annotations: &RichTextAnnotations, url: Option<&str>) -> String {
+    let trimmed = content.trim();
+
+    if trimmed.is_empty() {
+        return content;
+    }
+
+    let mut decorated = trimmed.to_string();
+
+    if annotations.strikethrough {
+        decorated = format!("~~{}~~", decorated);
+    }
+
+    if annotations.italic {
+        decorated = format!("*{}*", decorated);
+    }
+
+    if annotations.bold {
+        decorated = format!("**{}**", decorated);
+    }
+
+    if let Some(url) = url {
+        decorated = format!("[{}]({})", decorated, destination(url));
+    }
+
+    let start = content.len() - content.trim_start().len();
+    let end = content.trim_end().len();
+
+    format!("{}{}{}", &content[..start], decorated, &content[end..])
+}
+
+/// The destination of a link or an image. URLs with spaces or parentheses are wrapped
+/// in `<...>`, so that they don't end the link early.
+fn destination(url: &str) -> String {
+    if url.contains([' ', '(', ')', '<', '>', '\n']) {
+        format!("<{}>", encode_angle_brackets(url))
+    } else {
+        url.to_string()
+    }
+}
+
+/// An autolink, e.g. `<https://example.com>`. Autolinks cannot contain spaces.
+fn autolink(url: &str) -> String {
+    format!("<{}>", encode_angle_brackets(url).replace(' ', "%20"))
+}
+
+fn encode_angle_brackets(url: &str) -> String {
+    url.replace('<', "%3C")
+        .replace('>', "%3E")
+        .replace('\n', "%0A")
+}
+
+fn code_span(content: &str) -> String {
+    let fence = "`".repeat(longest_backtick_run(content) + 1);
+
+    if content.starts_with('`') || content.ends_with('`') {
+        format!("{fence} {content} {fence}")
+    } else {
+        format!("{fence}{content}{fence}")
+    }
+}
+
+fn longest_backtick_run(content: &str) -> usize {
+    content.split(|c| c != '`').map(str::len).max().unwrap_or(0)
+}
+
+/// Escapes the characters that would otherwise be interpreted as Markdown,
+/// and turns line breaks into hard line breaks.
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+
+    for c in text.chars() {
+        match c {
+            '\\' | '`' | '*' | '_' | '[' | ']' | '<' | '~' | '$' => {
+                escaped.push('\\');
+                escaped.push(c);
+            }
+            '\n' => escaped.push_str("\\\n"),
+            _ => escaped.push(c),
+        }
+    }
+
+    escaped
+}
+
+/// Escapes a marker at the start of a line that would turn the line into a heading,
+/// a list item, a quote or the underline of a heading.
+fn escape_line_start(line: &str) -> String {
+    let start = line.len() - line.trim_start_matches([' ', '\t']).len();
+    let rest = &line[start..];
+
+    let marker = match rest.chars().next() {
+        Some('#' | '-' | '+' | '=' | '>') => Some(start),
+        Some(c) if c.is_ascii_digit() => {
+            let digits = rest.len() - rest.trim_start_matches(|c: char| c.is_ascii_digit()).len();
+
+            rest[digits..]
+                .starts_with(['.', ')'])
+                .then_some(start + digits)
+        }
+        _ => None,
+    };
+
+    match marker {
+        Some(index) => format!("{}\\{}", &line[..index], &line[index..]),
+        None => line.to_string(),
+    }
+}
+
+// # --------------------------------------------------------------------------------
+//
+// unit test
+//
+// # --------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod unit_tests {
+    use super::*;
+    use crate::block::{
+        BlockNode, BulletedListItemBlock, CalloutBlock, CodeBlock, NumberedListItemBlock,
+        ParagraphBlock, TableBlock, TableRowBlock, ToDoBlock, ToggleBlock,
+    };
+
+    fn paragraph(text: &str) -> Block {
+        Block::Paragraph {
+            paragraph: ParagraphBlock::new().rich_text(vec![RichText::from(text)]),
+        }
+    }
+
+    /// A node of a block tree, as returned by `get_block_tree`.
+    fn node(block: Block, children: Vec<BlockNode>) -> BlockNode {
+        let mut response = serde_json::json!({
+            "object": "block",
+            "id": "c02fc1d3-db8b-45c5-a222-27595b15aea7",
+            "parent": { "type": "page_id", "page_id": "59833787-2cf9-4fdf-8782-e53db20768a5" },
+            "created_time": "2022-03-01T19:05:00.000Z",
+            "last_edited_time": "2022-03-01T19:05:00.000Z",
+            "created_by": { "object": "user", "id": "ee5f0f84-409a-440f-983a-a5315961c6e4" },
+            "last_edited_by": { "object": "user", "id": "ee5f0f84-409a-440f-983a-a5315961c6e4" },
+            "has_children": !children.is_empty(),
+            "archived": false,
+            "in_trash": false,
+        });
+
+        if let (Some(response), serde_json::Value::Object(block)) = (
+            response.as_object_mut(),
+            serde_json::to_value(block).unwrap(),
+        ) {
+            response.extend(block);
+        }
+
+        BlockNode {
+            block: serde_json::from_value(response).unwrap(),
+            children,
+        }
+    }
+
+    #[test]
+    fn render_rich_text_annotations() {
+        let rich_text = vec![
+            RichText::from("plain "),
+            RichText::from("bold ").bold(),
+            RichText::from("both").bold().italic(),
+            RichText::from(" "),
+            RichText::from("x*y").code(),
+            RichText::from(" "),
+            RichText::from("gone").strikethrough(),
+            RichText::from(" "),
+            RichText::from("link").href("https://example.com"),
+            RichText::from(" 2*3"),
+        ];
+
+        assert_eq!(
+            render_rich_text(&rich_text),
+            "plain **bold** ***both*** `x*y` ~~gone~~ [link](https://example.com) 2\\*3"
+        );
+    }
+
+    #[test]
+    fn render_mentions() {
+        use crate::others::rich_text::mention::{
+            PageMention, TemplateMention, TemplateMentionUser,
+        };
+
+        let mention = |mention: Mention, plain_text: &str, bold: bool| RichText::Mention {
+            mention,
+            annotations: RichTextAnnotations {
+                bold,
+                ..Default::default()
+            },
+            plain_text: plain_text.to_string(),
+            href: None,
+        };
+
+        let rich_text = vec![
+            mention(
+                Mention::Page(PageMention {
+                    id: "a1b2-c3d4".to_string(),
+                }),
+                "My Page",
+                false,
+            ),
+            RichText::from(" "),
+            mention(
+                Mention::TemplateMention(TemplateMention::TemplateMentionUser(
+                    TemplateMentionUser::Me,
+                )),
+                "@me",
+                true,
+            ),
+        ];
+
+        assert_eq!(
+            render_rich_text(&rich_text),
+            "[My Page](https://www.notion.so/a1b2c3d4) **@me**"
+        );
+    }
+
+    #[test]
+    fn render_nested_lists() {
+        let blocks = vec![
+            paragraph("Intro"),
+            Block::BulletedListItem {
+                bulleted_list_item: BulletedListItemBlock::new()
+                    .rich_text(vec![RichText::from("a")])
+                    .children(vec![
+                        Block::NumberedListItem {
+                            numbered_list_item: NumberedListItemBlock::new()
+                                .rich_text(vec![RichText::from("one")]),
+                        },
+                        Block::NumberedListItem {
+                            numbered_list_item: NumberedListItemBlock::new()
+                                .rich_text(vec![RichText::from("two")]),
+                        },
+                    ]),
+            },
+            Block::ToDo {
+                to_do: ToDoBlock::new()
+                    .rich_text(vec![RichText::from("done")])
+                    .checked(true),
+            },
+        ];
+
+        assert_eq!(
+            render(&blocks),
+            "Intro\n\n- a\n  1. one\n  2. two\n- [x] done\n"
+        );
+    }
+
+    #[test]
+    fn render_nested_paragraph() {
+        let nodes = vec![node(
+            paragraph("parent"),
+            vec![node(paragraph("NESTED CHILD"), vec![])],
+        )];
+
+        assert_eq!(render(&nodes), "parent\n\n  NESTED CHILD\n");
+    }
+
+    #[test]
+    fn escape_block_markers() {
+        let blocks = vec![
+            paragraph("# not heading\n- not list\n+ not list"),
+            paragraph("> not quote\n1. not list\n2) not list\n==="),
+            paragraph("2024 was #1 - 2. place"),
+        ];
+
+        let markdown = render(&blocks);
+
+        assert_eq!(
+            markdown,
+            "\\# not heading\\\n\\- not list\\\n\\+ not list\n\n\\> not quote\\\n1\\. not list\\\n2\\) not list\\\n\\===\n\n2024 was #1 - 2. place\n"
+        );
+
+        #[cfg(feature = "markdown")]
+        {
+            let parsed = crate::markdown::parse(&markdown);
+
+            assert_eq!(parsed.len(), 3);
+
+            for (block, expected) in parsed.iter().zip(&blocks) {
+                match (block, expected) {
+                    (
+                        Block::Paragraph { paragraph },
+                        Block::Paragraph {
+                            paragraph: expected,
+                        },
+                    ) => assert_eq!(
+                        plain_text(&paragraph.rich_text),
+                        plain_text(&expected.rich_text)
+                    ),
+                    _ => panic!("not a paragraph: {:?}", block),
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn render_toggle_callout_and_code() {
+        let blocks = vec![
+            Block::Toggle {
+                toggle: ToggleBlock::new()
+                    .rich_text(vec![RichText::from("More")])
+                    .children(vec![paragraph("Hidden")]),
+            },
+            Block::Callout {
+                callout: CalloutBlock::new()
+                    .rich_text(vec![RichText::from("Note")])
+                    .icon_emoji('💡'),
+            },
+            Block::Code {
+                code: CodeBlock::new()
+                    .rich_text(vec![RichText::from("fn main() {}")])
+                    .lnaguage(Language::Rust),
+            },
+        ];
+
+        assert_eq!(
+            render(&blocks),
+            "<details>\n<summary>More</summary>\n\nHidden\n\n</details>\n\n> 💡 Note\n\n```rust\nfn main() {}\n```\n"
+        );
+    }
+
+    #[test]
+    fn render_table() {
+        let row = |cells: [&str; 2]| Block::TableRow {
+            table_row: TableRowBlock::new()
+                .cells(cells.iter().map(|c| vec![RichText::from(*c)]).collect()),
+        };
+
+        let blocks = vec![Block::Table {
+            table: TableBlock::new()
+                .table_width(2)
+                .has_column_header(true)
+                .children(vec![row(["a", "b"]), row(["1", "x|y"])]),
+        }];
+
+        assert_eq!(render(&blocks), "| a | b |\n| --- | --- |\n| 1 | x\\|y |\n");
+    }
+
+    #[test]
+    fn render_urls_with_spaces_and_parentheses() {
+        let rich_text =
+            vec![RichText::from("wiki").href("https://en.wikipedia.org/wiki/Rust_(language)")];
+
+        assert_eq!(
+            render_rich_text(&rich_text),
+            "[wiki](<https://en.wikipedia.org/wiki/Rust_(language)>)"
+        );
+
+        #[cfg(feature = "markdown")]
+        {
+            let parsed = crate::markdown::parse(&render_rich_text(&rich_text));
+
+            let Block::Paragraph { paragraph } = &parsed[0] else {
+                panic!("not a paragraph: {:?}", parsed);
+            };
+
+            assert_eq!(paragraph.rich_text, rich_text);
+        }
+
+        let blocks = vec![
+            Block::Image {
+                image: File::new().url("https://example.com/my image.png"),
+            },
+            Block::Bookmark {
+                bookmark: crate::block::BookmarkBlock::new().url("https://example.com/a b<c>"),
+            },
+        ];
+
+        assert_eq!(
+            render(&blocks),
+            "![](<https://example.com/my image.png>)\n\n<https://example.com/a%20b%3Cc%3E>\n"
+        );
+    }
+
+    #[test]
+    fn render_files_and_equation() {
+        let blocks = vec![
+            Block::Image {
+                image: File::new()
+                    .url("https://example.com/a.png")
+                    .caption(vec![RichText::from("A")]),
+            },
+            Block::Pdf {
+                pdf: File::new().url("https://example.com/a.pdf").name("a.pdf"),
+            },
+            Block::File {
+                file: File::file_upload("upload"),
+            },
+            Block::Equation {
+                equation: crate::block::EquationBlock::new().expression("x^2"),
+            },
+        ];
+
+        assert_eq!(
+            render(&blocks),
+            "![A](https://example.com/a.png)\n\n[a.pdf](https://example.com/a.pdf)\n\n$$\nx^2\n$$\n"
+        );
+    }
+}
@@ -27,6 +27,33 @@ pub enum Color {
     YellowBackground,
 }
 
+impl Color {
+    /// The name of the color as used by the Notion API (e.g. `blue_background`).
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Color::Default => "default",
+            Color::Blue => "blue",
+            Color::Brown => "brown",
+            Color::Gray => "gray",
+            Color::Green => "green",
+            Color::Orange => "orange",
+            Color::Pink => "pink",
+            Color::Purple => "purple",
+            Color::Red => "red",
+            Color::Yellow => "yellow",
+            Color::BlueBackground => "blue_background",
+            Color::BrownBackground => "brown_background",
+            Color::GrayBackground => "gray_background",
+            Color::GreenBackground => "green_background",
+            Color::OrangeBackground => "orange_background",
+            Color::PinkBackground => "pink_background",
+            Color::PurpleBackground => "purple_background",
+            Color::RedBackground => "red_background",
+            Color::YellowBackground => "yellow_background",
+        }
+    }
+}
+
 // # --------------------------------------------------------------------------------
 //
 // macro
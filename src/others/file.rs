@@ -1,8 +1,9 @@
 use serde::{Deserialize, Serialize};
 
-/// **Note**: The Notion API does not support file uploads.
-/// Therefore, for creating or updating, you are only allowed to
-/// specify an external URL using the `ExternalFile` variant of the enum.
+/// **Note**: Files hosted by Notion (`UploadedFile`) cannot be created directly.
+/// For creating or updating, specify an external URL using the `ExternalFile` variant,
+/// or a file sent with [`Client::upload_file`](crate::Client::upload_file)
+/// using the `FileUpload` variant.
 ///
 /// <https://developers.notion.com/reference/file-object>
 ///
@@ -36,16 +37,28 @@ use serde::{Deserialize, Serialize};
 ///     }
 /// }
 /// ```
+///
+/// A file sent with the File Upload API is attached by its ID.
+///
+/// ```json
+/// {
+///     "type": "file_upload",
+///     "file_upload": {
+///         "id": "43833259-72ae-404e-8441-b6577f3159b4"
+///     }
+/// }
+/// ```
 #[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Clone)]
 #[serde(untagged)]
 pub enum File {
     External(ExternalFile),
     Uploaded(UploadedFile),
+    FileUpload(FileUploadFile),
 }
 
 impl File {
     /// Note that while `File` is an enum, this function will return the `External` variant of `File`.
-    /// This is because the Notion API does not support uploading new files.
+    /// Use [`File::file_upload`] to attach a file sent with the File Upload API.
     pub fn new() -> Self {
         File::External(ExternalFile {
             r#type: "external".to_string(),
@@ -55,32 +68,79 @@ impl File {
         })
     }
 
+    /// Returns the `FileUpload` variant of `File`, which refers to a file sent with
+    /// [`Client::upload_file`](crate::Client::upload_file).
+    ///
+    /// ```no_run
+    /// # async fn run() -> Result<(), notionrs::error::Error> {
+    /// let client = notionrs::Client::new();
+    ///
+    /// let upload = client.upload_file().path("./report.pdf").send().await?;
+    ///
+    /// let file = notionrs::File::file_upload(upload.id).name("report.pdf");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn file_upload<T>(file_upload_id: T) -> Self
+    where
+        T: AsRef<str>,
+    {
+        File::FileUpload(FileUploadFile::from(file_upload_id))
+    }
+
     /// This utility returns the URL regardless of whether the File variant is External or Uploaded.
     /// (You can retrieve the URL without having to check the variant).
+    ///
+    /// The `FileUpload` variant has no URL, so an empty string is returned.
     pub fn get_url(&self) -> String {
         match self {
             File::External(f) => f.external.url.clone(),
             File::Uploaded(f) => f.file.url.clone(),
+            File::FileUpload(_) => String::new(),
         }
     }
 
-    /// This function can only be used if the File variant is External.
+    /// Returns the name of the file regardless of the variant.
+    pub fn get_name(&self) -> Option<&str> {
+        match self {
+            File::External(f) => f.name.as_deref(),
+            File::Uploaded(f) => f.name.as_deref(),
+            File::FileUpload(f) => f.name.as_deref(),
+        }
+    }
+
+    /// Returns the caption of the file regardless of the variant.
+    pub fn get_caption(&self) -> Option<&[crate::others::rich_text::RichText]> {
+        match self {
+            File::External(f) => f.caption.as_deref(),
+            File::Uploaded(f) => f.caption.as_deref(),
+            File::FileUpload(f) => f.caption.as_deref(),
+        }
+    }
+
+    /// This function can only be used if the File variant is External or FileUpload.
     /// If the File variant is Uploaded, it returns Self without changing the value.
     pub fn name<T>(mut self, name: T) -> Self
     where
         T: AsRef<str>,
     {
-        if let File::External(ref mut external) = self {
-            external.name = Some(name.as_ref().to_string())
+        match self {
+            File::External(ref mut external) => external.name = Some(name.as_ref().to_string()),
+            File::FileUpload(ref mut file_upload) => {
+                file_upload.name = Some(name.as_ref().to_string())
+            }
+            File::Uploaded(_) => {}
         }
         self
     }
 
-    /// This function can only be used if the File variant is External.
+    /// This function can only be used if the File variant is External or FileUpload.
     /// If the File variant is Uploaded, it returns Self without changing the value.
     pub fn caption(mut self, caption: Vec<crate::others::rich_text::RichText>) -> Self {
-        if let File::External(ref mut external) = self {
-            external.caption = Some(caption)
+        match self {
+            File::External(ref mut external) => external.caption = Some(caption),
+            File::FileUpload(ref mut file_upload) => file_upload.caption = Some(caption),
+            File::Uploaded(_) => {}
         }
         self
     }
@@ -109,6 +169,7 @@ impl std::fmt::Display for File {
         match self {
             File::External(file) => write!(f, "{}", file),
             File::Uploaded(file) => write!(f, "{}", file),
+            File::FileUpload(file) => write!(f, "{}", file),
         }
     }
 }
@@ -214,7 +275,7 @@ impl std::fmt::Display for ExternalFileParameter {
 ///
 /// **This struct is read-only.**
 ///
-/// **Note**: The Notion API does not support file uploads.
+/// Files sent with the File Upload API also become this object once they are attached.
 ///
 /// When a file is uploaded to Notion, it becomes an object as shown below.
 ///
@@ -264,6 +325,71 @@ impl std::fmt::Display for UploadedFileParameter {
     }
 }
 
+// # --------------------------------------------------------------------------------
+//
+// file_upload (uploaded via the File Upload API)
+//
+// # --------------------------------------------------------------------------------
+
+/// ## FileUploadFile
+///
+/// Refers to a file sent with the File Upload API by its ID.
+/// It can be used in file-type blocks (image, pdf, file, ...), `Files` properties,
+/// icons and page covers.
+///
+/// ```json
+/// {
+///     "type": "file_upload",
+///     "file_upload": {
+///         "id": "43833259-72ae-404e-8441-b6577f3159b4"
+///     }
+/// }
+/// ```
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Clone)]
+pub struct FileUploadFile {
+    /// always "file_upload"
+    pub r#type: String,
+
+    /// file_upload
+    pub file_upload: FileUploadFileParameter,
+
+    /// File caption (can only be set in the file type block or database properties)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// File caption (setting is available only in the file type block)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption: Option<Vec<crate::others::rich_text::RichText>>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Default, PartialEq, Eq, Clone)]
+pub struct FileUploadFileParameter {
+    /// ID of the file upload
+    pub id: String,
+}
+
+impl<T> From<T> for FileUploadFile
+where
+    T: AsRef<str>,
+{
+    fn from(file_upload_id: T) -> Self {
+        Self {
+            r#type: "file_upload".to_string(),
+            file_upload: FileUploadFileParameter {
+                id: file_upload_id.as_ref().to_string(),
+            },
+            name: None,
+            caption: None,
+        }
+    }
+}
+
+impl std::fmt::Display for FileUploadFile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.file_upload.id)
+    }
+}
+
 // # --------------------------------------------------------------------------------
 //
 // unit test
@@ -319,4 +445,18 @@ mod unit_tests {
         );
         assert_eq!(file.file.expiry_time, "2024-04-04T10:45:54.308Z");
     }
+
+    #[test]
+    fn serialize_file_upload() {
+        let file = File::file_upload("43833259-72ae-404e-8441-b6577f3159b4").name("report.pdf");
+
+        assert_eq!(
+            serde_json::to_string(&file).unwrap(),
+            r#"{"type":"file_upload","file_upload":{"id":"43833259-72ae-404e-8441-b6577f3159b4"},"name":"report.pdf"}"#
+        );
+
+        let file = serde_json::from_str::<File>(&serde_json::to_string(&file).unwrap()).unwrap();
+
+        assert!(matches!(file, File::FileUpload(_)));
+    }
 }
@@ -0,0 +1,287 @@
+//! IDs of Notion objects.
+//!
+//! Each kind of object has its own ID type, so that e.g. a database ID cannot be passed
+//! where a page ID is expected. IDs are parsed from any of the forms Notion shows them in:
+//!
+//! - a UUID with dashes: `1429989f-e8ac-4eff-bc8f-57f56486db54`
+//! - 32 hex characters: `1429989fe8ac4effbc8f57f56486db54`
+//! - a share URL: `https://www.notion.so/workspace/My-Page-1429989fe8ac4effbc8f57f56486db54`
+//!
+//! and are displayed (and sent to the API) as a lowercase UUID with dashes.
+
+use crate::error::IdError;
+
+macro_rules! notion_id {
+    ($(#[$meta:meta])* $name:ident, $from_url:expr) => {
+        $(#[$meta])*
+        #[derive(Debug, Default, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+        #[derive(serde::Serialize, serde::Deserialize)]
+        #[serde(from = "String", into = "String")]
+        pub struct $name(String);
+
+        impl $name {
+            /// The ID as a string.
+            pub fn as_str(&self) -> &str {
+                &self.0
+            }
+
+            /// Converts a string that is not a valid ID as it is,
+            /// so that the Notion API reports the invalid ID.
+            fn lenient(id: &str) -> Self {
+                Self(parse(id, $from_url).unwrap_or_else(|| id.to_string()))
+            }
+        }
+
+        impl std::str::FromStr for $name {
+            type Err = IdError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                parse(s, $from_url).map(Self).ok_or_else(|| IdError {
+                    input: s.to_string(),
+                })
+            }
+        }
+
+        impl std::fmt::Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, "{}", self.0)
+            }
+        }
+
+        impl AsRef<str> for $name {
+            fn as_ref(&self) -> &str {
+                &self.0
+            }
+        }
+
+        impl From<&str> for $name {
+            fn from(id: &str) -> Self {
+                Self::lenient(id)
+            }
+        }
+
+        impl From<String> for $name {
+            fn from(id: String) -> Self {
+                Self::lenient(&id)
+            }
+        }
+
+        impl From<&String> for $name {
+            fn from(id: &String) -> Self {
+                Self::lenient(id)
+            }
+        }
+
+        impl From<&$name> for $name {
+            fn from(id: &$name) -> Self {
+                id.clone()
+            }
+        }
+
+        impl From<$name> for String {
+            fn from(id: $name) -> Self {
+                id.0
+            }
+        }
+
+        impl PartialEq<str> for $name {
+            fn eq(&self, other: &str) -> bool {
+                parse(other, $from_url).as_deref().unwrap_or(other) == self.0
+            }
+        }
+
+        impl PartialEq<&str> for $name {
+            fn eq(&self, other: &&str) -> bool {
+                self == *other
+            }
+        }
+    };
+}
+
+notion_id!(
+    /// The ID of a page. In a URL, `?p=` (a page opened as a peek) takes precedence over the path.
+    PageId,
+    UrlPart::Page
+);
+
+notion_id!(
+    /// The ID of a database. In a URL, the ID is taken from the path (`?v=` is the ID of a view).
+    DatabaseId,
+    UrlPart::Path
+);
+
+notion_id!(
+    /// The ID of a block. Pages and databases are also blocks.
+    /// In a URL, the `#` anchor (a link to a block) takes precedence over the page.
+    BlockId,
+    UrlPart::Block
+);
+
+notion_id!(
+    /// The ID of a user.
+    UserId,
+    UrlPart::Path
+);
+
+impl From<PageId> for BlockId {
+    fn from(id: PageId) -> Self {
+        Self(id.0)
+    }
+}
+
+impl From<&PageId> for BlockId {
+    fn from(id: &PageId) -> Self {
+        Self(id.0.clone())
+    }
+}
+
+impl From<DatabaseId> for BlockId {
+    fn from(id: DatabaseId) -> Self {
+        Self(id.0)
+    }
+}
+
+impl From<&DatabaseId> for BlockId {
+    fn from(id: &DatabaseId) -> Self {
+        Self(id.0.clone())
+    }
+}
+
+/// Which part of a URL an ID is taken from.
+#[derive(Debug, Clone, Copy)]
+enum UrlPart {
+    /// The last segment of the path.
+    Path,
+
+    /// `?p=`, or the path.
+    Page,
+
+    /// The `#` anchor, `?p=`, or the path.
+    Block,
+}
+
+/// Parses an ID or a URL into a lowercase UUID with dashes.
+fn parse(input: &str, part: UrlPart) -> Option<String> {
+    let input = input.trim();
+
+    if !input.contains('/') {
+        return canonical(input);
+    }
+
+    let (rest, anchor) = match input.split_once('#') {
+        Some((rest, anchor)) => (rest, Some(anchor)),
+        None => (input, None),
+    };
+
+    let (path, query) = match rest.split_once('?') {
+        Some((path, query)) => (path, Some(query)),
+        None => (rest, None),
+    };
+
+    let peek = query
+        .into_iter()
+        .flat_map(|query| query.split('&'))
+        .filter_map(|pair| pair.split_once('='))
+        .find(|(key, _)| *key == "p")
+        .map(|(_, value)| value);
+
+    let from_path = || {
+        path.trim_end_matches('/')
+            .rsplit('/')
+            .next()
+            .and_then(|segment| {
+                canonical(segment).or_else(|| {
+                    // A slug followed by the ID, e.g. `My-Page-1429989fe8ac4effbc8f57f56486db54`
+                    let start = segment.len().checked_sub(32)?;
+                    canonical(segment.get(start..)?)
+                })
+            })
+    };
+
+    match part {
+        UrlPart::Path => from_path(),
+        UrlPart::Page => peek.and_then(canonical).or_else(from_path),
+        UrlPart::Block => anchor
+            .and_then(canonical)
+            .or_else(|| peek.and_then(canonical))
+            .or_else(from_path),
+    }
+}
+
+/// Converts 32 hex characters, with or without dashes, into a lowercase UUID with dashes.
+fn canonical(id: &str) -> Option<String> {
+    let hex = id.replace('-', "").to_ascii_lowercase();
+
+    if hex.len() != 32 || !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+
+    Some(format!(
+        "{}-{}-{}-{}-{}",
+        &hex[0..8],
+        &hex[8..12],
+        &hex[12..16],
+        &hex[16..20],
+        &hex[20..32]
+    ))
+}
+
+// # --------------------------------------------------------------------------------
+//
+// unit test
+//
+// # --------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod unit_tests {
+    use super::*;
+
+    const ID: &str = "1429989f-e8ac-4eff-bc8f-57f56486db54";
+
+    #[test]
+    fn parse_ids() {
+        for input in [
+            "1429989f-e8ac-4eff-bc8f-57f56486db54",
+            "1429989FE8AC4EFFBC8F57F56486DB54",
+            " 1429989fe8ac4effbc8f57f56486db54 ",
+            "https://www.notion.so/1429989fe8ac4effbc8f57f56486db54",
+            "https://www.notion.so/workspace/My-Page-1429989fe8ac4effbc8f57f56486db54",
+            "https://www.notion.so/workspace/My-Page-1429989fe8ac4effbc8f57f56486db54?pvs=4",
+            "https://workspace.notion.site/1429989f-e8ac-4eff-bc8f-57f56486db54/",
+            "https://www.notion.so/workspace/c6d4f5b2a1e04c8f9e3b7a2d1f0e9c8b?v=0123456789abcdef0123456789abcdef&p=1429989fe8ac4effbc8f57f56486db54",
+        ] {
+            assert_eq!(input.parse::<PageId>().unwrap().to_string(), ID, "{}", input);
+        }
+
+        assert!("not-an-id".parse::<PageId>().is_err());
+        assert!("https://www.notion.so/workspace/My-Page"
+            .parse::<PageId>()
+            .is_err());
+    }
+
+    #[test]
+    fn parse_url_by_kind() {
+        let url = "https://www.notion.so/workspace/c6d4f5b2a1e04c8f9e3b7a2d1f0e9c8b?v=0123456789abcdef0123456789abcdef&p=1429989fe8ac4effbc8f57f56486db54#5c6a28216bb14a7eb6e1c50111515c3d";
+
+        assert_eq!(
+            url.parse::<DatabaseId>().unwrap(),
+            "c6d4f5b2-a1e0-4c8f-9e3b-7a2d1f0e9c8b"
+        );
+        assert_eq!(url.parse::<PageId>().unwrap(), ID);
+        assert_eq!(
+            url.parse::<BlockId>().unwrap(),
+            "5c6a2821-6bb1-4a7e-b6e1-c50111515c3d"
+        );
+    }
+
+    #[test]
+    fn serde() {
+        let id = serde_json::from_str::<PageId>(r#""1429989fe8ac4effbc8f57f56486db54""#).unwrap();
+
+        assert_eq!(id.as_str(), ID);
+        assert_eq!(serde_json::to_string(&id).unwrap(), format!(r#""{}""#, ID));
+
+        // Invalid IDs are kept as they are.
+        assert_eq!(PageId::from("page_id").as_str(), "page_id");
+    }
+}
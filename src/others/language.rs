@@ -182,3 +182,58 @@ pub enum Language {
     #[serde(rename = "java/c/c++/c#")]
     JavaCCPlusPlusCSharp,
 }
+
+impl std::str::FromStr for Language {
+    type Err = serde_json::Error;
+
+    /// Parses a language name as used in Notion (`"rust"`, `"c++"`, `"plain text"`, ...).
+    /// Common aliases used in Markdown code fences (`rs`, `py`, `ts`, `sh`, ...) are also accepted.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let name = s.trim().to_ascii_lowercase();
+
+        let name = match name.as_str() {
+            "" | "text" | "txt" | "plain" | "plaintext" => "plain text",
+            "rs" => "rust",
+            "js" | "jsx" | "mjs" | "cjs" => "javascript",
+            "ts" | "tsx" => "typescript",
+            "py" => "python",
+            "rb" => "ruby",
+            "sh" | "zsh" | "console" => "shell",
+            "ps1" | "pwsh" => "powershell",
+            "yml" => "yaml",
+            "md" => "markdown",
+            "cpp" | "cc" | "cxx" | "hpp" => "c++",
+            "cs" | "csharp" => "c#",
+            "fs" | "fsharp" => "f#",
+            "golang" => "go",
+            "hs" => "haskell",
+            "kt" | "kts" => "kotlin",
+            "ex" | "exs" => "elixir",
+            "objc" => "objective-c",
+            "tex" => "latex",
+            "dockerfile" => "docker",
+            "make" => "makefile",
+            "proto" => "protobuf",
+            "wasm" | "wat" => "webassembly",
+            "vb" => "visual basic",
+            "gql" => "graphql",
+            "jl" => "julia",
+            "ml" => "ocaml",
+            "pl" => "perl",
+            "sol" => "solidity",
+            name => name,
+        };
+
+        serde_json::from_value(serde_json::Value::String(name.to_string()))
+    }
+}
+
+impl std::fmt::Display for Language {
+    /// Displays the language name as used in Notion (e.g. `rust`, `plain text`).
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match serde_json::to_value(self) {
+            Ok(serde_json::Value::String(name)) => write!(f, "{}", name),
+            _ => Err(std::fmt::Error),
+        }
+    }
+}
@@ -2,6 +2,7 @@ pub mod color;
 pub mod emoji;
 pub mod file;
 pub mod icon;
+pub mod id;
 pub mod language;
 pub mod parent;
 pub mod rich_text;
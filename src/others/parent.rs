@@ -1,5 +1,7 @@
 use serde::{Deserialize, Serialize};
 
+use crate::others::id::{BlockId, DatabaseId, PageId};
+
 /// <https://developers.notion.com/reference/parent-object>
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(untagged)]
@@ -15,20 +17,29 @@ pub enum Parent {
 pub struct DatabaseParent {
     /// always "database_id"
     pub r#type: String,
-    pub database_id: String,
+    pub database_id: DatabaseId,
 }
 
 impl From<&str> for DatabaseParent {
     fn from(database_id: &str) -> Self {
         Self {
             r#type: "database_id".to_string(),
-            database_id: database_id.to_string(),
+            database_id: DatabaseId::from(database_id),
         }
     }
 }
 
 impl From<String> for DatabaseParent {
     fn from(database_id: String) -> Self {
+        Self {
+            r#type: "database_id".to_string(),
+            database_id: DatabaseId::from(database_id),
+        }
+    }
+}
+
+impl From<DatabaseId> for DatabaseParent {
+    fn from(database_id: DatabaseId) -> Self {
         Self {
             r#type: "database_id".to_string(),
             database_id,
@@ -41,20 +52,29 @@ impl From<String> for DatabaseParent {
 pub struct PageParent {
     /// always "page_id"
     pub r#type: String,
-    pub page_id: String,
+    pub page_id: PageId,
 }
 
 impl From<&str> for PageParent {
     fn from(page_id: &str) -> Self {
         Self {
             r#type: "page_id".to_string(),
-            page_id: page_id.to_string(),
+            page_id: PageId::from(page_id),
         }
     }
 }
 
 impl From<String> for PageParent {
     fn from(page_id: String) -> Self {
+        Self {
+            r#type: "page_id".to_string(),
+            page_id: PageId::from(page_id),
+        }
+    }
+}
+
+impl From<PageId> for PageParent {
+    fn from(page_id: PageId) -> Self {
         Self {
             r#type: "page_id".to_string(),
             page_id,
@@ -76,20 +96,29 @@ pub struct WorkspaceParent {
 pub struct BlockParent {
     /// always "block_id"
     pub r#type: String,
-    pub block_id: String,
+    pub block_id: BlockId,
 }
 
 impl From<&str> for BlockParent {
     fn from(block_id: &str) -> Self {
         Self {
             r#type: "block_id".to_string(),
-            block_id: block_id.to_string(),
+            block_id: BlockId::from(block_id),
         }
     }
 }
 
 impl From<String> for BlockParent {
     fn from(block_id: String) -> Self {
+        Self {
+            r#type: "block_id".to_string(),
+            block_id: BlockId::from(block_id),
+        }
+    }
+}
+
+impl From<BlockId> for BlockParent {
+    fn from(block_id: BlockId) -> Self {
         Self {
             r#type: "block_id".to_string(),
             block_id,
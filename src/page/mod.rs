@@ -1,5 +1,7 @@
 pub mod page_response;
 pub mod properties;
+pub mod property_item;
+pub mod row;
 
 pub use page_response::*;
 pub use properties::*;
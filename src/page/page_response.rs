@@ -1,14 +1,20 @@
 use serde::{Deserialize, Serialize};
 
 use crate::{
+    error::PropertyError,
     others::{file::File, icon::Icon, parent::Parent},
+    page::properties::PageProperty,
     user::User,
 };
 
+/// The number of items of a `title`, `rich_text`, `relation` or `people` property
+/// that are included in a page object. Longer properties are truncated.
+const TRUNCATED_LENGTH: usize = 25;
+
 /// <https://developers.notion.com/reference/page>
 #[derive(Deserialize, Serialize, Debug)]
 pub struct PageResponse {
-    pub id: String,
+    pub id: crate::others::id::PageId,
     pub created_time: chrono::DateTime<chrono::FixedOffset>,
     pub last_edited_time: chrono::DateTime<chrono::FixedOffset>,
     pub created_by: User,
@@ -17,10 +23,275 @@ pub struct PageResponse {
     pub icon: Option<Icon>,
     pub parent: Parent,
     pub archived: bool,
-    pub properties: std::collections::HashMap<String, crate::page::properties::PageProperty>,
+    pub properties: std::collections::HashMap<String, PageProperty>,
     pub url: String,
     pub public_url: Option<String>,
     pub developer_survey: Option<String>,
     pub request_id: Option<String>,
     pub in_trash: bool,
 }
+
+impl PageResponse {
+    /// Returns the property named `name`.
+    pub fn get_property(&self, name: &str) -> Result<&PageProperty, PropertyError> {
+        self.properties
+            .get(name)
+            .ok_or_else(|| PropertyError::Missing {
+                property: name.to_string(),
+            })
+    }
+
+    /// Returns the plain text of the `title` property, whatever its name is.
+    pub fn get_title(&self) -> Option<String> {
+        self.properties
+            .values()
+            .find(|property| matches!(property, PageProperty::Title(_)))
+            .and_then(PageProperty::as_plain_text)
+    }
+
+    /// Returns the plain text of the `title` or `rich_text` property named `name`.
+    pub fn get_str(&self, name: &str) -> Result<String, PropertyError> {
+        let property = self.get_property(name)?;
+
+        property
+            .as_plain_text()
+            .ok_or_else(|| PropertyError::TypeMismatch {
+                property: name.to_string(),
+                expected: "rich_text",
+                found: property.type_name(),
+            })
+    }
+
+    /// Returns the value of the `number` property named `name`.
+    /// `None` if the number is not set.
+    pub fn get_number(&self, name: &str) -> Result<Option<f64>, PropertyError> {
+        match self.get_property(name)? {
+            PageProperty::Number(number) => Ok(number.number),
+            property => Err(PropertyError::TypeMismatch {
+                property: name.to_string(),
+                expected: "number",
+                found: property.type_name(),
+            }),
+        }
+    }
+
+    /// Re-fetches the properties that are truncated in the response,
+    /// and replaces them with the complete ones:
+    ///
+    /// - relations with more than 25 pages (`has_more` is `true`)
+    /// - `title`, `rich_text` and `people` properties with 25 or more items,
+    ///   as the page object only contains the first 25
+    pub async fn fetch_truncated_properties(
+        &mut self,
+        client: &crate::client::Client,
+    ) -> Result<(), crate::error::Error> {
+        for property in self.properties.values_mut() {
+            let (truncated, property_id) = match property {
+                PageProperty::Relation(relation) => (relation.has_more, &relation.id),
+                PageProperty::Title(title) => (title.title.len() >= TRUNCATED_LENGTH, &title.id),
+                PageProperty::RichText(rich_text) => {
+                    (rich_text.rich_text.len() >= TRUNCATED_LENGTH, &rich_text.id)
+                }
+                PageProperty::People(people) => {
+                    (people.people.len() >= TRUNCATED_LENGTH, &people.id)
+                }
+                _ => continue,
+            };
+
+            let (true, Some(property_id)) = (truncated, property_id) else {
+                continue;
+            };
+
+            *property = client
+                .get_page_property_item()
+                .page_id(&self.id)
+                .property_id(property_id)
+                .send()
+                .await?;
+        }
+
+        Ok(())
+    }
+}
+
+// # --------------------------------------------------------------------------------
+//
+// unit test
+//
+// # --------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod unit_tests {
+    use super::*;
+
+    fn page() -> PageResponse {
+        let json_data = r#"
+        {
+            "object": "page",
+            "id": "59833787-2cf9-4fdf-8782-e53db20768a5",
+            "created_time": "2022-03-01T19:05:00.000Z",
+            "last_edited_time": "2022-07-06T20:25:00.000Z",
+            "created_by": { "object": "user", "id": "ee5f0f84-409a-440f-983a-a5315961c6e4" },
+            "last_edited_by": { "object": "user", "id": "0c3e9826-b8f7-4f73-927d-2caaf86f1103" },
+            "cover": null,
+            "icon": null,
+            "parent": { "type": "database_id", "database_id": "d9824bdc-8445-4327-be8b-5b47500af6ce" },
+            "archived": false,
+            "in_trash": false,
+            "properties": {
+                "Name": { "id": "title", "type": "title", "title": [
+                    { "type": "text", "text": { "content": "Tuscan kale", "link": null },
+                      "annotations": { "bold": false, "italic": false, "strikethrough": false, "underline": false, "code": false, "color": "default" },
+                      "plain_text": "Tuscan kale", "href": null }
+                ] },
+                "Price": { "id": "a", "type": "number", "number": 2.5 },
+                "Stock": { "id": "b", "type": "number", "number": null },
+                "Description": { "id": "c", "type": "rich_text", "rich_text": [] },
+                "Store": { "id": "d", "type": "select", "select": { "id": "s", "name": "Gus's Community Market", "color": "yellow" } },
+                "Harvest": { "id": "e", "type": "date", "date": { "start": "2024-10-01", "end": "2024-10-15", "time_zone": null } },
+                "Recipes": { "id": "f", "type": "relation", "relation": [{ "id": "page-1" }, { "id": "page-2" }], "has_more": false },
+                "In stock": { "id": "g", "type": "checkbox", "checkbox": true }
+            },
+            "url": "https://www.notion.so/Tuscan-kale-598337872cf94fdf8782e53db20768a5",
+            "public_url": null
+        }
+        "#;
+
+        serde_json::from_str(json_data).unwrap()
+    }
+
+    #[test]
+    fn property_accessors() {
+        let page = page();
+
+        let property = |name: &str| page.get_property(name).unwrap();
+
+        assert_eq!(property("Price").as_number(), Some(2.5));
+        assert_eq!(property("Stock").as_number(), None);
+        assert_eq!(property("Name").as_number(), None);
+
+        assert_eq!(
+            property("Store").as_select_name(),
+            Some("Gus's Community Market")
+        );
+
+        let (start, end) = property("Harvest").as_date_range().unwrap();
+        assert_eq!(start.to_rfc3339(), "2024-10-01T00:00:00+00:00");
+        assert_eq!(end.unwrap().to_rfc3339(), "2024-10-15T00:00:00+00:00");
+
+        assert_eq!(
+            property("Recipes").as_relation_ids(),
+            Some(vec!["page-1", "page-2"])
+        );
+        assert_eq!(property("In stock").as_checkbox(), Some(true));
+        assert_eq!(property("Recipes").as_people(), None);
+    }
+
+    #[derive(Debug)]
+    struct RelationTransport;
+
+    #[async_trait::async_trait]
+    impl crate::client::Transport for RelationTransport {
+        async fn execute(
+            &self,
+            request: reqwest::Request,
+        ) -> Result<reqwest::Response, crate::error::Error> {
+            let body = match request.url().path() {
+                "/v1/pages/59833787-2cf9-4fdf-8782-e53db20768a5/properties/f" => {
+                    r#"{"object":"list","results":[{"object":"property_item","id":"f","type":"relation","relation":{"id":"page-1"}},{"object":"property_item","id":"f","type":"relation","relation":{"id":"page-2"}},{"object":"property_item","id":"f","type":"relation","relation":{"id":"page-3"}}],"next_cursor":null,"has_more":false,"type":"property_item","property_item":{"id":"f","next_url":null,"type":"relation","relation":{}}}"#.to_string()
+                }
+                "/v1/pages/59833787-2cf9-4fdf-8782-e53db20768a5/properties/c" => {
+                    let results = (0..30)
+                        .map(|index| {
+                            serde_json::json!({
+                                "object": "property_item",
+                                "id": "c",
+                                "type": "rich_text",
+                                "rich_text": serde_json::to_value(crate::RichText::from(index.to_string())).unwrap(),
+                            })
+                        })
+                        .collect::<Vec<_>>();
+
+                    serde_json::json!({
+                        "object": "list",
+                        "results": results,
+                        "next_cursor": null,
+                        "has_more": false,
+                        "type": "property_item",
+                        "property_item": { "id": "c", "next_url": null, "type": "rich_text", "rich_text": {} },
+                    })
+                    .to_string()
+                }
+                path => panic!("unexpected request: {}", path),
+            };
+
+            Ok(reqwest::Response::from(http::Response::new(body)))
+        }
+    }
+
+    #[tokio::test]
+    async fn fetch_truncated_properties() {
+        let client = crate::client::Client::builder()
+            .secret("secret_test")
+            .transport(RelationTransport)
+            .build();
+
+        let mut page = page();
+
+        // Not truncated: no request is sent.
+        page.fetch_truncated_properties(&client).await.unwrap();
+        assert_eq!(
+            page.get_property("Recipes").unwrap().as_relation_ids(),
+            Some(vec!["page-1", "page-2"])
+        );
+
+        if let Some(PageProperty::Relation(relation)) = page.properties.get_mut("Recipes") {
+            relation.has_more = true;
+        }
+
+        page.fetch_truncated_properties(&client).await.unwrap();
+        assert_eq!(
+            page.get_property("Recipes").unwrap().as_relation_ids(),
+            Some(vec!["page-1", "page-2", "page-3"])
+        );
+
+        // Rich text with 25 items may be truncated.
+        if let Some(PageProperty::RichText(description)) = page.properties.get_mut("Description") {
+            description.rich_text = (0..25)
+                .map(|index| crate::RichText::from(index.to_string()))
+                .collect();
+        }
+
+        page.fetch_truncated_properties(&client).await.unwrap();
+        assert_eq!(
+            page.get_str("Description").unwrap(),
+            (0..30).map(|index| index.to_string()).collect::<String>()
+        );
+    }
+
+    #[test]
+    fn page_accessors() {
+        let page = page();
+
+        assert_eq!(page.get_title(), Some("Tuscan kale".to_string()));
+        assert_eq!(page.get_str("Name").unwrap(), "Tuscan kale");
+        assert_eq!(page.get_str("Description").unwrap(), "");
+        assert_eq!(page.get_number("Price").unwrap(), Some(2.5));
+        assert_eq!(page.get_number("Stock").unwrap(), None);
+
+        assert_eq!(
+            page.get_number("Name").unwrap_err(),
+            PropertyError::TypeMismatch {
+                property: "Name".to_string(),
+                expected: "number",
+                found: "title",
+            }
+        );
+        assert_eq!(
+            page.get_str("Unknown").unwrap_err(),
+            PropertyError::Missing {
+                property: "Unknown".to_string(),
+            }
+        );
+    }
+}
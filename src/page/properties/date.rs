@@ -1,5 +1,5 @@
-use chrono::{DateTime, FixedOffset, NaiveDate, TimeZone};
-use serde::{Deserialize, Deserializer, Serialize};
+use chrono::{DateTime, FixedOffset, NaiveDate, NaiveTime, SecondsFormat};
+use serde::{Deserialize, Serialize};
 
 /// <https://developers.notion.com/reference/page-property-values#date>
 ///
@@ -54,46 +54,90 @@ pub struct PageDateProperty {
 
 /// If the value is blank, it will be an empty object.
 #[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Clone, Default)]
+#[serde(
+    try_from = "RawPageDatePropertyParameter",
+    into = "RawPageDatePropertyParameter"
+)]
 pub struct PageDatePropertyParameter {
     /// A date, with an optional time.
-    #[serde(deserialize_with = "deserialize_date_or_datetime")]
     pub start: Option<DateTime<FixedOffset>>,
 
     /// A string representing the end of a date range.
     /// If the value is null, then the date value is not a range.
-    #[serde(deserialize_with = "deserialize_date_or_datetime")]
     pub end: Option<DateTime<FixedOffset>>,
 
     /// Always `null`. The time zone is already included in the formats of start and end times.
-    #[serde(skip_deserializing)]
     pub time_zone: Option<String>,
+
+    /// Whether `start` and `end` are dates without a time.
+    /// They are held as midnight in UTC and serialized as `"YYYY-MM-DD"`.
+    pub date_only: bool,
 }
 
-fn deserialize_date_or_datetime<'de, D>(
-    deserializer: D,
-) -> Result<Option<DateTime<FixedOffset>>, D::Error>
-where
-    D: Deserializer<'de>,
-{
-    let date_str = Option::<String>::deserialize(deserializer)?;
-
-    if let Some(date_str) = date_str {
-        if let Ok(date) = NaiveDate::parse_from_str(&date_str, "%Y-%m-%d") {
-            return Ok(Some(
-                FixedOffset::east_opt(0).unwrap().from_utc_datetime(
-                    &date
-                        .and_hms_opt(0, 0, 0)
-                        .ok_or_else(|| serde::de::Error::custom("Invalid time"))?,
-                ),
-            ));
-        }
-        if let Ok(datetime) = DateTime::parse_from_rfc3339(&date_str) {
-            return Ok(Some(datetime));
+/// The wire format of [`PageDatePropertyParameter`].
+#[derive(Deserialize, Serialize)]
+struct RawPageDatePropertyParameter {
+    #[serde(default)]
+    start: Option<String>,
+
+    #[serde(default)]
+    end: Option<String>,
+
+    #[serde(skip_deserializing)]
+    time_zone: Option<String>,
+}
+
+impl TryFrom<RawPageDatePropertyParameter> for PageDatePropertyParameter {
+    type Error = String;
+
+    fn try_from(value: RawPageDatePropertyParameter) -> Result<Self, Self::Error> {
+        let date_only = value
+            .start
+            .as_deref()
+            .or(value.end.as_deref())
+            .is_some_and(|date| NaiveDate::parse_from_str(date, "%Y-%m-%d").is_ok());
+
+        Ok(Self {
+            start: value
+                .start
+                .as_deref()
+                .map(parse_date_or_datetime)
+                .transpose()?,
+            end: value
+                .end
+                .as_deref()
+                .map(parse_date_or_datetime)
+                .transpose()?,
+            time_zone: value.time_zone,
+            date_only,
+        })
+    }
+}
+
+impl From<PageDatePropertyParameter> for RawPageDatePropertyParameter {
+    fn from(value: PageDatePropertyParameter) -> Self {
+        let format = |date: DateTime<FixedOffset>| {
+            if value.date_only {
+                date.date_naive().format("%Y-%m-%d").to_string()
+            } else {
+                date.to_rfc3339_opts(SecondsFormat::AutoSi, false)
+            }
+        };
+
+        Self {
+            start: value.start.map(format),
+            end: value.end.map(format),
+            time_zone: value.time_zone,
         }
-        return Err(serde::de::Error::custom("Invalid date or datetime format"));
     }
+}
 
-    Ok(None)
+fn parse_date_or_datetime(date_str: &str) -> Result<DateTime<FixedOffset>, String> {
+    if let Ok(date) = NaiveDate::parse_from_str(date_str, "%Y-%m-%d") {
+        return Ok(date.and_time(NaiveTime::MIN).and_utc().fixed_offset());
+    }
+    DateTime::parse_from_rfc3339(date_str)
+        .map_err(|_| "Invalid date or datetime format".to_string())
 }
 
 impl PageDateProperty {
@@ -136,6 +180,20 @@ impl From<chrono::DateTime<chrono::FixedOffset>> for PageDateProperty {
     }
 }
 
+/// A date without a time, serialized as `"YYYY-MM-DD"`.
+impl From<NaiveDate> for PageDateProperty {
+    fn from(value: NaiveDate) -> Self {
+        Self {
+            id: None,
+            date: Some(PageDatePropertyParameter {
+                start: Some(value.and_time(NaiveTime::MIN).and_utc().fixed_offset()),
+                date_only: true,
+                ..Default::default()
+            }),
+        }
+    }
+}
+
 impl std::fmt::Display for PageDateProperty {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         if let Some(date) = &self.date {
@@ -200,4 +258,33 @@ mod unit_tests {
             None => {}
         }
     }
+
+    #[test]
+    fn serialize_page_date_property() {
+        let date = PageDateProperty::from(chrono::NaiveDate::from_ymd_opt(2024, 10, 1).unwrap());
+
+        assert_eq!(
+            serde_json::to_value(&date).unwrap(),
+            serde_json::json!({ "date": { "start": "2024-10-01", "end": null, "time_zone": null } })
+        );
+
+        let date = PageDateProperty::from(
+            chrono::DateTime::parse_from_rfc3339("2024-04-04T10:30:00.000+02:00").unwrap(),
+        );
+
+        assert_eq!(
+            serde_json::to_value(&date).unwrap()["date"]["start"],
+            "2024-04-04T10:30:00+02:00"
+        );
+
+        let json_data =
+            r#"{ "date": { "start": "2024-10-01", "end": "2024-10-03", "time_zone": null } }"#;
+        let date = serde_json::from_str::<PageDateProperty>(json_data).unwrap();
+
+        assert!(date.date.as_ref().unwrap().date_only);
+        assert_eq!(
+            serde_json::to_value(&date).unwrap(),
+            serde_json::from_str::<serde_json::Value>(json_data).unwrap()
+        );
+    }
 }
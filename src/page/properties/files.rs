@@ -144,6 +144,7 @@ mod unit_tests {
                     assert_eq!(f.name, Some("Favicon.ico".to_string()));
                     assert_eq!(f.external.url, "https://www.notion.so/images/favicon.ico");
                 }
+                crate::others::file::File::FileUpload(_) => panic!("Unexpected!"),
             }
         }
     }
@@ -91,6 +91,169 @@ impl std::fmt::Display for PageProperty {
     }
 }
 
+impl PageProperty {
+    /// The name of the type of the property, as used by the Notion API (e.g. `rich_text`).
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            PageProperty::Button(_) => "button",
+            PageProperty::Checkbox(_) => "checkbox",
+            PageProperty::CreatedBy(_) => "created_by",
+            PageProperty::CreatedTime(_) => "created_time",
+            PageProperty::Date(_) => "date",
+            PageProperty::Email(_) => "email",
+            PageProperty::Files(_) => "files",
+            PageProperty::Formula(_) => "formula",
+            PageProperty::LastEditedBy(_) => "last_edited_by",
+            PageProperty::LastEditedTime(_) => "last_edited_time",
+            PageProperty::MultiSelect(_) => "multi_select",
+            PageProperty::Number(_) => "number",
+            PageProperty::People(_) => "people",
+            PageProperty::PhoneNumber(_) => "phone_number",
+            PageProperty::Relation(_) => "relation",
+            PageProperty::RichText(_) => "rich_text",
+            PageProperty::Rollup(_) => "rollup",
+            PageProperty::Select(_) => "select",
+            PageProperty::Status(_) => "status",
+            PageProperty::Title(_) => "title",
+            PageProperty::UniqueId(_) => "unique_id",
+            PageProperty::Url(_) => "url",
+        }
+    }
+
+    // The accessors below return `None` if the property is of another type
+    // or has no value.
+
+    /// The rich text of a `title` or `rich_text` property.
+    pub fn as_rich_text(&self) -> Option<&[crate::others::rich_text::RichText]> {
+        match self {
+            PageProperty::Title(title) => Some(&title.title),
+            PageProperty::RichText(rich_text) => Some(&rich_text.rich_text),
+            _ => None,
+        }
+    }
+
+    /// The plain text of a `title` or `rich_text` property.
+    pub fn as_plain_text(&self) -> Option<String> {
+        self.as_rich_text()
+            .map(|rich_text| rich_text.iter().map(|t| t.to_string()).collect())
+    }
+
+    /// The value of a `number` property.
+    pub fn as_number(&self) -> Option<f64> {
+        match self {
+            PageProperty::Number(number) => number.number,
+            _ => None,
+        }
+    }
+
+    /// The value of a `checkbox` property.
+    pub fn as_checkbox(&self) -> Option<bool> {
+        match self {
+            PageProperty::Checkbox(checkbox) => Some(checkbox.checkbox),
+            _ => None,
+        }
+    }
+
+    /// The selected option of a `select` property.
+    pub fn as_select(&self) -> Option<&crate::others::select::Select> {
+        match self {
+            PageProperty::Select(select) => select.select.as_ref(),
+            _ => None,
+        }
+    }
+
+    /// The name of the selected option of a `select` property.
+    pub fn as_select_name(&self) -> Option<&str> {
+        self.as_select().map(|select| select.name.as_str())
+    }
+
+    /// The name of the option of a `status` property.
+    pub fn as_status_name(&self) -> Option<&str> {
+        match self {
+            PageProperty::Status(status) => Some(&status.status.name),
+            _ => None,
+        }
+    }
+
+    /// The names of the selected options of a `multi_select` property.
+    pub fn as_multi_select_names(&self) -> Option<Vec<&str>> {
+        match self {
+            PageProperty::MultiSelect(multi_select) => Some(
+                multi_select
+                    .multi_select
+                    .iter()
+                    .map(|select| select.name.as_str())
+                    .collect(),
+            ),
+            _ => None,
+        }
+    }
+
+    /// The start and the end (if it is a range) of a `date` property.
+    pub fn as_date_range(
+        &self,
+    ) -> Option<(
+        chrono::DateTime<chrono::FixedOffset>,
+        Option<chrono::DateTime<chrono::FixedOffset>>,
+    )> {
+        match self {
+            PageProperty::Date(date) => {
+                let date = date.date.as_ref()?;
+                Some((date.start?, date.end))
+            }
+            _ => None,
+        }
+    }
+
+    /// The users of a `people` property.
+    pub fn as_people(&self) -> Option<&[crate::user::User]> {
+        match self {
+            PageProperty::People(people) => Some(&people.people),
+            _ => None,
+        }
+    }
+
+    /// The IDs of the pages of a `relation` property.
+    ///
+    /// Relations with more than 25 pages are truncated (`has_more` is `true`).
+    pub fn as_relation_ids(&self) -> Option<Vec<&str>> {
+        match self {
+            PageProperty::Relation(relation) => Some(
+                relation
+                    .relation
+                    .iter()
+                    .map(|relation| relation.id.as_str())
+                    .collect(),
+            ),
+            _ => None,
+        }
+    }
+
+    /// The value of a `url` property.
+    pub fn as_url(&self) -> Option<&str> {
+        match self {
+            PageProperty::Url(url) => url.url.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// The value of an `email` property.
+    pub fn as_email(&self) -> Option<&str> {
+        match self {
+            PageProperty::Email(email) => email.email.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// The value of a `phone_number` property.
+    pub fn as_phone_number(&self) -> Option<&str> {
+        match self {
+            PageProperty::PhoneNumber(phone_number) => phone_number.phone_number.as_deref(),
+            _ => None,
+        }
+    }
+}
+
 // # --------------------------------------------------------------------------------
 //
 // unit test
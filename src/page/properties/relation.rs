@@ -42,6 +42,8 @@ pub struct PageRelationProperty {
     /// If a relation has more than 25 references,
     /// then the has_more value for the relation in the response object is true.
     /// If a relation doesn’t exceed the limit, then has_more is false.
+    /// This field is only present in responses.
+    #[serde(default, skip_serializing)]
     pub has_more: bool,
 }
 
@@ -1,20 +1,156 @@
 use serde::{Deserialize, Serialize};
 
-// TODO: Implement the Rollup object.
+use crate::database::RollupFunction;
+
+use super::{date::PageDatePropertyParameter, PageProperty};
+
+/// <https://developers.notion.com/reference/page-property-values#rollup>
+///
+/// Rollup property value objects represent the result of a rollup
+/// described in the database's properties.
+///
+/// - `$.['*'].id`: An underlying identifier for the property.
+///   `id` remains constant when the property name changes.
+/// - `$.['*'].type`: Always `"rollup"`
+/// - `$.['*'].rollup.type`: A string indicating the data type of the result of the rollup.
+///   Possible type values are:
+///   - `array`
+///   - `date`
+///   - `incomplete`
+///   - `number`
+///   - `unsupported`
+/// - `$.['*'].rollup.function`: The function that is evaluated for the rollup.
+///
+/// **Note**: The `['*']` part represents the column name you set when creating the database.
+///
+/// Example rollup page property value
+///
+/// ```json
+/// {
+///   "Total": {
+///     "id": "ahLk",
+///     "type": "rollup",
+///     "rollup": {
+///       "type": "number",
+///       "number": 1234,
+///       "function": "sum"
+///     }
+///   }
+/// }
+/// ```
 #[derive(Debug, Deserialize, Serialize)]
 pub struct PageRollupProperty {
     /// An underlying identifier for the property.
     /// `id` remains constant when the property name changes.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub id: Option<String>,
+
+    /// Rollup property value objects represent the result of a rollup
+    /// described in the database's properties.
+    pub rollup: Rollup,
 }
 
 impl std::fmt::Display for PageRollupProperty {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "")
+        write!(f, "{}", self.rollup)
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Rollup {
+    /// The result of the rollup.
+    #[serde(flatten)]
+    pub value: RollupValue,
+
+    /// The function that is evaluated for the rollup.
+    pub function: RollupFunction,
+}
+
+impl std::fmt::Display for Rollup {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.value)
     }
 }
 
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum RollupValue {
+    Array(RollupArray),
+    Date(RollupDate),
+    Number(RollupNumber),
+
+    /// The rollup is not computed yet.
+    Incomplete,
+
+    /// The rollup type is not supported by the Notion API.
+    Unsupported,
+}
+
+impl std::fmt::Display for RollupValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RollupValue::Array(a) => write!(
+                f,
+                "{}",
+                a.array
+                    .iter()
+                    .map(|property| property.to_string())
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            ),
+            RollupValue::Date(d) => match &d.date {
+                Some(date) => write!(f, "{}", date),
+                None => write!(f, ""),
+            },
+            RollupValue::Number(n) => match n.number {
+                Some(number) => write!(f, "{}", number),
+                None => write!(f, ""),
+            },
+            RollupValue::Incomplete | RollupValue::Unsupported => write!(f, ""),
+        }
+    }
+}
+
+/// ```json
+/// {
+///   "type": "array",
+///   "array": [
+///     { "type": "number", "number": 12 },
+///     { "type": "number", "number": 34 }
+///   ]
+/// }
+/// ```
+#[derive(Debug, Deserialize, Serialize)]
+pub struct RollupArray {
+    /// The values of the rolled up property of each related page (`show_original`
+    /// and `show_unique`). The items have no `id`.
+    pub array: Vec<PageProperty>,
+}
+
+/// ```json
+/// {
+///   "type": "date",
+///   "date": { "start": "2024-08-15T05:24:00.000Z", "end": null, "time_zone": null }
+/// }
+/// ```
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Eq)]
+pub struct RollupDate {
+    /// Calculated value of the rollup
+    pub date: Option<PageDatePropertyParameter>,
+}
+
+/// ```json
+/// {
+///   "type": "number",
+///   "number": 1234
+/// }
+/// ```
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq)]
+pub struct RollupNumber {
+    /// Calculated value of the rollup
+    pub number: Option<f64>,
+}
+
 // # --------------------------------------------------------------------------------
 //
 // unit test
@@ -23,5 +159,164 @@ impl std::fmt::Display for PageRollupProperty {
 
 #[cfg(test)]
 mod unit_tests {
-    // TODO: test
+
+    use super::*;
+
+    fn deserialize(json_data: &str) -> PageRollupProperty {
+        serde_json::from_str::<std::collections::HashMap<String, PageRollupProperty>>(json_data)
+            .unwrap()
+            .remove("Rollup")
+            .unwrap()
+    }
+
+    #[test]
+    fn deserialize_page_rollup_property_number() {
+        let json_data = r#"
+        {
+            "Rollup": {
+                "id": "%5E%7Bmj",
+                "type": "rollup",
+                "rollup": {
+                    "type": "number",
+                    "number": 1234.5,
+                    "function": "sum"
+                }
+            }
+        }
+        "#;
+
+        let rollup = deserialize(json_data);
+
+        assert_eq!(rollup.id, Some("%5E%7Bmj".to_string()));
+        assert_eq!(rollup.rollup.function, RollupFunction::Sum);
+
+        match &rollup.rollup.value {
+            RollupValue::Number(n) => assert_eq!(n.number, Some(1234.5)),
+            _ => panic!(),
+        }
+
+        assert_eq!(rollup.to_string(), "1234.5");
+
+        let json = serde_json::to_value(&rollup).unwrap();
+        assert_eq!(json["rollup"]["type"], "number");
+        assert_eq!(json["rollup"]["number"], 1234.5);
+        assert_eq!(json["rollup"]["function"], "sum");
+    }
+
+    #[test]
+    fn deserialize_page_rollup_property_date() {
+        let json_data = r#"
+        {
+            "Rollup": {
+                "id": "vG%5Cn",
+                "type": "rollup",
+                "rollup": {
+                    "type": "date",
+                    "date": {
+                        "start": "2024-08-15T05:24:00.000+00:00",
+                        "end": null,
+                        "time_zone": null
+                    },
+                    "function": "latest_date"
+                }
+            }
+        }
+        "#;
+
+        let rollup = deserialize(json_data);
+
+        assert_eq!(rollup.rollup.function, RollupFunction::LatestDate);
+
+        match &rollup.rollup.value {
+            RollupValue::Date(d) => {
+                let date = d.date.as_ref().unwrap();
+                assert_eq!(
+                    date.start.unwrap().to_rfc3339(),
+                    "2024-08-15T05:24:00+00:00"
+                );
+                assert_eq!(date.end, None);
+            }
+            _ => panic!(),
+        }
+
+        assert_eq!(rollup.to_string(), "2024-08-15T05:24:00+00:00");
+    }
+
+    #[test]
+    fn deserialize_page_rollup_property_array() {
+        let json_data = r#"
+        {
+            "Rollup": {
+                "id": "TmdV",
+                "type": "rollup",
+                "rollup": {
+                    "type": "array",
+                    "array": [
+                        {
+                            "type": "title",
+                            "title": [
+                                {
+                                    "type": "text",
+                                    "text": { "content": "Invoice #1", "link": null },
+                                    "annotations": {
+                                        "bold": false,
+                                        "italic": false,
+                                        "strikethrough": false,
+                                        "underline": false,
+                                        "code": false,
+                                        "color": "default"
+                                    },
+                                    "plain_text": "Invoice #1",
+                                    "href": null
+                                }
+                            ]
+                        },
+                        {
+                            "type": "number",
+                            "number": 42
+                        }
+                    ],
+                    "function": "show_original"
+                }
+            }
+        }
+        "#;
+
+        let rollup = deserialize(json_data);
+
+        assert_eq!(rollup.rollup.function, RollupFunction::ShowOriginal);
+
+        match &rollup.rollup.value {
+            RollupValue::Array(a) => {
+                assert_eq!(a.array.len(), 2);
+                assert_eq!(a.array[0].as_plain_text(), Some("Invoice #1".to_string()));
+                assert_eq!(a.array[1].as_number(), Some(42.0));
+            }
+            _ => panic!(),
+        }
+
+        assert_eq!(rollup.to_string(), "Invoice #1, 42");
+    }
+
+    #[test]
+    fn deserialize_page_rollup_property_incomplete() {
+        let json_data = r#"
+        {
+            "Rollup": {
+                "id": "TmdV",
+                "type": "rollup",
+                "rollup": {
+                    "type": "incomplete",
+                    "incomplete": {},
+                    "function": "count"
+                }
+            }
+        }
+        "#;
+
+        let rollup = deserialize(json_data);
+
+        assert!(matches!(rollup.rollup.value, RollupValue::Incomplete));
+        assert_eq!(rollup.rollup.function, RollupFunction::Count);
+    }
 }
@@ -0,0 +1,348 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    error::Error,
+    others::rich_text::RichText,
+    page::{
+        properties::{
+            relation::PageRelationPropertyParameter,
+            rollup::{Rollup, RollupArray, RollupValue},
+        },
+        PagePeopleProperty, PageProperty, PageRelationProperty, PageRichTextProperty,
+        PageRollupProperty, PageTitleProperty,
+    },
+    user::User,
+};
+
+/// <https://developers.notion.com/reference/retrieve-a-page-property>
+///
+/// The response of the "Retrieve a page property item" endpoint.
+/// `title`, `rich_text`, `relation`, `people` and `rollup` properties are
+/// returned as a paginated list of property items, and the others as a single property item.
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(tag = "object", rename_all = "snake_case")]
+pub enum PropertyItemResponse {
+    List(PropertyItemList),
+    PropertyItem(PageProperty),
+}
+
+/// One page of the property items of a paginated property.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct PropertyItemList {
+    pub results: Vec<PropertyItem>,
+
+    pub next_cursor: Option<String>,
+
+    #[serde(default)]
+    pub has_more: bool,
+
+    /// The type of the property (and the value of a `rollup`).
+    pub property_item: PropertyItemSummary,
+}
+
+/// ```json
+/// {
+///   "id": "kjPO",
+///   "next_url": null,
+///   "type": "relation",
+///   "relation": {}
+/// }
+/// ```
+#[derive(Debug, Deserialize, Serialize)]
+pub struct PropertyItemSummary {
+    /// An underlying identifier for the property.
+    pub id: Option<String>,
+
+    /// The URL of the next page of property items.
+    pub next_url: Option<String>,
+
+    /// The type of the property.
+    pub r#type: String,
+
+    /// The value of a `rollup` property. For the `array` type,
+    /// the values are returned as the property items of the list.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rollup: Option<Rollup>,
+}
+
+/// An item of a paginated property.
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum PropertyItem {
+    /// One element of a `title`, `rich_text`, `relation` or `people` property.
+    Element(PropertyItemElement),
+
+    /// A whole property, e.g. a `number` rolled up from a related page.
+    Property(PageProperty),
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum PropertyItemElement {
+    Title {
+        title: RichText,
+    },
+    RichText {
+        rich_text: RichText,
+    },
+    Relation {
+        relation: PageRelationPropertyParameter,
+    },
+    People {
+        people: User,
+    },
+}
+
+impl From<PropertyItem> for PageProperty {
+    /// Converts an element into a property containing only that element.
+    fn from(item: PropertyItem) -> Self {
+        match item {
+            PropertyItem::Element(element) => match element {
+                PropertyItemElement::Title { title } => PageProperty::Title(PageTitleProperty {
+                    id: None,
+                    title: vec![title],
+                }),
+                PropertyItemElement::RichText { rich_text } => {
+                    PageProperty::RichText(PageRichTextProperty {
+                        id: None,
+                        rich_text: vec![rich_text],
+                    })
+                }
+                PropertyItemElement::Relation { relation } => {
+                    PageProperty::Relation(PageRelationProperty {
+                        id: None,
+                        relation: vec![relation],
+                        has_more: false,
+                    })
+                }
+                PropertyItemElement::People { people } => {
+                    PageProperty::People(PagePeopleProperty {
+                        id: None,
+                        people: vec![people],
+                    })
+                }
+            },
+            PropertyItem::Property(property) => property,
+        }
+    }
+}
+
+impl PropertyItemSummary {
+    /// Merges the property items of all pages into a complete property.
+    pub fn into_property(self, items: Vec<PropertyItem>) -> Result<PageProperty, Error> {
+        let id = self.id;
+
+        fn elements(items: Vec<PropertyItem>) -> impl Iterator<Item = PropertyItemElement> {
+            items.into_iter().filter_map(|item| match item {
+                PropertyItem::Element(element) => Some(element),
+                PropertyItem::Property(_) => None,
+            })
+        }
+
+        let property = match self.r#type.as_str() {
+            "title" => PageProperty::Title(PageTitleProperty {
+                id,
+                title: elements(items)
+                    .filter_map(|element| match element {
+                        PropertyItemElement::Title { title } => Some(title),
+                        _ => None,
+                    })
+                    .collect(),
+            }),
+            "rich_text" => PageProperty::RichText(PageRichTextProperty {
+                id,
+                rich_text: elements(items)
+                    .filter_map(|element| match element {
+                        PropertyItemElement::RichText { rich_text } => Some(rich_text),
+                        _ => None,
+                    })
+                    .collect(),
+            }),
+            "relation" => PageProperty::Relation(PageRelationProperty {
+                id,
+                relation: elements(items)
+                    .filter_map(|element| match element {
+                        PropertyItemElement::Relation { relation } => Some(relation),
+                        _ => None,
+                    })
+                    .collect(),
+                has_more: false,
+            }),
+            "people" => PageProperty::People(PagePeopleProperty {
+                id,
+                people: elements(items)
+                    .filter_map(|element| match element {
+                        PropertyItemElement::People { people } => Some(people),
+                        _ => None,
+                    })
+                    .collect(),
+            }),
+            "rollup" => {
+                let mut rollup = self.rollup.ok_or_else(|| {
+                    Error::Unknown("the value of the rollup is not returned".to_string())
+                })?;
+
+                if let RollupValue::Array(_) = rollup.value {
+                    rollup.value = RollupValue::Array(RollupArray {
+                        array: items.into_iter().map(PageProperty::from).collect(),
+                    });
+                }
+
+                PageProperty::Rollup(PageRollupProperty { id, rollup })
+            }
+            other => {
+                return Err(Error::Unknown(format!(
+                    "unexpected type of paginated property: {}",
+                    other
+                )))
+            }
+        };
+
+        Ok(property)
+    }
+}
+
+// # --------------------------------------------------------------------------------
+//
+// unit test
+//
+// # --------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod unit_tests {
+    use super::*;
+
+    #[test]
+    fn deserialize_property_item() {
+        let json_data = r#"
+        {
+            "object": "property_item",
+            "id": "kjPO",
+            "type": "number",
+            "number": 2
+        }
+        "#;
+
+        let response = serde_json::from_str::<PropertyItemResponse>(json_data).unwrap();
+
+        match response {
+            PropertyItemResponse::PropertyItem(property) => {
+                assert_eq!(property.as_number(), Some(2.0))
+            }
+            PropertyItemResponse::List(_) => panic!(),
+        }
+    }
+
+    #[test]
+    fn merge_relation_items() {
+        let json_data = r#"
+        {
+            "object": "list",
+            "results": [
+                { "object": "property_item", "id": "kjPO", "type": "relation", "relation": { "id": "page-1" } },
+                { "object": "property_item", "id": "kjPO", "type": "relation", "relation": { "id": "page-2" } }
+            ],
+            "next_cursor": null,
+            "has_more": false,
+            "type": "property_item",
+            "property_item": { "id": "kjPO", "next_url": null, "type": "relation", "relation": {} }
+        }
+        "#;
+
+        let PropertyItemResponse::List(list) =
+            serde_json::from_str::<PropertyItemResponse>(json_data).unwrap()
+        else {
+            panic!()
+        };
+
+        let property = list.property_item.into_property(list.results).unwrap();
+
+        assert_eq!(property.as_relation_ids(), Some(vec!["page-1", "page-2"]));
+
+        let PageProperty::Relation(relation) = property else {
+            panic!()
+        };
+        assert_eq!(relation.id.as_deref(), Some("kjPO"));
+    }
+
+    #[test]
+    fn merge_title_items() {
+        let json_data = r#"
+        {
+            "object": "list",
+            "results": [
+                {
+                    "object": "property_item",
+                    "id": "title",
+                    "type": "title",
+                    "title": {
+                        "type": "text",
+                        "text": { "content": "Hello, ", "link": null },
+                        "annotations": { "bold": false, "italic": false, "strikethrough": false, "underline": false, "code": false, "color": "default" },
+                        "plain_text": "Hello, ",
+                        "href": null
+                    }
+                },
+                {
+                    "object": "property_item",
+                    "id": "title",
+                    "type": "title",
+                    "title": {
+                        "type": "text",
+                        "text": { "content": "world", "link": null },
+                        "annotations": { "bold": true, "italic": false, "strikethrough": false, "underline": false, "code": false, "color": "default" },
+                        "plain_text": "world",
+                        "href": null
+                    }
+                }
+            ],
+            "next_cursor": null,
+            "has_more": false,
+            "type": "property_item",
+            "property_item": { "id": "title", "next_url": null, "type": "title", "title": {} }
+        }
+        "#;
+
+        let PropertyItemResponse::List(list) =
+            serde_json::from_str::<PropertyItemResponse>(json_data).unwrap()
+        else {
+            panic!()
+        };
+
+        let property = list.property_item.into_property(list.results).unwrap();
+
+        assert_eq!(property.as_plain_text(), Some("Hello, world".to_string()));
+    }
+
+    #[test]
+    fn merge_rollup_items() {
+        let json_data = r#"
+        {
+            "object": "list",
+            "results": [
+                { "object": "property_item", "id": "a", "type": "number", "number": 12 },
+                { "object": "property_item", "id": "a", "type": "number", "number": 30 }
+            ],
+            "next_cursor": null,
+            "has_more": false,
+            "type": "property_item",
+            "property_item": {
+                "id": "Rollup",
+                "next_url": null,
+                "type": "rollup",
+                "rollup": { "type": "array", "array": [], "function": "show_original" }
+            }
+        }
+        "#;
+
+        let PropertyItemResponse::List(list) =
+            serde_json::from_str::<PropertyItemResponse>(json_data).unwrap()
+        else {
+            panic!()
+        };
+
+        let property = list.property_item.into_property(list.results).unwrap();
+
+        assert_eq!(property.to_string(), "12, 30");
+    }
+}
@@ -0,0 +1,933 @@
+//! Mapping database rows (pages) to Rust structs.
+//!
+//! Implement [`NotionRow`] with `#[derive(NotionRow)]` (requires the `derive` feature):
+//!
+#![cfg_attr(feature = "derive", doc = "```no_run")]
+#![cfg_attr(not(feature = "derive"), doc = "```ignore")]
+//! use notionrs::NotionRow;
+//!
+//! #[derive(NotionRow)]
+//! struct Task {
+//!     #[notion(rename = "Name", title)]
+//!     name: String,
+//!
+//!     #[notion(rename = "Status", status)]
+//!     status: String,
+//!
+//!     #[notion(rename = "Estimate")]
+//!     estimate: Option<f64>,
+//!
+//!     #[notion(rename = "Tags")]
+//!     tags: Vec<String>,
+//!
+//!     #[notion(rename = "Due")]
+//!     due: Option<chrono::NaiveDate>,
+//! }
+//!
+//! # async fn run() -> Result<(), notionrs::error::Error> {
+//! # let client = notionrs::Client::new();
+//! # let database_id = "DATABASE_ID";
+//! # let page = client.get_page().page_id("PAGE_ID").send().await?;
+//! let task = Task::from_page(&page)?;
+//!
+//! client
+//!     .create_page()
+//!     .database_id(database_id)
+//!     .properties(task.to_properties())
+//!     .send()
+//!     .await?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! ## Field attributes
+//!
+//! - `rename = "..."`: the name of the property (defaults to the name of the field).
+//! - `skip`: the field is not read nor written. It is initialized with `Default::default()`.
+//! - The type of the property: `title`, `rich_text`, `number`, `select`, `multi_select`,
+//!   `status`, `date`, `checkbox`, `url`, `email`, `phone_number` or `relation`.
+//!   If it is omitted, the default type of the field type is used ([`DefaultKind`]).
+//!
+//! | Rust type | Default property type | Other property types |
+//! | --- | --- | --- |
+//! | `String` | `rich_text` | `title`, `select`, `status`, `url`, `email`, `phone_number` |
+//! | `Vec<RichText>` | `rich_text` | `title` |
+//! | `f64`, `f32`, `i64`, `i32`, `u64`, `u32` | `number` | |
+//! | `bool` | `checkbox` | |
+//! | `Select` | `select` | `status` |
+//! | `Vec<String>` | `multi_select` | `relation` (page IDs) |
+//! | `Vec<Select>` | `multi_select` | |
+//! | `chrono::DateTime<FixedOffset>`, `chrono::DateTime<Utc>`, `chrono::NaiveDate` | `date` | |
+//! | `PageDatePropertyParameter` (start, end and time zone) | `date` | |
+//! | `Option<T>` | the one of `T` | |
+//!
+//! Combining a Rust type with a property type that is not listed is a compile error.
+//!
+//! Reading fails with [`PropertyError`] if a property is missing, has another type,
+//! or is empty while the field is not an `Option` (e.g. a number or a date that is not set).
+
+use std::collections::HashMap;
+
+use chrono::{DateTime, FixedOffset, NaiveDate, Utc};
+
+use crate::{
+    error::{Error, PropertyError},
+    others::{rich_text::RichText, select::Select},
+    page::{
+        date::PageDatePropertyParameter, relation::PageRelationPropertyParameter,
+        PageCheckboxProperty, PageDateProperty, PageEmailProperty, PageMultiSelectProperty,
+        PageNumberProperty, PagePhoneNumberProperty, PageProperty, PageRelationProperty,
+        PageResponse, PageRichTextProperty, PageSelectProperty, PageStatusProperty,
+        PageTitleProperty, PageUrlProperty,
+    },
+};
+
+/// A struct that can be converted from and to the properties of a page.
+///
+/// Usually implemented with `#[derive(NotionRow)]`. See the [module documentation](self).
+pub trait NotionRow: Sized {
+    /// Converts the properties of a page.
+    fn from_properties(properties: &HashMap<String, PageProperty>) -> Result<Self, Error>;
+
+    /// Converts the struct into properties that can be sent with
+    /// `create_page().properties(..)` or `update_page().properties(..)`.
+    fn to_properties(&self) -> HashMap<String, PageProperty>;
+
+    /// Converts the properties of a page.
+    fn from_page(page: &PageResponse) -> Result<Self, Error> {
+        Self::from_properties(&page.properties)
+    }
+}
+
+/// Marker types for the types of page properties.
+pub mod kind {
+    use crate::page::{
+        PageCheckboxProperty, PageDateProperty, PageEmailProperty, PageMultiSelectProperty,
+        PageNumberProperty, PagePhoneNumberProperty, PageProperty, PageRelationProperty,
+        PageRichTextProperty, PageSelectProperty, PageTitleProperty, PageUrlProperty,
+    };
+
+    /// A type of page property.
+    pub trait PropertyKind {
+        /// The name of the type used by the Notion API, e.g. `rich_text`.
+        const NAME: &'static str;
+
+        /// The property used to clear the value (when an `Option` field is `None`).
+        /// `None` if the value cannot be cleared (e.g. `status`), in which case
+        /// the property is not written.
+        fn empty() -> Option<PageProperty>;
+    }
+
+    macro_rules! property_kind {
+        ($kind:ident, $name:literal, $empty:expr) => {
+            #[doc = concat!("The `", $name, "` property type.")]
+            #[derive(Debug, Clone, Copy, Default)]
+            pub struct $kind;
+
+            impl PropertyKind for $kind {
+                const NAME: &'static str = $name;
+
+                fn empty() -> Option<PageProperty> {
+                    $empty
+                }
+            }
+        };
+    }
+
+    property_kind!(
+        Title,
+        "title",
+        Some(PageProperty::Title(PageTitleProperty::default()))
+    );
+    property_kind!(
+        RichText,
+        "rich_text",
+        Some(PageProperty::RichText(PageRichTextProperty {
+            id: None,
+            rich_text: vec![],
+        }))
+    );
+    property_kind!(
+        Number,
+        "number",
+        Some(PageProperty::Number(PageNumberProperty::default()))
+    );
+    property_kind!(
+        Select,
+        "select",
+        Some(PageProperty::Select(PageSelectProperty::default()))
+    );
+    property_kind!(
+        MultiSelect,
+        "multi_select",
+        Some(PageProperty::MultiSelect(PageMultiSelectProperty::default()))
+    );
+    property_kind!(Status, "status", None);
+    property_kind!(
+        Date,
+        "date",
+        Some(PageProperty::Date(PageDateProperty::default()))
+    );
+    property_kind!(
+        Checkbox,
+        "checkbox",
+        Some(PageProperty::Checkbox(PageCheckboxProperty::from(false)))
+    );
+    property_kind!(
+        Url,
+        "url",
+        Some(PageProperty::Url(PageUrlProperty::default()))
+    );
+    property_kind!(
+        Email,
+        "email",
+        Some(PageProperty::Email(PageEmailProperty::default()))
+    );
+    property_kind!(
+        PhoneNumber,
+        "phone_number",
+        Some(PageProperty::PhoneNumber(PagePhoneNumberProperty::default()))
+    );
+    property_kind!(
+        Relation,
+        "relation",
+        Some(PageProperty::Relation(PageRelationProperty {
+            id: None,
+            relation: vec![],
+            has_more: false,
+        }))
+    );
+}
+
+use kind::PropertyKind;
+
+/// A Rust type that can be read from and written to a property of the type `K`.
+pub trait PropertyValue<K>: Sized
+where
+    K: PropertyKind,
+{
+    /// Converts the property named `name`.
+    fn from_property(name: &str, property: &PageProperty) -> Result<Self, PropertyError>;
+
+    /// Converts the value into a property. `None` if the property should not be written.
+    fn to_property(&self) -> Option<PageProperty>;
+
+    /// The value used when the page doesn't have the property.
+    fn from_missing(name: &str) -> Result<Self, PropertyError> {
+        Err(PropertyError::Missing {
+            property: name.to_string(),
+        })
+    }
+}
+
+/// The property type used for a Rust type when a field has no type attribute.
+pub trait DefaultKind {
+    type Kind: PropertyKind;
+}
+
+/// Reads the property `name` as `T`. Used by `#[derive(NotionRow)]`.
+pub fn read_property<T, K>(
+    properties: &HashMap<String, PageProperty>,
+    name: &str,
+) -> Result<T, Error>
+where
+    T: PropertyValue<K>,
+    K: PropertyKind,
+{
+    let value = match properties.get(name) {
+        Some(property) => T::from_property(name, property)?,
+        None => T::from_missing(name)?,
+    };
+
+    Ok(value)
+}
+
+/// Writes `value` as the property `name`. Used by `#[derive(NotionRow)]`.
+pub fn write_property<T, K>(properties: &mut HashMap<String, PageProperty>, name: &str, value: &T)
+where
+    T: PropertyValue<K>,
+    K: PropertyKind,
+{
+    if let Some(property) = value.to_property() {
+        properties.insert(name.to_string(), property);
+    }
+}
+
+fn mismatch<K>(name: &str, property: &PageProperty) -> PropertyError
+where
+    K: PropertyKind,
+{
+    PropertyError::TypeMismatch {
+        property: name.to_string(),
+        expected: K::NAME,
+        found: property.type_name(),
+    }
+}
+
+fn empty(name: &str) -> PropertyError {
+    PropertyError::Empty {
+        property: name.to_string(),
+    }
+}
+
+// # --------------------------------------------------------------------------------
+//
+// Option
+//
+// # --------------------------------------------------------------------------------
+
+impl<T, K> PropertyValue<K> for Option<T>
+where
+    T: PropertyValue<K>,
+    K: PropertyKind,
+{
+    fn from_property(name: &str, property: &PageProperty) -> Result<Self, PropertyError> {
+        match T::from_property(name, property) {
+            Ok(value) => Ok(Some(value)),
+            Err(PropertyError::Empty { .. }) => Ok(None),
+            Err(error) => Err(error),
+        }
+    }
+
+    fn to_property(&self) -> Option<PageProperty> {
+        match self {
+            Some(value) => value.to_property(),
+            None => K::empty(),
+        }
+    }
+
+    fn from_missing(_name: &str) -> Result<Self, PropertyError> {
+        Ok(None)
+    }
+}
+
+impl<T> DefaultKind for Option<T>
+where
+    T: DefaultKind,
+{
+    type Kind = T::Kind;
+}
+
+// # --------------------------------------------------------------------------------
+//
+// title / rich_text
+//
+// # --------------------------------------------------------------------------------
+
+/// Both `title` and `rich_text` properties can be read as text.
+fn text<'a, K>(name: &str, property: &'a PageProperty) -> Result<&'a [RichText], PropertyError>
+where
+    K: PropertyKind,
+{
+    match property {
+        PageProperty::Title(title) => Ok(&title.title),
+        PageProperty::RichText(rich_text) => Ok(&rich_text.rich_text),
+        _ => Err(mismatch::<K>(name, property)),
+    }
+}
+
+fn plain_text(rich_text: &[RichText]) -> String {
+    rich_text.iter().map(|t| t.to_string()).collect()
+}
+
+impl PropertyValue<kind::Title> for String {
+    fn from_property(name: &str, property: &PageProperty) -> Result<Self, PropertyError> {
+        text::<kind::Title>(name, property).map(plain_text)
+    }
+
+    fn to_property(&self) -> Option<PageProperty> {
+        Some(PageProperty::Title(PageTitleProperty::from(self)))
+    }
+}
+
+impl PropertyValue<kind::RichText> for String {
+    fn from_property(name: &str, property: &PageProperty) -> Result<Self, PropertyError> {
+        text::<kind::RichText>(name, property).map(plain_text)
+    }
+
+    fn to_property(&self) -> Option<PageProperty> {
+        Some(PageProperty::RichText(PageRichTextProperty::from(self)))
+    }
+}
+
+impl PropertyValue<kind::Title> for Vec<RichText> {
+    fn from_property(name: &str, property: &PageProperty) -> Result<Self, PropertyError> {
+        text::<kind::Title>(name, property).map(<[RichText]>::to_vec)
+    }
+
+    fn to_property(&self) -> Option<PageProperty> {
+        Some(PageProperty::Title(PageTitleProperty {
+            id: None,
+            title: self.clone(),
+        }))
+    }
+}
+
+impl PropertyValue<kind::RichText> for Vec<RichText> {
+    fn from_property(name: &str, property: &PageProperty) -> Result<Self, PropertyError> {
+        text::<kind::RichText>(name, property).map(<[RichText]>::to_vec)
+    }
+
+    fn to_property(&self) -> Option<PageProperty> {
+        Some(PageProperty::RichText(PageRichTextProperty {
+            id: None,
+            rich_text: self.clone(),
+        }))
+    }
+}
+
+impl DefaultKind for String {
+    type Kind = kind::RichText;
+}
+
+impl DefaultKind for Vec<RichText> {
+    type Kind = kind::RichText;
+}
+
+// # --------------------------------------------------------------------------------
+//
+// number
+//
+// # --------------------------------------------------------------------------------
+
+macro_rules! number_value {
+    (float: $($ty:ty),*) => {
+        $(number_value!(@impl $ty, |_: &str, number: f64| Ok(number as $ty));)*
+    };
+    (integer: $($ty:ty),*) => {
+        $(number_value!(@impl $ty, integer::<$ty>);)*
+    };
+    (@impl $ty:ty, $convert:expr) => {
+        impl PropertyValue<kind::Number> for $ty {
+            fn from_property(name: &str, property: &PageProperty) -> Result<Self, PropertyError> {
+                match property {
+                    PageProperty::Number(number) => {
+                        let number = number.number.ok_or_else(|| empty(name))?;
+                        ($convert)(name, number)
+                    }
+                    _ => Err(mismatch::<kind::Number>(name, property)),
+                }
+            }
+
+            fn to_property(&self) -> Option<PageProperty> {
+                Some(PageProperty::Number(PageNumberProperty::default().number(*self as f64)))
+            }
+        }
+
+        impl DefaultKind for $ty {
+            type Kind = kind::Number;
+        }
+    };
+}
+
+number_value!(float: f64, f32);
+number_value!(integer: i64, i32, u64, u32);
+
+/// Integers are converted only if the number has no fractional part and fits in the type.
+fn integer<T>(name: &str, number: f64) -> Result<T, PropertyError>
+where
+    T: TryFrom<i64>,
+{
+    let in_range = number >= i64::MIN as f64 && number < i64::MAX as f64;
+
+    (number.fract() == 0.0 && in_range)
+        .then(|| T::try_from(number as i64).ok())
+        .flatten()
+        .ok_or_else(|| PropertyError::InvalidNumber {
+            property: name.to_string(),
+            value: number.to_string(),
+            expected: std::any::type_name::<T>(),
+        })
+}
+
+// # --------------------------------------------------------------------------------
+//
+// select / status / multi_select
+//
+// # --------------------------------------------------------------------------------
+
+fn select<'a>(name: &str, property: &'a PageProperty) -> Result<&'a Select, PropertyError> {
+    match property {
+        PageProperty::Select(select) => select.select.as_ref().ok_or_else(|| empty(name)),
+        _ => Err(mismatch::<kind::Select>(name, property)),
+    }
+}
+
+fn status<'a>(name: &str, property: &'a PageProperty) -> Result<&'a Select, PropertyError> {
+    match property {
+        PageProperty::Status(status) => Ok(&status.status),
+        _ => Err(mismatch::<kind::Status>(name, property)),
+    }
+}
+
+fn multi_select<'a>(name: &str, property: &'a PageProperty) -> Result<&'a [Select], PropertyError> {
+    match property {
+        PageProperty::MultiSelect(multi_select) => Ok(&multi_select.multi_select),
+        _ => Err(mismatch::<kind::MultiSelect>(name, property)),
+    }
+}
+
+impl PropertyValue<kind::Select> for String {
+    fn from_property(name: &str, property: &PageProperty) -> Result<Self, PropertyError> {
+        select(name, property).map(|select| select.name.clone())
+    }
+
+    fn to_property(&self) -> Option<PageProperty> {
+        Some(PageProperty::Select(
+            PageSelectProperty::default().select(Select::from(self)),
+        ))
+    }
+}
+
+impl PropertyValue<kind::Select> for Select {
+    fn from_property(name: &str, property: &PageProperty) -> Result<Self, PropertyError> {
+        select(name, property).cloned()
+    }
+
+    fn to_property(&self) -> Option<PageProperty> {
+        Some(PageProperty::Select(
+            PageSelectProperty::default().select(self.clone()),
+        ))
+    }
+}
+
+impl PropertyValue<kind::Status> for String {
+    fn from_property(name: &str, property: &PageProperty) -> Result<Self, PropertyError> {
+        status(name, property).map(|status| status.name.clone())
+    }
+
+    fn to_property(&self) -> Option<PageProperty> {
+        Some(PageProperty::Status(PageStatusProperty {
+            id: None,
+            status: Select::from(self),
+        }))
+    }
+}
+
+impl PropertyValue<kind::Status> for Select {
+    fn from_property(name: &str, property: &PageProperty) -> Result<Self, PropertyError> {
+        status(name, property).cloned()
+    }
+
+    fn to_property(&self) -> Option<PageProperty> {
+        Some(PageProperty::Status(PageStatusProperty {
+            id: None,
+            status: self.clone(),
+        }))
+    }
+}
+
+impl PropertyValue<kind::MultiSelect> for Vec<String> {
+    fn from_property(name: &str, property: &PageProperty) -> Result<Self, PropertyError> {
+        multi_select(name, property)
+            .map(|options| options.iter().map(|option| option.name.clone()).collect())
+    }
+
+    fn to_property(&self) -> Option<PageProperty> {
+        Some(PageProperty::MultiSelect(
+            PageMultiSelectProperty::default()
+                .multi_select(self.iter().map(Select::from).collect()),
+        ))
+    }
+}
+
+impl PropertyValue<kind::MultiSelect> for Vec<Select> {
+    fn from_property(name: &str, property: &PageProperty) -> Result<Self, PropertyError> {
+        multi_select(name, property).map(<[Select]>::to_vec)
+    }
+
+    fn to_property(&self) -> Option<PageProperty> {
+        Some(PageProperty::MultiSelect(
+            PageMultiSelectProperty::default().multi_select(self.clone()),
+        ))
+    }
+}
+
+impl DefaultKind for Select {
+    type Kind = kind::Select;
+}
+
+impl DefaultKind for Vec<String> {
+    type Kind = kind::MultiSelect;
+}
+
+impl DefaultKind for Vec<Select> {
+    type Kind = kind::MultiSelect;
+}
+
+// # --------------------------------------------------------------------------------
+//
+// date
+//
+// # --------------------------------------------------------------------------------
+
+fn date<'a>(
+    name: &str,
+    property: &'a PageProperty,
+) -> Result<&'a PageDatePropertyParameter, PropertyError> {
+    match property {
+        PageProperty::Date(date) => date.date.as_ref().ok_or_else(|| empty(name)),
+        _ => Err(mismatch::<kind::Date>(name, property)),
+    }
+}
+
+fn date_start(name: &str, property: &PageProperty) -> Result<DateTime<FixedOffset>, PropertyError> {
+    date(name, property)?.start.ok_or_else(|| empty(name))
+}
+
+impl PropertyValue<kind::Date> for DateTime<FixedOffset> {
+    fn from_property(name: &str, property: &PageProperty) -> Result<Self, PropertyError> {
+        date_start(name, property)
+    }
+
+    fn to_property(&self) -> Option<PageProperty> {
+        Some(PageProperty::Date(PageDateProperty::from(*self)))
+    }
+}
+
+impl PropertyValue<kind::Date> for DateTime<Utc> {
+    fn from_property(name: &str, property: &PageProperty) -> Result<Self, PropertyError> {
+        date_start(name, property).map(|start| start.with_timezone(&Utc))
+    }
+
+    fn to_property(&self) -> Option<PageProperty> {
+        Some(PageProperty::Date(PageDateProperty::from(
+            self.fixed_offset(),
+        )))
+    }
+}
+
+/// Dates are written without a time, e.g. `"2024-10-01"`.
+impl PropertyValue<kind::Date> for NaiveDate {
+    fn from_property(name: &str, property: &PageProperty) -> Result<Self, PropertyError> {
+        date_start(name, property).map(|start| start.date_naive())
+    }
+
+    fn to_property(&self) -> Option<PageProperty> {
+        Some(PageProperty::Date(PageDateProperty::from(*self)))
+    }
+}
+
+impl PropertyValue<kind::Date> for PageDatePropertyParameter {
+    fn from_property(name: &str, property: &PageProperty) -> Result<Self, PropertyError> {
+        date(name, property).cloned()
+    }
+
+    fn to_property(&self) -> Option<PageProperty> {
+        Some(PageProperty::Date(PageDateProperty {
+            id: None,
+            date: Some(self.clone()),
+        }))
+    }
+}
+
+impl DefaultKind for DateTime<FixedOffset> {
+    type Kind = kind::Date;
+}
+
+impl DefaultKind for DateTime<Utc> {
+    type Kind = kind::Date;
+}
+
+impl DefaultKind for NaiveDate {
+    type Kind = kind::Date;
+}
+
+impl DefaultKind for PageDatePropertyParameter {
+    type Kind = kind::Date;
+}
+
+// # --------------------------------------------------------------------------------
+//
+// checkbox
+//
+// # --------------------------------------------------------------------------------
+
+impl PropertyValue<kind::Checkbox> for bool {
+    fn from_property(name: &str, property: &PageProperty) -> Result<Self, PropertyError> {
+        match property {
+            PageProperty::Checkbox(checkbox) => Ok(checkbox.checkbox),
+            _ => Err(mismatch::<kind::Checkbox>(name, property)),
+        }
+    }
+
+    fn to_property(&self) -> Option<PageProperty> {
+        Some(PageProperty::Checkbox(PageCheckboxProperty::from(*self)))
+    }
+}
+
+impl DefaultKind for bool {
+    type Kind = kind::Checkbox;
+}
+
+// # --------------------------------------------------------------------------------
+//
+// url / email / phone_number
+//
+// # --------------------------------------------------------------------------------
+
+impl PropertyValue<kind::Url> for String {
+    fn from_property(name: &str, property: &PageProperty) -> Result<Self, PropertyError> {
+        match property {
+            PageProperty::Url(url) => url.url.clone().ok_or_else(|| empty(name)),
+            _ => Err(mismatch::<kind::Url>(name, property)),
+        }
+    }
+
+    fn to_property(&self) -> Option<PageProperty> {
+        Some(PageProperty::Url(PageUrlProperty::from(self)))
+    }
+}
+
+impl PropertyValue<kind::Email> for String {
+    fn from_property(name: &str, property: &PageProperty) -> Result<Self, PropertyError> {
+        match property {
+            PageProperty::Email(email) => email.email.clone().ok_or_else(|| empty(name)),
+            _ => Err(mismatch::<kind::Email>(name, property)),
+        }
+    }
+
+    fn to_property(&self) -> Option<PageProperty> {
+        Some(PageProperty::Email(PageEmailProperty::from(self)))
+    }
+}
+
+impl PropertyValue<kind::PhoneNumber> for String {
+    fn from_property(name: &str, property: &PageProperty) -> Result<Self, PropertyError> {
+        match property {
+            PageProperty::PhoneNumber(phone_number) => {
+                phone_number.phone_number.clone().ok_or_else(|| empty(name))
+            }
+            _ => Err(mismatch::<kind::PhoneNumber>(name, property)),
+        }
+    }
+
+    fn to_property(&self) -> Option<PageProperty> {
+        Some(PageProperty::PhoneNumber(PagePhoneNumberProperty::from(
+            self,
+        )))
+    }
+}
+
+// # --------------------------------------------------------------------------------
+//
+// relation
+//
+// # --------------------------------------------------------------------------------
+
+/// The IDs of the related pages.
+impl PropertyValue<kind::Relation> for Vec<String> {
+    fn from_property(name: &str, property: &PageProperty) -> Result<Self, PropertyError> {
+        match property {
+            PageProperty::Relation(relation) => Ok(relation
+                .relation
+                .iter()
+                .map(|page| page.id.clone())
+                .collect()),
+            _ => Err(mismatch::<kind::Relation>(name, property)),
+        }
+    }
+
+    fn to_property(&self) -> Option<PageProperty> {
+        Some(PageProperty::Relation(PageRelationProperty {
+            id: None,
+            relation: self
+                .iter()
+                .map(|id| PageRelationPropertyParameter { id: id.clone() })
+                .collect(),
+            has_more: false,
+        }))
+    }
+}
+
+// # --------------------------------------------------------------------------------
+//
+// unit test
+//
+// # --------------------------------------------------------------------------------
+
+#[cfg(all(test, feature = "derive"))]
+mod unit_tests {
+    use super::*;
+    use crate::NotionRow;
+
+    #[derive(NotionRow, Debug, PartialEq)]
+    struct Task {
+        #[notion(rename = "Name", title)]
+        name: String,
+
+        #[notion(rename = "Status", status)]
+        status: String,
+
+        #[notion(rename = "Estimate")]
+        estimate: Option<f64>,
+
+        #[notion(rename = "Points")]
+        points: u32,
+
+        #[notion(rename = "Tags")]
+        tags: Vec<String>,
+
+        #[notion(rename = "Due")]
+        due: Option<NaiveDate>,
+
+        #[notion(rename = "Done")]
+        done: bool,
+
+        #[notion(rename = "Website", url)]
+        website: Option<String>,
+
+        #[notion(rename = "Blocked by", relation)]
+        blocked_by: Vec<String>,
+
+        notes: String,
+
+        #[notion(skip)]
+        local: u8,
+    }
+
+    fn properties() -> HashMap<String, PageProperty> {
+        let json_data = r#"
+        {
+            "Name": { "id": "title", "type": "title", "title": [
+                { "type": "text", "text": { "content": "Write docs", "link": null },
+                  "annotations": { "bold": false, "italic": false, "strikethrough": false, "underline": false, "code": false, "color": "default" },
+                  "plain_text": "Write docs", "href": null }
+            ] },
+            "Status": { "id": "a", "type": "status", "status": { "id": "s", "name": "In progress", "color": "blue" } },
+            "Estimate": { "id": "b", "type": "number", "number": null },
+            "Points": { "id": "c", "type": "number", "number": 3 },
+            "Tags": { "id": "d", "type": "multi_select", "multi_select": [
+                { "id": "t1", "name": "docs", "color": "red" },
+                { "id": "t2", "name": "rust", "color": "gray" }
+            ] },
+            "Due": { "id": "e", "type": "date", "date": { "start": "2024-10-01", "end": null, "time_zone": null } },
+            "Done": { "id": "f", "type": "checkbox", "checkbox": true },
+            "Website": { "id": "g", "type": "url", "url": null },
+            "Blocked by": { "id": "h", "type": "relation", "relation": [{ "id": "page-1" }], "has_more": false },
+            "notes": { "id": "i", "type": "rich_text", "rich_text": [] }
+        }
+        "#;
+
+        serde_json::from_str(json_data).unwrap()
+    }
+
+    #[test]
+    fn read_row() {
+        let task = Task::from_properties(&properties()).unwrap();
+
+        assert_eq!(
+            task,
+            Task {
+                name: "Write docs".to_string(),
+                status: "In progress".to_string(),
+                estimate: None,
+                points: 3,
+                tags: vec!["docs".to_string(), "rust".to_string()],
+                due: NaiveDate::from_ymd_opt(2024, 10, 1),
+                done: true,
+                website: None,
+                blocked_by: vec!["page-1".to_string()],
+                notes: String::new(),
+                local: 0,
+            }
+        );
+    }
+
+    #[test]
+    fn read_row_errors() {
+        let mut properties = properties();
+        properties.remove("Points");
+
+        match Task::from_properties(&properties) {
+            Err(Error::Property(error)) => {
+                assert_eq!(
+                    *error,
+                    PropertyError::Missing {
+                        property: "Points".to_string()
+                    }
+                )
+            }
+            other => panic!("unexpected result: {:?}", other),
+        }
+
+        let mut properties = self::properties();
+        let done = properties.remove("Done").unwrap();
+        properties.insert("Points".to_string(), done);
+
+        let error = Task::from_properties(&properties).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "property `Points` is expected to be `number`, but it is `checkbox`"
+        );
+    }
+
+    #[test]
+    fn read_invalid_integers() {
+        for (number, value) in [
+            (serde_json::json!(3.7), "3.7"),
+            (serde_json::json!(-1), "-1"),
+            (serde_json::json!(1e10), "10000000000"),
+        ] {
+            let mut properties = properties();
+            properties.insert(
+                "Points".to_string(),
+                serde_json::from_value(
+                    serde_json::json!({ "id": "c", "type": "number", "number": number }),
+                )
+                .unwrap(),
+            );
+
+            match Task::from_properties(&properties) {
+                Err(Error::Property(error)) => assert_eq!(
+                    *error,
+                    PropertyError::InvalidNumber {
+                        property: "Points".to_string(),
+                        value: value.to_string(),
+                        expected: "u32",
+                    }
+                ),
+                other => panic!("unexpected result: {:?}", other),
+            }
+        }
+
+        let number = PageProperty::Number(PageNumberProperty::default().number(-3.0));
+
+        assert_eq!(
+            <i32 as PropertyValue<kind::Number>>::from_property("Points", &number),
+            Ok(-3)
+        );
+        assert_eq!(
+            <f32 as PropertyValue<kind::Number>>::from_property("Points", &number),
+            Ok(-3.0)
+        );
+    }
+
+    #[test]
+    fn write_row() {
+        let task = Task::from_properties(&properties()).unwrap();
+
+        let properties = task.to_properties();
+
+        assert!(!properties.contains_key("local"));
+
+        let json = serde_json::to_value(&properties).unwrap();
+
+        assert_eq!(json["Name"]["type"], "title");
+        assert_eq!(json["Name"]["title"][0]["text"]["content"], "Write docs");
+        assert_eq!(json["Status"]["status"]["name"], "In progress");
+        assert_eq!(json["Estimate"]["number"], serde_json::Value::Null);
+        assert_eq!(json["Points"]["number"], 3.0);
+        assert_eq!(json["Tags"]["multi_select"][1]["name"], "rust");
+        assert_eq!(json["Due"]["date"]["start"], "2024-10-01");
+        assert_eq!(json["Done"]["checkbox"], true);
+        assert_eq!(json["Blocked by"]["relation"][0]["id"], "page-1");
+        assert_eq!(json["notes"]["type"], "rich_text");
+
+        let round_trip = Task::from_properties(
+            &serde_json::from_value::<HashMap<String, PageProperty>>(json).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(round_trip, task);
+    }
+}
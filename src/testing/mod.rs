@@ -0,0 +1,528 @@
+//! An in-memory fake of the Notion API for tests that run without a workspace or a token.
+//!
+//! [`FakeNotion`] serves pages, databases (including queries with [`Filter`](crate::filter::Filter)
+//! and [`Sort`](crate::database::Sort)), blocks, users and search over HTTP on localhost.
+//! Nothing is persisted; every server starts with an empty workspace.
+//!
+//! ```
+//! use notionrs::testing::FakeNotion;
+//!
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let notion = FakeNotion::start().await?;
+//!
+//! // Integrations cannot create pages at the top level of a workspace,
+//! // so add a page to be the parent of the pages and databases under test.
+//! let page_id = notion.add_page("Sandbox");
+//!
+//! let client = notion.client();
+//!
+//! let page = client.get_page().page_id(&page_id).send().await?;
+//!
+//! assert_eq!(page.get_title().as_deref(), Some("Sandbox"));
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Formulas and rollups are not computed, and filters are evaluated with
+//! [`Filter::matches`](crate::filter::Filter::matches).
+
+mod store;
+
+use std::{
+    collections::HashMap,
+    convert::Infallible,
+    net::SocketAddr,
+    sync::{Arc, Mutex, PoisonError},
+};
+
+use http_body_util::{BodyExt, Full};
+use hyper::body::{Bytes, Incoming};
+
+use self::store::{Failure, Store};
+
+/// A fake Notion API server listening on `127.0.0.1`.
+///
+/// The server runs on the tokio runtime that started it and stops when this is dropped.
+#[derive(Debug)]
+pub struct FakeNotion {
+    address: SocketAddr,
+
+    store: Arc<Mutex<Store>>,
+
+    server: tokio::task::JoinHandle<()>,
+}
+
+impl FakeNotion {
+    /// Starts a server on a free port of `127.0.0.1`.
+    pub async fn start() -> Result<Self, std::io::Error> {
+        let listener = tokio::net::TcpListener::bind(("127.0.0.1", 0)).await?;
+
+        let address = listener.local_addr()?;
+
+        let store = Arc::new(Mutex::new(Store::default()));
+
+        let server = tokio::spawn(serve(listener, store.clone()));
+
+        Ok(Self {
+            address,
+            store,
+            server,
+        })
+    }
+
+    /// The base URL to pass to [`ClientBuilder::base_url`](crate::client::ClientBuilder::base_url),
+    /// e.g. `http://127.0.0.1:49152/v1`.
+    pub fn base_url(&self) -> String {
+        format!("http://{}/v1", self.address)
+    }
+
+    /// A client that sends requests to this server.
+    pub fn client(&self) -> crate::client::Client {
+        crate::client::Client::builder()
+            .secret("secret_fake")
+            .base_url(self.base_url())
+            .build()
+    }
+
+    /// The ID of the bot user that is returned by [`Client::get_self`](crate::client::Client::get_self).
+    pub fn bot_id(&self) -> String {
+        self.lock().bot_id()
+    }
+
+    /// Adds a person to the workspace and returns their ID.
+    pub fn add_user<T: AsRef<str>>(&self, name: T, email: Option<&str>) -> String {
+        self.lock().add_user(name.as_ref(), email)
+    }
+
+    /// Adds a page at the top level of the workspace and returns its ID.
+    ///
+    /// Pages and databases can only be created under an existing page or database,
+    /// so tests usually start by adding one.
+    pub fn add_page<T: AsRef<str>>(&self, title: T) -> String {
+        self.lock().add_page(title.as_ref())
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Store> {
+        self.store.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl Drop for FakeNotion {
+    fn drop(&mut self) {
+        self.server.abort();
+    }
+}
+
+/// Accepts connections until the server is dropped.
+async fn serve(listener: tokio::net::TcpListener, store: Arc<Mutex<Store>>) {
+    loop {
+        let Ok((stream, _)) = listener.accept().await else {
+            continue;
+        };
+
+        let store = store.clone();
+
+        tokio::spawn(async move {
+            let service = hyper::service::service_fn(move |request| handle(store.clone(), request));
+
+            // Connection errors (e.g. a client going away) only concern that connection.
+            let _ = hyper::server::conn::http1::Builder::new()
+                .serve_connection(hyper_util::rt::TokioIo::new(stream), service)
+                .await;
+        });
+    }
+}
+
+async fn handle(
+    store: Arc<Mutex<Store>>,
+    request: hyper::Request<Incoming>,
+) -> Result<hyper::Response<Full<Bytes>>, Infallible> {
+    let (parts, body) = request.into_parts();
+
+    let outcome = match body.collect().await {
+        Ok(body) => route(&store, &parts, &body.to_bytes()),
+        Err(e) => Err(Failure::invalid_json(e.to_string())),
+    };
+
+    let (status, body) = match outcome {
+        Ok(body) => (200, body),
+        Err(failure) => (failure.status, failure.to_json()),
+    };
+
+    let response = hyper::Response::builder()
+        .status(status)
+        .header("Content-Type", "application/json")
+        .body(Full::new(Bytes::from(body.to_string())))
+        .unwrap_or_else(|_| hyper::Response::new(Full::new(Bytes::new())));
+
+    Ok(response)
+}
+
+/// Checks the headers of the request and passes it to the store.
+fn route(
+    store: &Mutex<Store>,
+    parts: &hyper::http::request::Parts,
+    body: &[u8],
+) -> Result<serde_json::Value, Failure> {
+    let authorized = parts
+        .headers
+        .get("Authorization")
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "))
+        .is_some_and(|token| !token.is_empty());
+
+    if !authorized {
+        return Err(Failure::unauthorized());
+    }
+
+    if !parts.headers.contains_key("Notion-Version") {
+        return Err(Failure::missing_version());
+    }
+
+    let path = parts.uri.path().strip_prefix("/v1").unwrap_or_default();
+
+    let query = parts
+        .uri
+        .query()
+        .into_iter()
+        .flat_map(|query| query.split('&'))
+        .filter_map(|pair| pair.split_once('='))
+        .map(|(key, value)| (key.to_string(), value.to_string()))
+        .collect::<HashMap<_, _>>();
+
+    let body = if body.is_empty() {
+        serde_json::Value::Null
+    } else {
+        serde_json::from_slice(body).map_err(|e| Failure::invalid_json(e.to_string()))?
+    };
+
+    store.lock().unwrap_or_else(PoisonError::into_inner).handle(
+        parts.method.as_str(),
+        path,
+        &query,
+        body,
+    )
+}
+
+// # --------------------------------------------------------------------------------
+//
+// unit test
+//
+// # --------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod unit_tests {
+    use super::*;
+    use crate::{
+        block::{Block, BulletedListItemBlock, ParagraphBlock},
+        database::{
+            DatabaseCreatedTimeProperty, DatabaseLastEditedTimeProperty, DatabaseNumberProperty,
+            DatabaseProperty, DatabaseSelectProperty, DatabaseTitleProperty, Sort,
+        },
+        error::Error,
+        filter::Filter,
+        page::{PageNumberProperty, PageProperty, PageSelectProperty, PageTitleProperty},
+        RichText,
+    };
+
+    async fn create_database(notion: &FakeNotion) -> String {
+        let parent_id = notion.add_page("Sandbox");
+
+        let properties = HashMap::from([
+            (
+                "Name".to_string(),
+                DatabaseProperty::Title(DatabaseTitleProperty::default()),
+            ),
+            (
+                "Price".to_string(),
+                DatabaseProperty::Number(DatabaseNumberProperty::default()),
+            ),
+            (
+                "Category".to_string(),
+                DatabaseProperty::Select(DatabaseSelectProperty::default()),
+            ),
+            (
+                "Created".to_string(),
+                DatabaseProperty::CreatedTime(DatabaseCreatedTimeProperty::default()),
+            ),
+            (
+                "Edited".to_string(),
+                DatabaseProperty::LastEditedTime(DatabaseLastEditedTimeProperty::default()),
+            ),
+        ]);
+
+        let database = notion
+            .client()
+            .create_database()
+            .page_id(parent_id)
+            .title(vec![RichText::from("Vegetables")])
+            .properties(properties)
+            .send()
+            .await
+            .unwrap();
+
+        database.id
+    }
+
+    async fn create_row(notion: &FakeNotion, database_id: &str, name: &str, price: Option<f64>) {
+        let mut properties = HashMap::from([
+            (
+                "Name".to_string(),
+                PageProperty::Title(PageTitleProperty::from(name)),
+            ),
+            (
+                "Category".to_string(),
+                PageProperty::Select(PageSelectProperty::from("Leaf")),
+            ),
+        ]);
+
+        if let Some(price) = price {
+            properties.insert(
+                "Price".to_string(),
+                PageProperty::Number(PageNumberProperty::from(price)),
+            );
+        }
+
+        notion
+            .client()
+            .create_page()
+            .database_id(database_id)
+            .properties(properties)
+            .send()
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn query_database() {
+        let notion = FakeNotion::start().await.unwrap();
+
+        let database_id = create_database(&notion).await;
+
+        create_row(&notion, &database_id, "Kale", Some(3.0)).await;
+        create_row(&notion, &database_id, "Spinach", Some(5.0)).await;
+        create_row(&notion, &database_id, "Lettuce", None).await;
+        create_row(&notion, &database_id, "Chard", Some(1.0)).await;
+        create_row(&notion, &database_id, "Arugula", Some(3.0)).await;
+
+        let response = notion
+            .client()
+            .query_database()
+            .database_id(&database_id)
+            .filter(Filter::or(vec![
+                Filter::number_greater_than("Price", 2),
+                Filter::number_is_empty("Price"),
+            ]))
+            .sort_by(Sort::desc("Price"))
+            .then_by(Sort::asc("Name"))
+            .send()
+            .await
+            .unwrap();
+
+        let names = response
+            .results
+            .iter()
+            .map(|page| page.get_title().unwrap())
+            .collect::<Vec<_>>();
+
+        assert_eq!(names, ["Spinach", "Arugula", "Kale", "Lettuce"]);
+
+        // The option is added to the schema, as Notion does.
+        let database = notion
+            .client()
+            .retrieve_database()
+            .database_id(&database_id)
+            .send()
+            .await
+            .unwrap();
+
+        assert_eq!(
+            serde_json::to_value(&database.properties["Category"]).unwrap()["select"]["options"][0]
+                ["name"],
+            "Leaf"
+        );
+    }
+
+    #[tokio::test]
+    async fn date_filter_on_timestamp_properties() {
+        let notion = FakeNotion::start().await.unwrap();
+
+        let database_id = create_database(&notion).await;
+
+        create_row(&notion, &database_id, "Kale", Some(3.0)).await;
+
+        let today = chrono::Utc::now().date_naive();
+
+        for (filter, expected) in [
+            (Filter::date_on_or_before("Created", today), 1),
+            (Filter::date_before("Created", today), 0),
+            (Filter::date_past_week("Edited"), 1),
+            (Filter::date_next_week("Edited"), 1),
+        ] {
+            let response = notion
+                .client()
+                .query_database()
+                .database_id(&database_id)
+                .filter(filter.clone())
+                .send()
+                .await
+                .unwrap();
+
+            assert_eq!(response.results.len(), expected, "{:?}", filter);
+        }
+    }
+
+    #[tokio::test]
+    async fn invalid_filter_is_rejected() {
+        let notion = FakeNotion::start().await.unwrap();
+
+        let database_id = create_database(&notion).await;
+
+        let error = notion
+            .client()
+            .query_database()
+            .database_id(&database_id)
+            .filter(Filter::number_greater_than("Prise", 2))
+            .send()
+            .await
+            .unwrap_err();
+
+        let Error::Api(error) = error else {
+            panic!("unexpected error: {}", error)
+        };
+
+        assert_eq!(error.status(), 400);
+        assert!(error.message().contains("`Prise` does not exist"));
+    }
+
+    #[tokio::test]
+    async fn blocks() {
+        let notion = FakeNotion::start().await.unwrap();
+
+        let client = notion.client();
+
+        let page_id = notion.add_page("Sandbox");
+
+        let appended = client
+            .append_block_children()
+            .block_id(&page_id)
+            .children(vec![
+                Block::Paragraph {
+                    paragraph: ParagraphBlock::from("first"),
+                },
+                Block::BulletedListItem {
+                    bulleted_list_item: BulletedListItemBlock::from(vec![RichText::from("third")])
+                        .children(vec![Block::Paragraph {
+                            paragraph: ParagraphBlock::from("nested"),
+                        }]),
+                },
+            ])
+            .send()
+            .await
+            .unwrap();
+
+        assert!(appended.results[1].has_children);
+
+        client
+            .append_block_children()
+            .block_id(&page_id)
+            .after(&appended.results[0].id)
+            .children(vec![Block::Paragraph {
+                paragraph: ParagraphBlock::from("second"),
+            }])
+            .send()
+            .await
+            .unwrap();
+
+        let texts = |response: crate::list_response::ListResponse<crate::block::BlockResponse>| {
+            response
+                .results
+                .iter()
+                .map(|block| block.block.to_string())
+                .collect::<Vec<_>>()
+        };
+
+        let children = client
+            .get_block_children()
+            .block_id(&page_id)
+            .send()
+            .await
+            .unwrap();
+
+        assert_eq!(texts(children), ["first", "second", "third"]);
+
+        client
+            .delete_block()
+            .block_id(&appended.results[0].id)
+            .send()
+            .await
+            .unwrap();
+
+        let children = client
+            .get_block_children()
+            .block_id(&page_id)
+            .send()
+            .await
+            .unwrap();
+
+        assert_eq!(texts(children), ["second", "third"]);
+
+        let nested = client
+            .get_block_children()
+            .block_id(&appended.results[1].id)
+            .send()
+            .await
+            .unwrap();
+
+        assert_eq!(texts(nested), ["nested"]);
+    }
+
+    #[tokio::test]
+    async fn search_and_users() {
+        let notion = FakeNotion::start().await.unwrap();
+
+        let client = notion.client();
+
+        notion.add_page("Kale recipes");
+        notion.add_page("Spinach recipes");
+        notion.add_page("Notes");
+
+        let response = client.search_page().query("RECIPES").send().await.unwrap();
+
+        assert_eq!(response.results.len(), 2);
+
+        let user_id = notion.add_user("Kale", Some("kale@example.com"));
+
+        let users = client.list_users().send().await.unwrap();
+
+        assert_eq!(users.results.len(), 2);
+
+        let user = client.get_user().user_id(&user_id).send().await.unwrap();
+
+        assert!(matches!(user, crate::User::Person(_)));
+
+        let me = client.get_self().send().await.unwrap();
+
+        assert_eq!(me.id, notion.bot_id());
+    }
+
+    #[tokio::test]
+    async fn object_not_found() {
+        let notion = FakeNotion::start().await.unwrap();
+
+        let error = notion
+            .client()
+            .get_page()
+            .page_id("d9824bdc84454327be8b5b47500af6ce")
+            .send()
+            .await
+            .unwrap_err();
+
+        let Error::Api(error) = error else {
+            panic!("unexpected error: {}", error)
+        };
+
+        assert_eq!(error.status(), 404);
+    }
+}
@@ -0,0 +1,1297 @@
+//! The in-memory state of [`FakeNotion`](super::FakeNotion) and the handling of each endpoint.
+//!
+//! Objects are stored as the JSON returned by the Notion API, so that the responses
+//! are deserialized by the same code as the responses of the real API.
+
+use std::{cmp::Ordering, collections::HashMap};
+
+use serde_json::{json, Map, Value};
+
+use crate::{
+    database::{DatabaseResponse, Sort, SortDirection, SortTimestamp},
+    filter::Filter,
+    page::{PageProperty, PageResponse},
+};
+
+/// The maximum (and default) number of items returned in one page of a list.
+const MAX_PAGE_SIZE: usize = 100;
+
+/// Property types whose values are computed by Notion and cannot be set.
+const COMPUTED_TYPES: [&str; 7] = [
+    "created_time",
+    "created_by",
+    "last_edited_time",
+    "last_edited_by",
+    "unique_id",
+    "formula",
+    "rollup",
+];
+
+/// An error response, in the shape of the errors of the Notion API.
+#[derive(Debug)]
+pub(super) struct Failure {
+    pub(super) status: u16,
+    code: &'static str,
+    message: String,
+}
+
+impl Failure {
+    fn new<T: Into<String>>(status: u16, code: &'static str, message: T) -> Self {
+        Self {
+            status,
+            code,
+            message: message.into(),
+        }
+    }
+
+    pub(super) fn unauthorized() -> Self {
+        Self::new(401, "unauthorized", "API token is invalid.")
+    }
+
+    pub(super) fn missing_version() -> Self {
+        Self::new(
+            400,
+            "missing_version",
+            "Notion-Version header failed validation: Notion-Version header should be defined.",
+        )
+    }
+
+    pub(super) fn invalid_json<T: Into<String>>(message: T) -> Self {
+        Self::new(400, "invalid_json", message)
+    }
+
+    fn invalid_url() -> Self {
+        Self::new(400, "invalid_request_url", "Invalid request URL.")
+    }
+
+    fn validation<T: Into<String>>(message: T) -> Self {
+        Self::new(400, "validation_error", message)
+    }
+
+    fn not_found(object: &str, id: &str) -> Self {
+        Self::new(
+            404,
+            "object_not_found",
+            format!(
+                "Could not find {} with ID: {}. Make sure the relevant pages and databases are shared with your integration.",
+                object, id
+            ),
+        )
+    }
+
+    fn internal<T: std::fmt::Display>(error: T) -> Self {
+        Self::new(500, "internal_server_error", error.to_string())
+    }
+
+    pub(super) fn to_json(&self) -> Value {
+        json!({
+            "object": "error",
+            "status": self.status,
+            "code": self.code,
+            "message": self.message,
+        })
+    }
+}
+
+type Outcome = Result<Value, Failure>;
+
+#[derive(Debug)]
+pub(super) struct Store {
+    /// The integration making the requests (`GET /v1/users/me`).
+    bot: Value,
+
+    /// All users of the workspace, including the bot.
+    users: Vec<Value>,
+
+    pages: HashMap<String, Value>,
+
+    databases: HashMap<String, Value>,
+
+    blocks: HashMap<String, Value>,
+
+    /// The IDs of the child blocks of each page or block, in order.
+    children: HashMap<String, Vec<String>>,
+
+    /// The IDs of pages and databases, in the order they were created.
+    created: Vec<String>,
+
+    /// The last `unique_id` number given in each database.
+    unique_ids: HashMap<String, u64>,
+}
+
+impl Default for Store {
+    fn default() -> Self {
+        let bot = json!({
+            "object": "user",
+            "id": new_id(),
+            "name": "notionrs",
+            "avatar_url": null,
+            "type": "bot",
+            "bot": {
+                "owner": { "type": "workspace", "workspace": true },
+                "workspace_name": "Fake Workspace"
+            }
+        });
+
+        Self {
+            users: vec![bot.clone()],
+            bot,
+            pages: HashMap::new(),
+            databases: HashMap::new(),
+            blocks: HashMap::new(),
+            children: HashMap::new(),
+            created: Vec::new(),
+            unique_ids: HashMap::new(),
+        }
+    }
+}
+
+impl Store {
+    pub(super) fn bot_id(&self) -> String {
+        id_of(&self.bot)
+    }
+
+    pub(super) fn add_user(&mut self, name: &str, email: Option<&str>) -> String {
+        let id = new_id();
+
+        self.users.push(json!({
+            "object": "user",
+            "id": id,
+            "name": name,
+            "avatar_url": null,
+            "type": "person",
+            "person": { "email": email }
+        }));
+
+        id
+    }
+
+    /// Adds a page at the top level of the workspace, to be used as the parent
+    /// of pages and databases created through the API.
+    pub(super) fn add_page(&mut self, title: &str) -> String {
+        let body = json!({
+            "parent": { "type": "workspace", "workspace": true },
+            "properties": {
+                "title": {
+                    "type": "title",
+                    "title": [{
+                        "type": "text",
+                        "text": { "content": title, "link": null },
+                        "annotations": {
+                            "bold": false,
+                            "italic": false,
+                            "strikethrough": false,
+                            "underline": false,
+                            "code": false,
+                            "color": "default"
+                        },
+                        "plain_text": title,
+                        "href": null
+                    }]
+                }
+            }
+        });
+
+        let page = self
+            .create_page(body)
+            .expect("a page in the workspace is always valid");
+
+        id_of(&page)
+    }
+
+    /// Handles a request to `/v1{path}`.
+    pub(super) fn handle(
+        &mut self,
+        method: &str,
+        path: &str,
+        query: &HashMap<String, String>,
+        body: Value,
+    ) -> Outcome {
+        let segments = path.trim_matches('/').split('/').collect::<Vec<_>>();
+
+        match (method, segments.as_slice()) {
+            ("GET", ["users"]) => self.list_users(query),
+            ("GET", ["users", "me"]) => Ok(self.bot.clone()),
+            ("GET", ["users", id]) => self.get_user(id),
+            ("POST", ["pages"]) => self.create_page(body),
+            ("GET", ["pages", id]) => self.get_page(id),
+            ("PATCH", ["pages", id]) => self.update_page(id, body),
+            ("GET", ["pages", id, "properties", property_id]) => {
+                self.get_page_property(id, property_id, query)
+            }
+            ("POST", ["databases"]) => self.create_database(body),
+            ("GET", ["databases", id]) => self.get_database(id),
+            ("PATCH", ["databases", id]) => self.update_database(id, body),
+            ("POST", ["databases", id, "query"]) => self.query_database(id, body),
+            ("GET", ["blocks", id]) => self.get_block(id),
+            ("PATCH", ["blocks", id]) => self.update_block(id, body),
+            ("DELETE", ["blocks", id]) => self.delete_block(id),
+            ("GET", ["blocks", id, "children"]) => self.list_children(id, query),
+            ("PATCH", ["blocks", id, "children"]) => self.append_children(id, body),
+            ("POST", ["search"]) => self.search(body),
+            _ => Err(Failure::invalid_url()),
+        }
+    }
+
+    // # --------------------------------------------------------------------------------
+    //
+    // User
+    //
+    // # --------------------------------------------------------------------------------
+
+    fn list_users(&self, query: &HashMap<String, String>) -> Outcome {
+        let list = paginate(
+            self.users.clone(),
+            query.get("start_cursor").map(String::as_str),
+            query.get("page_size").and_then(|size| size.parse().ok()),
+        )?;
+
+        Ok(with_type(list, "user"))
+    }
+
+    fn get_user(&self, id: &str) -> Outcome {
+        let id = normalize_id(id);
+
+        self.users
+            .iter()
+            .find(|user| id_of(user) == id)
+            .cloned()
+            .ok_or_else(|| Failure::not_found("user", &id))
+    }
+
+    // # --------------------------------------------------------------------------------
+    //
+    // Page
+    //
+    // # --------------------------------------------------------------------------------
+
+    fn get_page(&self, id: &str) -> Outcome {
+        let id = normalize_id(id);
+
+        self.pages
+            .get(&id)
+            .cloned()
+            .ok_or_else(|| Failure::not_found("page", &id))
+    }
+
+    fn create_page(&mut self, body: Value) -> Outcome {
+        let id = new_id();
+        let now = now();
+
+        let mut parent = body["parent"].clone();
+
+        let database_id = match parent["type"].as_str() {
+            Some("database_id") => {
+                let database_id = normalize_id(parent["database_id"].as_str().unwrap_or_default());
+
+                if !self.databases.contains_key(&database_id) {
+                    return Err(Failure::not_found("database", &database_id));
+                }
+
+                parent["database_id"] = json!(database_id);
+                Some(database_id)
+            }
+            Some("page_id") => {
+                let page_id = normalize_id(parent["page_id"].as_str().unwrap_or_default());
+
+                if !self.pages.contains_key(&page_id) {
+                    return Err(Failure::not_found("page", &page_id));
+                }
+
+                parent["page_id"] = json!(page_id);
+                None
+            }
+            Some("workspace") => None,
+            _ => {
+                return Err(Failure::validation(
+                    "body.parent should be a database, a page or the workspace.",
+                ))
+            }
+        };
+
+        let mut page = json!({
+            "object": "page",
+            "id": id,
+            "created_time": now,
+            "last_edited_time": now,
+            "created_by": user_reference(&self.bot),
+            "last_edited_by": user_reference(&self.bot),
+            "cover": body["cover"],
+            "icon": body["icon"],
+            "parent": parent,
+            "archived": false,
+            "in_trash": false,
+            "properties": {},
+            "url": format!("https://www.notion.so/{}", id.replace('-', "")),
+            "public_url": null
+        });
+
+        let properties = body["properties"].as_object().cloned().unwrap_or_default();
+
+        self.set_properties(&mut page, database_id.as_deref(), properties)?;
+
+        // A page is also a `child_page` block, which can be retrieved and deleted as a block.
+        let child_page = json!({
+            "type": "child_page",
+            "child_page": { "title": title_of(&page) }
+        });
+
+        self.insert_block(&id, &parent, child_page);
+
+        if parent["type"] == "page_id" {
+            let parent_id = normalize_id(parent["page_id"].as_str().unwrap_or_default());
+            self.children.entry(parent_id).or_default().push(id.clone());
+        }
+
+        self.pages.insert(id.clone(), page);
+        self.created.push(id.clone());
+
+        if let Some(Value::Array(children)) = body.get("children") {
+            self.add_children(&id, children.clone(), None)?;
+        }
+
+        self.get_page(&id)
+    }
+
+    fn update_page(&mut self, id: &str, body: Value) -> Outcome {
+        let id = normalize_id(id);
+
+        let mut page = self.get_page(&id)?;
+
+        let database_id = match page["parent"]["type"].as_str() {
+            Some("database_id") => page["parent"]["database_id"].as_str().map(String::from),
+            _ => None,
+        };
+
+        let properties = body["properties"].as_object().cloned().unwrap_or_default();
+
+        page["last_edited_time"] = json!(now());
+        page["last_edited_by"] = user_reference(&self.bot);
+
+        self.set_properties(&mut page, database_id.as_deref(), properties)?;
+
+        for key in ["icon", "cover", "archived", "in_trash"] {
+            if let Some(value) = body.get(key) {
+                page[key] = value.clone();
+            }
+        }
+
+        if let Some(block) = self.blocks.get_mut(&id) {
+            block["child_page"]["title"] = json!(title_of(&page));
+            block["archived"] = page["archived"].clone();
+            block["in_trash"] = page["in_trash"].clone();
+        }
+
+        self.pages.insert(id.clone(), page);
+
+        self.get_page(&id)
+    }
+
+    fn get_page_property(
+        &self,
+        id: &str,
+        property_id: &str,
+        query: &HashMap<String, String>,
+    ) -> Outcome {
+        let page = self.get_page(id)?;
+
+        let property = page["properties"]
+            .as_object()
+            .and_then(|properties| {
+                properties
+                    .values()
+                    .find(|property| property["id"] == property_id)
+            })
+            .ok_or_else(|| Failure::not_found("property", property_id))?;
+
+        let r#type = property["type"].as_str().unwrap_or_default();
+
+        let mut item = property.clone();
+        item["object"] = json!("property_item");
+
+        if !matches!(r#type, "title" | "rich_text" | "relation" | "people") {
+            return Ok(item);
+        }
+
+        let items = property[r#type]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .map(|element| {
+                let mut item = Map::new();
+                item.insert("object".to_string(), json!("property_item"));
+                item.insert("id".to_string(), json!(property_id));
+                item.insert("type".to_string(), json!(r#type));
+                item.insert(r#type.to_string(), element.clone());
+                Value::Object(item)
+            })
+            .collect();
+
+        let mut list = paginate(
+            items,
+            query.get("start_cursor").map(String::as_str),
+            query.get("page_size").and_then(|size| size.parse().ok()),
+        )?;
+
+        let mut summary = Map::new();
+        summary.insert("id".to_string(), json!(property_id));
+        summary.insert("next_url".to_string(), Value::Null);
+        summary.insert("type".to_string(), json!(r#type));
+        summary.insert(r#type.to_string(), json!({}));
+
+        list["type"] = json!("property_item");
+        list["property_item"] = Value::Object(summary);
+
+        Ok(list)
+    }
+
+    /// Sets the given property values on the page, and fills in the other properties
+    /// of the database (empty values, or the values computed by Notion).
+    fn set_properties(
+        &mut self,
+        page: &mut Value,
+        database_id: Option<&str>,
+        properties: Map<String, Value>,
+    ) -> Result<(), Failure> {
+        let Some(database_id) = database_id else {
+            for (name, mut value) in properties {
+                if value["type"] == "title" {
+                    value["id"] = json!("title");
+                }
+                page["properties"][name] = value;
+            }
+            return Ok(());
+        };
+
+        for (name, mut value) in properties {
+            let definition = self.databases[database_id]["properties"]
+                .get(&name)
+                .cloned()
+                .ok_or_else(|| {
+                    Failure::validation(format!("{} is not a property that exists.", name))
+                })?;
+
+            let r#type = definition["type"].as_str().unwrap_or_default();
+
+            if value["type"] != r#type {
+                return Err(Failure::validation(format!(
+                    "{} is expected to be {}.",
+                    name, r#type
+                )));
+            }
+
+            if COMPUTED_TYPES.contains(&r#type) {
+                return Err(Failure::validation(format!(
+                    "{} is a {} property, which cannot be set.",
+                    name, r#type
+                )));
+            }
+
+            match r#type {
+                "select" | "status" if !value[r#type].is_null() => {
+                    let option = self.resolve_option(database_id, &name, &value[r#type])?;
+                    value[r#type] = option;
+                }
+                "multi_select" => {
+                    let options = value[r#type]
+                        .as_array()
+                        .into_iter()
+                        .flatten()
+                        .map(|option| self.resolve_option(database_id, &name, option))
+                        .collect::<Result<Vec<_>, _>>()?;
+                    value[r#type] = Value::Array(options);
+                }
+                _ => {}
+            }
+
+            value["id"] = definition["id"].clone();
+            page["properties"][name] = value;
+        }
+
+        let definitions = self.databases[database_id]["properties"]
+            .as_object()
+            .cloned()
+            .unwrap_or_default();
+
+        for (name, definition) in definitions {
+            let r#type = definition["type"].as_str().unwrap_or_default();
+
+            let value = match r#type {
+                "created_time" => page["created_time"].clone(),
+                "last_edited_time" => page["last_edited_time"].clone(),
+                "created_by" => page["created_by"].clone(),
+                "last_edited_by" => page["last_edited_by"].clone(),
+                _ if page["properties"].get(&name).is_some() => continue,
+                "unique_id" => {
+                    let number = self.unique_ids.entry(database_id.to_string()).or_default();
+                    *number += 1;
+
+                    json!({ "prefix": definition["unique_id"]["prefix"], "number": number })
+                }
+                "status" => definition["status"]["options"]
+                    .get(0)
+                    .cloned()
+                    .unwrap_or(Value::Null),
+                "title" | "rich_text" | "multi_select" | "people" | "files" | "relation" => {
+                    json!([])
+                }
+                "checkbox" => json!(false),
+                "button" => json!({}),
+                // Formulas and rollups are not computed.
+                "formula" | "rollup" => continue,
+                _ => Value::Null,
+            };
+
+            let mut property = Map::new();
+            property.insert("id".to_string(), definition["id"].clone());
+            property.insert("type".to_string(), json!(r#type));
+            property.insert(r#type.to_string(), value);
+
+            page["properties"][name] = Value::Object(property);
+        }
+
+        Ok(())
+    }
+
+    /// Finds the option of a `select`, `multi_select` or `status` property by its name.
+    /// Unknown options are added to `select` and `multi_select` properties, as Notion does.
+    fn resolve_option(
+        &mut self,
+        database_id: &str,
+        property: &str,
+        option: &Value,
+    ) -> Result<Value, Failure> {
+        let definition = &mut self
+            .databases
+            .get_mut(database_id)
+            .ok_or_else(|| Failure::not_found("database", database_id))?["properties"][property];
+
+        let r#type = definition["type"].as_str().unwrap_or_default().to_string();
+
+        let options = &mut definition[r#type.as_str()]["options"];
+
+        let found = options.as_array().into_iter().flatten().find(|candidate| {
+            candidate["name"] == option["name"] || (candidate["id"] == option["id"])
+        });
+
+        match found {
+            Some(found) => Ok(found.clone()),
+            None if r#type == "status" => Err(Failure::validation(format!(
+                "{} is not a valid option of {}.",
+                option["name"], property
+            ))),
+            None => {
+                let option = json!({
+                    "id": short_id(),
+                    "name": option["name"],
+                    "color": option.get("color").cloned().unwrap_or(json!("default"))
+                });
+
+                if let Some(options) = options.as_array_mut() {
+                    options.push(option.clone());
+                } else {
+                    *options = json!([option]);
+                }
+
+                Ok(option)
+            }
+        }
+    }
+
+    // # --------------------------------------------------------------------------------
+    //
+    // Database
+    //
+    // # --------------------------------------------------------------------------------
+
+    fn get_database(&self, id: &str) -> Outcome {
+        let id = normalize_id(id);
+
+        self.databases
+            .get(&id)
+            .cloned()
+            .ok_or_else(|| Failure::not_found("database", &id))
+    }
+
+    fn create_database(&mut self, body: Value) -> Outcome {
+        let id = new_id();
+        let now = now();
+
+        let page_id = normalize_id(body["parent"]["page_id"].as_str().unwrap_or_default());
+
+        if !self.pages.contains_key(&page_id) {
+            return Err(Failure::not_found("page", &page_id));
+        }
+
+        let parent = json!({ "type": "page_id", "page_id": page_id });
+
+        let mut properties = Map::new();
+
+        for (name, definition) in body["properties"].as_object().into_iter().flatten() {
+            properties.insert(name.clone(), property_definition(name, definition.clone()));
+        }
+
+        if !properties
+            .values()
+            .any(|property| property["type"] == "title")
+        {
+            return Err(Failure::validation(
+                "Title is not provided. A database must have a title property.",
+            ));
+        }
+
+        let database = json!({
+            "object": "database",
+            "id": id,
+            "created_time": now,
+            "last_edited_time": now,
+            "created_by": user_reference(&self.bot),
+            "last_edited_by": user_reference(&self.bot),
+            "cover": body["cover"],
+            "icon": body["icon"],
+            "parent": parent,
+            "url": format!("https://www.notion.so/{}", id.replace('-', "")),
+            "public_url": null,
+            "archived": false,
+            "in_trash": false,
+            "is_inline": false,
+            "title": body.get("title").cloned().unwrap_or(json!([])),
+            "description": body.get("description").cloned().unwrap_or(json!([])),
+            "properties": properties
+        });
+
+        let child_database = json!({
+            "type": "child_database",
+            "child_database": { "title": plain_text(&database["title"]) }
+        });
+
+        self.insert_block(&id, &parent, child_database);
+        self.children.entry(page_id).or_default().push(id.clone());
+
+        self.databases.insert(id.clone(), database);
+        self.created.push(id.clone());
+
+        self.get_database(&id)
+    }
+
+    fn update_database(&mut self, id: &str, body: Value) -> Outcome {
+        let id = normalize_id(id);
+
+        let mut database = self.get_database(&id)?;
+
+        for key in ["title", "description", "icon", "cover"] {
+            if let Some(value) = body.get(key) {
+                database[key] = value.clone();
+            }
+        }
+
+        let mut removed = Vec::new();
+
+        for (name, definition) in body["properties"].as_object().into_iter().flatten() {
+            let properties = database["properties"]
+                .as_object_mut()
+                .ok_or_else(|| Failure::internal("the database has no properties"))?;
+
+            match (properties.get_mut(name), definition) {
+                (Some(_), Value::Null) => {
+                    properties.remove(name);
+                    removed.push(name.clone());
+                }
+                (Some(existing), definition) => {
+                    let r#type = definition["type"].as_str().unwrap_or_default();
+                    let definition = property_definition(name, definition.clone());
+
+                    existing["type"] = json!(r#type);
+                    existing[r#type] = definition[r#type].clone();
+                }
+                (None, Value::Null) => {}
+                (None, definition) => {
+                    properties.insert(name.clone(), property_definition(name, definition.clone()));
+                }
+            }
+        }
+
+        database["last_edited_time"] = json!(now());
+
+        if let Some(block) = self.blocks.get_mut(&id) {
+            block["child_database"]["title"] = json!(plain_text(&database["title"]));
+        }
+
+        for page in self.pages.values_mut() {
+            if page["parent"]["database_id"] == id.as_str() {
+                if let Some(properties) = page["properties"].as_object_mut() {
+                    for name in &removed {
+                        properties.remove(name);
+                    }
+                }
+            }
+        }
+
+        self.databases.insert(id.clone(), database);
+
+        self.get_database(&id)
+    }
+
+    /// Queries the pages of the database. The filter is validated against the schema
+    /// and evaluated with [`Filter::matches`], so its semantics are those of this crate.
+    fn query_database(&self, id: &str, body: Value) -> Outcome {
+        let database = self.get_database(id)?;
+
+        let filter = match body.get("filter") {
+            Some(filter) if !filter.is_null() => Some(
+                serde_json::from_value::<Filter>(filter.clone())
+                    .map_err(|e| Failure::validation(format!("body.filter is invalid: {}", e)))?,
+            ),
+            _ => None,
+        };
+
+        let sorts = match body.get("sorts") {
+            Some(sorts) if !sorts.is_null() => {
+                serde_json::from_value::<Vec<Sort>>(sorts.clone())
+                    .map_err(|e| Failure::validation(format!("body.sorts is invalid: {}", e)))?
+            }
+            _ => Vec::new(),
+        };
+
+        if let Some(filter) = &filter {
+            let schema = serde_json::from_value::<DatabaseResponse>(database.clone())
+                .map_err(Failure::internal)?;
+
+            filter
+                .validate(&schema)
+                .map_err(|e| Failure::validation(e.to_string()))?;
+        }
+
+        for sort in &sorts {
+            if let Sort::Property { property, .. } = sort {
+                if database["properties"].get(property).is_none() {
+                    return Err(Failure::validation(format!(
+                        "Could not find sort property with name or id: {}",
+                        property
+                    )));
+                }
+            }
+        }
+
+        let mut pages = Vec::new();
+
+        for page in self.created.iter().filter_map(|id| self.pages.get(id)) {
+            if page["parent"]["database_id"] != database["id"]
+                || page["archived"] == true
+                || page["in_trash"] == true
+            {
+                continue;
+            }
+
+            let response =
+                serde_json::from_value::<PageResponse>(page.clone()).map_err(Failure::internal)?;
+
+            if let Some(filter) = &filter {
+                if !filter
+                    .matches(&response)
+                    .map_err(|e| Failure::validation(e.to_string()))?
+                {
+                    continue;
+                }
+            }
+
+            pages.push((response, page.clone()));
+        }
+
+        pages.sort_by(|(a, _), (b, _)| {
+            sorts
+                .iter()
+                .map(|sort| compare_pages(a, b, sort))
+                .find(|ordering| ordering.is_ne())
+                .unwrap_or(Ordering::Equal)
+        });
+
+        let list = paginate(
+            pages.into_iter().map(|(_, page)| page).collect(),
+            body["start_cursor"].as_str(),
+            body["page_size"].as_u64().map(|size| size as usize),
+        )?;
+
+        Ok(with_type(list, "page_or_database"))
+    }
+
+    // # --------------------------------------------------------------------------------
+    //
+    // Block
+    //
+    // # --------------------------------------------------------------------------------
+
+    fn get_block(&self, id: &str) -> Outcome {
+        let id = normalize_id(id);
+
+        let mut block = self
+            .blocks
+            .get(&id)
+            .cloned()
+            .ok_or_else(|| Failure::not_found("block", &id))?;
+
+        block["has_children"] = json!(self.has_children(&id));
+
+        Ok(block)
+    }
+
+    fn update_block(&mut self, id: &str, body: Value) -> Outcome {
+        let id = normalize_id(id);
+
+        let block = self
+            .blocks
+            .get_mut(&id)
+            .ok_or_else(|| Failure::not_found("block", &id))?;
+
+        if let Some(r#type) = body["type"].as_str() {
+            if block["type"] != r#type {
+                return Err(Failure::validation(format!(
+                    "The type of the block cannot be changed from {} to {}.",
+                    block["type"], r#type
+                )));
+            }
+
+            let mut content = body[r#type].clone();
+
+            if let Some(content) = content.as_object_mut() {
+                content.remove("children");
+            }
+
+            block[r#type] = content;
+        }
+
+        if let Some(archived) = body["archived"].as_bool() {
+            block["archived"] = json!(archived);
+            block["in_trash"] = json!(archived);
+
+            if let Some(page) = self.pages.get_mut(&id) {
+                page["archived"] = json!(archived);
+                page["in_trash"] = json!(archived);
+            }
+        }
+
+        block["last_edited_time"] = json!(now());
+
+        self.get_block(&id)
+    }
+
+    fn delete_block(&mut self, id: &str) -> Outcome {
+        self.update_block(id, json!({ "archived": true }))
+    }
+
+    fn list_children(&self, id: &str, query: &HashMap<String, String>) -> Outcome {
+        let id = normalize_id(id);
+
+        if !self.blocks.contains_key(&id) && !self.pages.contains_key(&id) {
+            return Err(Failure::not_found("block", &id));
+        }
+
+        let children = self
+            .live_children(&id)
+            .map(|child| self.get_block(child))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let list = paginate(
+            children,
+            query.get("start_cursor").map(String::as_str),
+            query.get("page_size").and_then(|size| size.parse().ok()),
+        )?;
+
+        Ok(with_type(list, "block"))
+    }
+
+    fn append_children(&mut self, id: &str, body: Value) -> Outcome {
+        let id = normalize_id(id);
+
+        if !self.blocks.contains_key(&id) && !self.pages.contains_key(&id) {
+            return Err(Failure::not_found("block", &id));
+        }
+
+        let children = body["children"]
+            .as_array()
+            .cloned()
+            .ok_or_else(|| Failure::validation("body.children should be defined."))?;
+
+        let ids = self.add_children(&id, children, body["after"].as_str())?;
+
+        let blocks = ids
+            .iter()
+            .map(|id| self.get_block(id))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(with_type(paginate(blocks, None, None)?, "block"))
+    }
+
+    /// Adds blocks (and their nested `children`) under a page or a block,
+    /// after the child `after` or at the end. Returns the IDs of the new blocks.
+    fn add_children(
+        &mut self,
+        parent_id: &str,
+        children: Vec<Value>,
+        after: Option<&str>,
+    ) -> Result<Vec<String>, Failure> {
+        let parent = if self.pages.contains_key(parent_id) {
+            json!({ "type": "page_id", "page_id": parent_id })
+        } else {
+            json!({ "type": "block_id", "block_id": parent_id })
+        };
+
+        let position = match after {
+            Some(after) => {
+                let after = normalize_id(after);
+
+                let index = self
+                    .children
+                    .get(parent_id)
+                    .and_then(|children| children.iter().position(|child| *child == after))
+                    .ok_or_else(|| Failure::not_found("block", &after))?;
+
+                Some(index + 1)
+            }
+            None => None,
+        };
+
+        let mut ids = Vec::new();
+
+        for mut block in children {
+            let r#type = block["type"]
+                .as_str()
+                .ok_or_else(|| Failure::validation("body.children[].type should be defined."))?
+                .to_string();
+
+            let nested = block[r#type.as_str()]
+                .as_object_mut()
+                .and_then(|content| content.remove("children"));
+
+            let id = new_id();
+
+            self.insert_block(&id, &parent, block);
+
+            if let Some(Value::Array(nested)) = nested {
+                self.add_children(&id, nested, None)?;
+            }
+
+            ids.push(id);
+        }
+
+        let siblings = self.children.entry(parent_id.to_string()).or_default();
+
+        match position {
+            Some(position) => {
+                siblings.splice(position..position, ids.iter().cloned());
+            }
+            None => siblings.extend(ids.iter().cloned()),
+        }
+
+        Ok(ids)
+    }
+
+    /// Stores a block with the given `type` and content.
+    fn insert_block(&mut self, id: &str, parent: &Value, block: Value) {
+        let now = now();
+
+        let mut response = json!({
+            "object": "block",
+            "id": id,
+            "parent": parent,
+            "created_time": now,
+            "last_edited_time": now,
+            "created_by": user_reference(&self.bot),
+            "last_edited_by": user_reference(&self.bot),
+            "has_children": false,
+            "archived": false,
+            "in_trash": false
+        });
+
+        for (key, value) in block.as_object().into_iter().flatten() {
+            response[key] = value.clone();
+        }
+
+        self.blocks.insert(id.to_string(), response);
+    }
+
+    /// The IDs of the children that are not archived.
+    fn live_children<'a>(&'a self, id: &str) -> impl Iterator<Item = &'a String> {
+        self.children.get(id).into_iter().flatten().filter(|child| {
+            self.blocks
+                .get(*child)
+                .is_some_and(|b| b["archived"] != true)
+        })
+    }
+
+    fn has_children(&self, id: &str) -> bool {
+        self.live_children(id).next().is_some()
+    }
+
+    // # --------------------------------------------------------------------------------
+    //
+    // Search
+    //
+    // # --------------------------------------------------------------------------------
+
+    fn search(&self, body: Value) -> Outcome {
+        let query = body["query"].as_str().unwrap_or_default().to_lowercase();
+        let object = body["filter"]["value"].as_str();
+
+        let mut results = self
+            .created
+            .iter()
+            .filter_map(|id| self.pages.get(id).or_else(|| self.databases.get(id)))
+            .filter(|item| item["archived"] != true && item["in_trash"] != true)
+            .filter(|item| object.is_none_or(|object| item["object"] == object))
+            .filter(|item| title_of(item).to_lowercase().contains(&query))
+            .cloned()
+            .collect::<Vec<_>>();
+
+        match body["sort"]["direction"].as_str() {
+            Some("ascending") => {
+                results.sort_by_key(|item| item["last_edited_time"].as_str().map(String::from))
+            }
+            Some("descending") => results.sort_by_key(|item| {
+                std::cmp::Reverse(item["last_edited_time"].as_str().map(String::from))
+            }),
+            _ => {}
+        }
+
+        let list = paginate(
+            results,
+            body["start_cursor"].as_str(),
+            body["page_size"].as_u64().map(|size| size as usize),
+        )?;
+
+        Ok(with_type(list, "page_or_database"))
+    }
+}
+
+// # --------------------------------------------------------------------------------
+//
+// helpers
+//
+// # --------------------------------------------------------------------------------
+
+/// A random ID in the format of the IDs of Notion (a UUID with dashes).
+fn new_id() -> String {
+    let hex = (0..32)
+        .map(|_| char::from_digit(fastrand::u32(0..16), 16).unwrap_or('0'))
+        .collect::<String>();
+
+    format!(
+        "{}-{}-{}-{}-{}",
+        &hex[0..8],
+        &hex[8..12],
+        &hex[12..16],
+        &hex[16..20],
+        &hex[20..32]
+    )
+}
+
+/// A random ID of a property or a select option.
+fn short_id() -> String {
+    (0..4).map(|_| fastrand::alphanumeric()).collect()
+}
+
+/// Accepts IDs with or without dashes, as the Notion API does.
+fn normalize_id(id: &str) -> String {
+    crate::BlockId::from(id).to_string()
+}
+
+fn now() -> String {
+    chrono::Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Millis, true)
+}
+
+fn id_of(object: &Value) -> String {
+    object["id"].as_str().unwrap_or_default().to_string()
+}
+
+/// A user as it is referred to in `created_by` and `last_edited_by`.
+fn user_reference(user: &Value) -> Value {
+    json!({ "object": "user", "id": user["id"] })
+}
+
+/// Concatenates the plain text of rich texts.
+fn plain_text(rich_text: &Value) -> String {
+    rich_text
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(|text| text["plain_text"].as_str())
+        .collect()
+}
+
+/// The title of a page or a database.
+fn title_of(object: &Value) -> String {
+    match object["object"].as_str() {
+        Some("database") => plain_text(&object["title"]),
+        _ => object["properties"]
+            .as_object()
+            .into_iter()
+            .flat_map(|properties| properties.values())
+            .find(|property| property["type"] == "title")
+            .map(|property| plain_text(&property["title"]))
+            .unwrap_or_default(),
+    }
+}
+
+/// Completes a property of a database schema given in a request.
+fn property_definition(name: &str, mut definition: Value) -> Value {
+    let r#type = definition["type"].as_str().unwrap_or_default().to_string();
+
+    definition["id"] = match r#type.as_str() {
+        "title" => json!("title"),
+        _ => json!(short_id()),
+    };
+    definition["name"] = json!(name);
+
+    match r#type.as_str() {
+        "select" | "multi_select" => {
+            let options = &mut definition[r#type.as_str()]["options"];
+
+            if let Some(options) = options.as_array_mut() {
+                for option in options {
+                    if option["id"].is_null() {
+                        option["id"] = json!(short_id());
+                    }
+                    if option["color"].is_null() {
+                        option["color"] = json!("default");
+                    }
+                }
+            } else {
+                *options = json!([]);
+            }
+        }
+        "status" => {
+            let status = &mut definition["status"];
+
+            if status["options"].as_array().is_none_or(Vec::is_empty) {
+                *status = default_status();
+            }
+        }
+        _ => {}
+    }
+
+    definition
+}
+
+/// The options of a `status` property created without options.
+fn default_status() -> Value {
+    let (not_started, in_progress, done) = (short_id(), short_id(), short_id());
+
+    json!({
+        "options": [
+            { "id": not_started, "name": "Not started", "color": "default" },
+            { "id": in_progress, "name": "In progress", "color": "blue" },
+            { "id": done, "name": "Done", "color": "green" }
+        ],
+        "groups": [
+            { "id": short_id(), "name": "To-do", "color": "gray", "option_ids": [not_started] },
+            { "id": short_id(), "name": "In progress", "color": "blue", "option_ids": [in_progress] },
+            { "id": short_id(), "name": "Complete", "color": "green", "option_ids": [done] }
+        ]
+    })
+}
+
+/// Returns the page of `items` starting at `start_cursor`.
+/// The cursor is the position of the first item of the page.
+fn paginate(
+    items: Vec<Value>,
+    start_cursor: Option<&str>,
+    page_size: Option<usize>,
+) -> Result<Value, Failure> {
+    let page_size = page_size.unwrap_or(MAX_PAGE_SIZE).clamp(1, MAX_PAGE_SIZE);
+
+    let start = match start_cursor {
+        Some(cursor) => cursor.parse::<usize>().map_err(|_| {
+            Failure::validation(format!(
+                "start_cursor should be a valid cursor, instead was `{}`.",
+                cursor
+            ))
+        })?,
+        None => 0,
+    };
+
+    let end = start + page_size;
+    let has_more = items.len() > end;
+
+    let results = items
+        .into_iter()
+        .skip(start)
+        .take(page_size)
+        .collect::<Vec<_>>();
+
+    Ok(json!({
+        "object": "list",
+        "results": results,
+        "next_cursor": has_more.then(|| end.to_string()),
+        "has_more": has_more
+    }))
+}
+
+/// Adds the `type` of the items to a list.
+fn with_type(mut list: Value, r#type: &str) -> Value {
+    list["type"] = json!(r#type);
+    list[r#type] = json!({});
+    list
+}
+
+/// A value of a property that pages are sorted by.
+#[derive(Debug, PartialEq, PartialOrd)]
+enum SortKey {
+    Number(f64),
+    Text(String),
+}
+
+fn sort_key(property: &PageProperty) -> Option<SortKey> {
+    match property {
+        PageProperty::Number(_) => property.as_number().map(SortKey::Number),
+        PageProperty::Checkbox(_) => property
+            .as_checkbox()
+            .map(|checked| SortKey::Number(if checked { 1.0 } else { 0.0 })),
+        PageProperty::Date(_) => property
+            .as_date_range()
+            .map(|(start, _)| SortKey::Number(start.timestamp_millis() as f64)),
+        PageProperty::CreatedTime(created_time) => Some(SortKey::Number(
+            created_time.created_time.timestamp_millis() as f64,
+        )),
+        PageProperty::LastEditedTime(last_edited_time) => Some(SortKey::Number(
+            last_edited_time.last_edited_time.timestamp_millis() as f64,
+        )),
+        PageProperty::UniqueId(unique_id) => {
+            Some(SortKey::Number(unique_id.unique_id.number as f64))
+        }
+        _ => {
+            let text = property.to_string();
+            (!text.is_empty()).then(|| SortKey::Text(text.to_lowercase()))
+        }
+    }
+}
+
+/// Compares two pages by a property or a timestamp.
+/// Pages with an empty value come last in both directions.
+fn compare_pages(a: &PageResponse, b: &PageResponse, sort: &Sort) -> Ordering {
+    let key = |page: &PageResponse| match sort {
+        Sort::Property { property, .. } => page.properties.get(property).and_then(sort_key),
+        Sort::Timestamp { timestamp, .. } => {
+            let time = match timestamp {
+                SortTimestamp::CreatedTime => page.created_time,
+                SortTimestamp::LastEditedTime => page.last_edited_time,
+            };
+            Some(SortKey::Number(time.timestamp_millis() as f64))
+        }
+    };
+
+    let (a, b) = (key(a), key(b));
+
+    match (a, b) {
+        (None, None) => Ordering::Equal,
+        (None, Some(_)) => Ordering::Greater,
+        (Some(_), None) => Ordering::Less,
+        (Some(a), Some(b)) => {
+            let ordering = a.partial_cmp(&b).unwrap_or(Ordering::Equal);
+
+            match sort.direction() {
+                SortDirection::Ascending => ordering,
+                SortDirection::Descending => ordering.reverse(),
+            }
+        }
+    }
+}